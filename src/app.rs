@@ -1,69 +1,476 @@
 use anyhow::Result;
+use aws_config::SdkConfig;
+use aws_types::region::Region;
+use chrono::{DateTime, Local, Utc};
 use copypasta::{ClipboardContext, ClipboardProvider};
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use std::time::Instant;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::Rect;
+use std::cell::Cell;
+use std::collections::{HashSet, VecDeque};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::task::JoinSet;
 
+use crate::config::{Config, ExperimentalFlags};
 use crate::services::{
+    ec2::EC2Service,
+    ecr::ECRService,
+    iam::IAMService,
+    lambda::LambdaService,
+    logs::CloudWatchLogsService,
     manager::ServiceManager,
-    traits::{ViewState, ViewType},
+    rds::RDSService,
+    route53::Route53Service,
+    s3::S3Service,
+    secrets::SecretsManagerService,
+    sfn::StepFunctionsService,
+    traits::{
+        Confirmation, ResourceData, ServiceAction, ServiceId, ServiceMetadata, SortValue,
+        ViewState, ViewType,
+    },
+    vpc::VPCService,
 };
+use crate::state::{RecentView, State};
+use crate::ui::theme::{THEME_NAMES, Theme};
+use crate::utils::{
+    aws::{
+        create_ec2_client, create_ecr_client, create_iam_client, create_lambda_client,
+        create_logs_client, create_rds_client, create_route53_client, create_s3_client,
+        create_secrets_client, create_sfn_client, get_caller_identity, get_credential_source,
+        is_known_region, load_aws_config, profile_names,
+    },
+    columns::column_order,
+    export::{select_columns, to_csv, to_tsv},
+    fuzzy::fuzzy_score,
+    text::fill_template,
+};
+
+/// Scheme of shareable links to a view, e.g. `ats://ecr/my-repo/images`
+const DEEP_LINK_SCHEME: &str = "ats://";
+
+/// Keys available everywhere, listed first in the `?` help overlay
+const GLOBAL_KEYS: [(&str, &str); 12] = [
+    (":", "Command mode"),
+    ("Ctrl-k", "Command palette: find and run any command"),
+    ("/", "Search"),
+    ("?", "Show / hide this help"),
+    ("Tab / Shift-Tab", "Next / previous service"),
+    ("Ctrl-o", "Reopen the view last closed with Esc"),
+    ("Ctrl-p", "Find an item in the loaded views"),
+    ("t", "Toggle UTC clock"),
+    ("p", "Pause / resume auto-refresh"),
+    ("z", "Toggle zen mode"),
+    ("Ctrl-z", "Suspend to the shell"),
+    ("q", "Quit"),
+];
+
+/// Keys that act on the current table, listed when a service view is open
+const VIEW_KEYS: [(&str, &str); 16] = [
+    ("Enter", "Open the selected item"),
+    ("PgUp / PgDn", "Move a page up / down"),
+    ("gg / G", "Jump to the first / last row"),
+    ("Ctrl-u / Ctrl-d", "Move half a page up / down"),
+    ("Esc", "Go back"),
+    ("r", "Refresh"),
+    ("c", "Copy the selected item"),
+    ("h / l", "Move the column cursor"),
+    ("y", "Copy the cell under the column cursor"),
+    ("1-9 / 0", "Sort by that column, again to flip / reset"),
+    ("i", "Show every field of the selected item in full"),
+    ("o", "Open the selected item in the AWS console"),
+    ("Ctrl-y", "Quit and print the selected item as JSON"),
+    ("Space", "Mark the row; c then copies all marked rows"),
+    ("w", "Toggle wrapping of long cells"),
+    ("x", "Reveal / mask secret values"),
+];
+
+/// Keys for the `:` and `/` input lines
+const INPUT_KEYS: [(&str, &str); 4] = [
+    ("Enter / Esc", "Run / cancel the command or search"),
+    ("Up / Down", "Recall earlier commands"),
+    ("Backspace", "Delete the last character"),
+    ("Ctrl-f", "Toggle fuzzy matching, best matches first"),
+];
+
+/// Built-in commands offered by the command palette besides services and themes.
+/// Commands ending in a space take an argument, typed on the `:` line.
+const PALETTE_COMMANDS: [(&str, &str); 16] = [
+    ("quit", "Quit ats"),
+    ("refresh", "Reload the current view"),
+    ("help", "Show the key bindings"),
+    ("services", "Open the services landing screen"),
+    ("recent", "Reopen a recently viewed view"),
+    ("logs", "Show earlier status messages and errors"),
+    ("whoami", "Show the AWS identity in use"),
+    ("ping", "Check the current service's API is reachable"),
+    ("group", "Group rows by the service's group key"),
+    ("copy-link", "Copy an ats:// link to the current view"),
+    ("copy-tsv", "Copy the table as tab-separated values"),
+    ("copyfmt ", "Set what c copies, e.g. docker pull {ref}"),
+    ("export ", "Save the table as CSV to a path"),
+    ("goto ", "Open an ats:// link"),
+    ("profile ", "Switch to another AWS profile"),
+    ("region ", "Switch to another region"),
+];
+
+/// Two clicks on the same row within this long open it, like Enter
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(400);
+
+/// Status messages and errors kept for `:logs`
+const MAX_NOTIFICATIONS: usize = 100;
+
+/// How long copy and action status messages stay in the footer
+pub const COPY_STATUS_TTL: Duration = Duration::from_secs(3);
+/// How often the current view reloads on its own unless --refresh-interval says otherwise
+const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum InputMode {
     Normal,
     Command,
     Search,
+    Prompt,
+}
+
+/// Async work requested from a key or command handler, run by the main loop
+#[derive(Debug, Clone, PartialEq)]
+pub enum PendingAction {
+    WhoAmI,
+    Ping,
+    ExportAll {
+        dir: PathBuf,
+        remaining: Vec<ServiceId>, // Exported one per main loop pass so progress can draw
+        total: usize,
+        failed: Vec<String>,
+    },
+    SwitchProfile {
+        profile: String,
+    },
+    ServiceAction {
+        action_id: String,
+        input: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NotificationLevel {
+    Info,
+    Warning, // Status messages marked with ✗, e.g. a rejected command
+    Error,
+}
+
+/// A status message or error, kept after it leaves the footer
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub at: DateTime<Utc>,
+    pub level: NotificationLevel,
+    pub message: String,
+}
+
+/// A centered key/value popup drawn over the content area
+#[derive(Debug, Clone)]
+pub struct Popup {
+    pub title: String,
+    pub lines: Vec<(String, String)>,
+}
+
+/// A selectable list overlay that opens the chosen view or runs the chosen command,
+/// e.g. for `:recent`
+#[derive(Debug, Clone)]
+pub struct Picker {
+    pub title: String,
+    pub items: Vec<PickerItem>,
+    pub selected_index: usize,  // Index into `visible_items`
+    pub filter: Option<String>, // Typed query narrowing the items; `None` if not filterable
+    pub fuzzy: bool,            // Match the filter as a subsequence, best matches first
+}
+
+impl Picker {
+    /// Items whose label contains the filter, case-insensitively, or matches it as a
+    /// subsequence for fuzzy pickers
+    pub fn visible_items(&self) -> Vec<&PickerItem> {
+        let filter = self.filter.as_deref().unwrap_or_default().to_lowercase();
+        if !self.fuzzy || filter.is_empty() {
+            return self
+                .items
+                .iter()
+                .filter(|item| item.label.to_lowercase().contains(&filter))
+                .collect();
+        }
+
+        let mut scored: Vec<(i64, &PickerItem)> = self
+            .items
+            .iter()
+            .filter_map(|item| Some((fuzzy_score(&item.label, &filter)?, item)))
+            .collect();
+        // Stable, so equally good matches keep the picker's order
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        scored.into_iter().map(|(_, item)| item).collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PickerItem {
+    pub label: String,
+    pub target: PickerTarget,
+}
+
+#[derive(Debug, Clone)]
+pub enum PickerTarget {
+    View(ViewState),
+    Command(String), // Run as if typed after `:`; a trailing space asks for an argument first
 }
 
 // Removed hardcoded CurrentView enum - now using ViewState from services
 
 pub struct App {
     pub running: bool,
+    pub suspend_requested: bool, // Ctrl-z: the main loop hands the terminal back to the shell
+    pub needs_redraw: bool,      // Set whenever visible state changes; cleared after drawing
+    pub content_area: Cell<Rect>, // Where the content was drawn last, for paging and clicks
+    pub last_click: Option<(Instant, usize)>, // Row and time of the last click, for double-clicks
+    pub pending_g: bool,         // First `g` of `gg` pressed; any other key cancels it
     pub input_mode: InputMode,
     pub current_view: Option<ViewState>,
     pub input_buffer: String,
+    pub command_history: Vec<String>, // Executed commands, oldest first
+    pub history_cursor: Option<usize>, // Entry shown while browsing history with Up/Down
     pub view_stack: Vec<ViewState>,
+    pub last_closed_view: Option<ViewState>, // Reopened with Ctrl-o after an accidental Esc
+    pub landing_selected: usize, // Services landing screen, shown when no view is active
+    pub landing_filter: String,
     pub last_refresh: Instant,
+    pub refresh_interval: Option<Duration>, // None disables auto-refresh
     pub loading: bool,
+    pub progress_status: Option<String>, // Replaces "Loading..." in the footer, e.g. while retrying
     pub error_message: Option<String>,
     pub copy_status: Option<(String, Instant)>, // (message, created_at timestamp)
+    pub notifications: VecDeque<Notification>,  // Oldest first, see `:logs`
     pub aws_profile: String,
     pub aws_region: String,
     pub service_manager: ServiceManager,
+    pub aws_config: Option<SdkConfig>,
+    pub endpoint_url: Option<String>, // --endpoint-url / AWS_ENDPOINT_URL, kept across :profile
+    pub pending_action: Option<PendingAction>,
+    pub popup: Option<Popup>,
+    pub picker: Option<Picker>,
+    pub active_prompt: Option<ServiceAction>, // Action waiting on Prompt mode input
+    pub expected_confirmation: Option<String>, // Input the active prompt must match to run
+    pub utc_clock: bool,
+    pub paused: bool, // `p` holds off auto-refresh so the rows stay put; `r` still reloads
+    pub selected_json: Option<String>, // Printed to stdout once the TUI exits, see Ctrl-y
+    pub theme: Theme, // Colors for every render function, see `--theme` and `:theme`
+    pub copy_template: Option<String>, // Set with `:copyfmt`, e.g. `docker pull {ref}`
+    pub config: Config,
+    pub experimental: ExperimentalFlags, // Runtime copy of the config's flags, see `:toggle`
+    pub state: State,
+    pub prefetch_tasks: JoinSet<(ViewState, Result<ResourceData>)>,
+    pub prefetched: HashSet<ServiceId>, // Services with unconsumed prefetched list data
 }
 
 impl App {
     pub fn new(profile: String, region: String) -> Self {
         Self {
             running: true,
+            suspend_requested: false,
+            needs_redraw: true,
+            content_area: Cell::new(Rect::default()),
+            last_click: None,
+            pending_g: false,
             input_mode: InputMode::Normal,
             current_view: None,
             input_buffer: String::new(),
+            command_history: Vec::new(),
+            history_cursor: None,
             view_stack: Vec::new(),
+            last_closed_view: None,
+            landing_selected: 0,
+            landing_filter: String::new(),
             last_refresh: Instant::now(),
+            refresh_interval: Some(DEFAULT_REFRESH_INTERVAL),
             loading: false,
+            progress_status: None,
             error_message: None,
             copy_status: None,
+            notifications: VecDeque::new(),
             aws_profile: profile,
             aws_region: region,
             service_manager: ServiceManager::new(),
+            aws_config: None,
+            endpoint_url: None,
+            pending_action: None,
+            popup: None,
+            picker: None,
+            active_prompt: None,
+            expected_confirmation: None,
+            utc_clock: false,
+            paused: false,
+            selected_json: None,
+            theme: Theme::default(),
+            copy_template: None,
+            config: Config::default(),
+            experimental: ExperimentalFlags::default(),
+            state: State::default(),
+            prefetch_tasks: JoinSet::new(),
+            prefetched: HashSet::new(),
         }
     }
 
     pub fn handle_key_event(&mut self, key: KeyEvent) -> Result<()> {
-        match self.input_mode {
+        self.needs_redraw = true;
+
+        if self.popup.is_some() {
+            if matches!(
+                key.code,
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') | KeyCode::Char('?')
+            ) {
+                self.popup = None;
+            }
+            return Ok(());
+        }
+
+        if self.picker.is_some() {
+            return self.handle_picker_key(key);
+        }
+
+        let result = match self.input_mode {
             InputMode::Normal => self.handle_normal_mode(key),
             InputMode::Command => self.handle_command_mode(key),
             InputMode::Search => self.handle_search_mode(key),
+            InputMode::Prompt => self.handle_prompt_mode(key),
+        };
+        self.keep_selection_visible();
+        result
+    }
+
+    /// Move the selection by `delta` rows, stopping at either end of the list
+    fn move_selection_by(&mut self, delta: isize) {
+        if let Some(view_state) = &self.current_view {
+            let max_index = self.get_filtered_data_count(view_state).saturating_sub(1);
+            if let Some(view_state) = &mut self.current_view {
+                view_state.selected_index = view_state
+                    .selected_index
+                    .saturating_add_signed(delta)
+                    .min(max_index);
+            }
+        } else {
+            let max_index = self.landing_services().len().saturating_sub(1);
+            self.landing_selected = self
+                .landing_selected
+                .saturating_add_signed(delta)
+                .min(max_index);
+        }
+    }
+
+    /// Scroll the selection with the wheel, select a table row by clicking it, and
+    /// open it by double-clicking
+    pub fn handle_mouse_event(&mut self, mouse: MouseEvent) {
+        if self.popup.is_some() || self.picker.is_some() || self.input_mode != InputMode::Normal {
+            return;
+        }
+        self.needs_redraw = true;
+
+        match mouse.kind {
+            MouseEventKind::ScrollDown => self.move_selection_by(1),
+            MouseEventKind::ScrollUp => self.move_selection_by(-1),
+            MouseEventKind::Down(MouseButton::Left) => {
+                let Some(index) = self.row_at(mouse.column, mouse.row) else {
+                    return;
+                };
+                let double_click = self
+                    .last_click
+                    .is_some_and(|(at, row)| row == index && at.elapsed() <= DOUBLE_CLICK_INTERVAL);
+
+                if let Some(view_state) = &mut self.current_view {
+                    view_state.selected_index = index;
+                }
+                if double_click {
+                    self.last_click = None;
+                    self.handle_enter_key();
+                } else {
+                    self.last_click = Some((Instant::now(), index));
+                }
+            }
+            _ => return,
+        }
+        self.keep_selection_visible();
+    }
+
+    /// Item index of the table row drawn at a screen position. Only plain tables
+    /// map cleanly: group headers and wrapped cells make rows uneven.
+    fn row_at(&self, column: u16, row: u16) -> Option<usize> {
+        let view_state = self.current_view.as_ref()?;
+        if view_state.grouped || view_state.wrap_cells {
+            return None;
+        }
+        let area = self.content_area.get();
+        // Skip the top border and the header row
+        let first_row = area.y + 2;
+        let last_row = area.y + area.height.saturating_sub(1);
+        if column <= area.x || column >= area.x + area.width.saturating_sub(1) {
+            return None;
+        }
+        if row < first_row || row >= last_row {
+            return None;
+        }
+
+        let index = view_state.scroll_offset + (row - first_row) as usize;
+        (index < self.get_filtered_data_count(view_state)).then_some(index)
+    }
+
+    /// Table rows that fit in the content area, leaving out its borders and header
+    pub fn visible_rows(&self) -> usize {
+        (self.content_area.get().height as usize)
+            .saturating_sub(3)
+            .max(1)
+    }
+
+    /// Scroll the current table just far enough that the selected row is on screen
+    fn keep_selection_visible(&mut self) {
+        let rows = self.visible_rows();
+        if let Some(view_state) = &mut self.current_view {
+            if view_state.selected_index < view_state.scroll_offset {
+                view_state.scroll_offset = view_state.selected_index;
+            } else if view_state.selected_index >= view_state.scroll_offset + rows {
+                view_state.scroll_offset = view_state.selected_index + 1 - rows;
+            }
         }
     }
 
     fn handle_normal_mode(&mut self, key: KeyEvent) -> Result<()> {
+        let pending_g = std::mem::take(&mut self.pending_g);
         match (key.code, key.modifiers) {
             (KeyCode::Char('c'), KeyModifiers::CONTROL) => self.running = false,
             (KeyCode::Char('r'), KeyModifiers::CONTROL) => {
                 self.refresh_data();
             }
+            (KeyCode::Char('o'), KeyModifiers::CONTROL) => {
+                if let Some(view_state) = self.last_closed_view.take() {
+                    self.open_view(view_state);
+                }
+            }
+            (KeyCode::Char('z'), KeyModifiers::CONTROL) => self.suspend_requested = true,
+            (KeyCode::Char('p'), KeyModifiers::CONTROL) => self.open_quick_switcher(),
+            (KeyCode::Char('k'), KeyModifiers::CONTROL) => self.open_command_palette(),
+            (KeyCode::Char('y'), KeyModifiers::CONTROL) => self.quit_with_selected_json(),
+            (KeyCode::Char('d'), KeyModifiers::CONTROL) => {
+                self.move_selection_by((self.visible_rows() / 2).max(1) as isize);
+            }
+            (KeyCode::Char('u'), KeyModifiers::CONTROL) => {
+                self.move_selection_by(-((self.visible_rows() / 2).max(1) as isize));
+            }
+            (KeyCode::Char('g'), KeyModifiers::NONE) => {
+                if pending_g {
+                    self.move_selection_by(isize::MIN); // Clamps to the first row
+                } else {
+                    self.pending_g = true;
+                }
+            }
+            (KeyCode::Char('G'), _) => self.move_selection_by(isize::MAX), // Clamps to the last row
+            (KeyCode::PageDown, _) => self.move_selection_by(self.visible_rows() as isize),
+            (KeyCode::PageUp, _) => self.move_selection_by(-(self.visible_rows() as isize)),
             (KeyCode::Char('q'), KeyModifiers::NONE) => self.running = false,
             (KeyCode::Char(':'), KeyModifiers::NONE) => {
                 self.input_mode = InputMode::Command;
@@ -81,19 +488,21 @@ impl App {
             }
             (KeyCode::Up, _) | (KeyCode::Char('k'), KeyModifiers::NONE) => {
                 if let Some(view_state) = &mut self.current_view {
-                    if view_state.selected_index > 0 {
-                        view_state.selected_index -= 1;
-                    }
+                    view_state.selected_index = view_state.selected_index.saturating_sub(1);
+                } else {
+                    self.landing_selected = self.landing_selected.saturating_sub(1);
                 }
             }
             (KeyCode::Down, _) | (KeyCode::Char('j'), KeyModifiers::NONE) => {
                 if let Some(view_state) = &self.current_view {
                     let max_index = self.get_filtered_data_count(view_state).saturating_sub(1);
-                    if let Some(current_view) = &mut self.current_view {
-                        if current_view.selected_index < max_index {
-                            current_view.selected_index += 1;
-                        }
+                    if let Some(current_view) = &mut self.current_view
+                        && current_view.selected_index < max_index
+                    {
+                        current_view.selected_index += 1;
                     }
+                } else if self.landing_selected + 1 < self.landing_services().len() {
+                    self.landing_selected += 1;
                 }
             }
             (KeyCode::Char('r'), KeyModifiers::NONE) => {
@@ -102,112 +511,663 @@ impl App {
             (KeyCode::Char('c'), KeyModifiers::NONE) => {
                 self.copy_selected_to_clipboard();
             }
+            (KeyCode::Char('t'), KeyModifiers::NONE) => {
+                self.utc_clock = !self.utc_clock;
+            }
+            (KeyCode::Char('p'), KeyModifiers::NONE) => self.paused = !self.paused,
+            (KeyCode::Tab, _) => self.cycle_service(true),
+            (KeyCode::BackTab, _) => self.cycle_service(false),
+            (KeyCode::Char('h'), KeyModifiers::NONE) | (KeyCode::Left, _) => {
+                self.move_column_cursor(false);
+            }
+            (KeyCode::Char('l'), KeyModifiers::NONE) | (KeyCode::Right, _) => {
+                self.move_column_cursor(true);
+            }
+            (KeyCode::Char('y'), KeyModifiers::NONE) => {
+                self.copy_selected_cell();
+            }
+            (KeyCode::Char('i'), KeyModifiers::NONE) => self.show_selected_row(),
+            (KeyCode::Char('o'), KeyModifiers::NONE) => self.open_in_console(),
+            (KeyCode::Char('w'), KeyModifiers::NONE) => {
+                if let Some(view_state) = &mut self.current_view {
+                    view_state.wrap_cells = !view_state.wrap_cells;
+                }
+            }
+            (KeyCode::Char('x'), KeyModifiers::NONE) => {
+                if let Some(view_state) = &mut self.current_view {
+                    view_state.reveal_values = !view_state.reveal_values;
+                }
+            }
+            (KeyCode::Char('z'), KeyModifiers::NONE) => {
+                self.state.zen_mode = !self.state.zen_mode;
+                let _ = self.state.save();
+            }
+            (KeyCode::Char('?'), _) => self.show_help(),
+            (KeyCode::Char(' '), KeyModifiers::NONE) => self.toggle_mark(),
+            (KeyCode::Char(c @ '0'..='9'), KeyModifiers::NONE) => {
+                self.sort_by_column(c as usize - '0' as usize);
+            }
+            (KeyCode::Char(c), modifiers) if !modifiers.contains(KeyModifiers::CONTROL) => {
+                self.trigger_service_action(c);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_picker_key(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(picker) = &mut self.picker else {
+            return Ok(());
+        };
+
+        match key.code {
+            KeyCode::Esc => self.picker = None,
+            // Filterable pickers take typed characters as the query
+            KeyCode::Char(c) if picker.filter.is_some() => {
+                picker.filter.get_or_insert_default().push(c);
+                picker.selected_index = 0;
+            }
+            KeyCode::Backspace => {
+                if let Some(filter) = &mut picker.filter {
+                    filter.pop();
+                    picker.selected_index = 0;
+                }
+            }
+            KeyCode::Char('q') => self.picker = None,
+            KeyCode::Up | KeyCode::Char('k') => {
+                picker.selected_index = picker.selected_index.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j')
+                if picker.selected_index + 1 < picker.visible_items().len() =>
+            {
+                picker.selected_index += 1;
+            }
+            KeyCode::Enter => {
+                let target = picker
+                    .visible_items()
+                    .get(picker.selected_index)
+                    .map(|item| item.target.clone());
+                self.picker = None;
+                match target {
+                    Some(PickerTarget::View(view_state)) => self.open_view(view_state),
+                    Some(PickerTarget::Command(command)) => self.run_palette_command(command)?,
+                    None => {}
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_prompt_mode(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc => {
+                self.input_mode = InputMode::Normal;
+                self.input_buffer.clear();
+                self.active_prompt = None;
+                self.expected_confirmation = None;
+            }
+            KeyCode::Enter => {
+                let confirmed = match self.expected_confirmation.take() {
+                    // Names must match exactly; a plain yes may be typed as `Y`
+                    Some(expected) if expected == "y" => {
+                        self.input_buffer.trim().eq_ignore_ascii_case("y")
+                    }
+                    Some(expected) => self.input_buffer.trim() == expected,
+                    None => true,
+                };
+                if let Some(action) = self.active_prompt.take() {
+                    if confirmed {
+                        self.pending_action = Some(PendingAction::ServiceAction {
+                            action_id: action.id,
+                            input: Some(self.input_buffer.clone()),
+                        });
+                    } else {
+                        self.set_status(
+                            "✗ Confirmation did not match; nothing was changed".to_string(),
+                        );
+                    }
+                }
+                self.input_mode = InputMode::Normal;
+                self.input_buffer.clear();
+            }
+            KeyCode::Char(c) => {
+                self.input_buffer.push(c);
+            }
+            KeyCode::Backspace => {
+                self.input_buffer.pop();
+            }
             _ => {}
         }
         Ok(())
     }
 
+    /// Ask before a destructive action; large batches require typing the target's name
+    fn prompt_for_confirmation(&mut self, action: ServiceAction, confirmation: Confirmation) {
+        let (prompt, expected) = if confirmation.item_count > self.config.confirm_by_name_threshold
+        {
+            (
+                format!(
+                    "{} ({} items) - type '{}' to confirm",
+                    action.description, confirmation.item_count, confirmation.target
+                ),
+                confirmation.target,
+            )
+        } else {
+            (
+                format!(
+                    "{} ({} items)? (y/N)",
+                    action.description, confirmation.item_count
+                ),
+                "y".to_string(),
+            )
+        };
+
+        self.input_mode = InputMode::Prompt;
+        self.input_buffer.clear();
+        self.expected_confirmation = Some(expected);
+        self.active_prompt = Some(ServiceAction {
+            prompt: Some(prompt),
+            ..action
+        });
+    }
+
+    /// Run the current service's action bound to `key`, prompting for input first if needed
+    fn trigger_service_action(&mut self, key: char) {
+        let Some(view_state) = &self.current_view else {
+            return;
+        };
+        let Some(service) = self.service_manager.get_service(&view_state.service_id) else {
+            return;
+        };
+        if let Some(copy_action) = service
+            .copy_actions(view_state)
+            .into_iter()
+            .find(|action| action.key == key)
+        {
+            self.copy_selected_with(&copy_action.id);
+            return;
+        }
+
+        let Some(action) = service
+            .actions(view_state)
+            .into_iter()
+            .find(|action| action.key == key)
+        else {
+            return;
+        };
+
+        let confirmation = self
+            .service_manager
+            .get_service_data(&view_state.service_id)
+            .and_then(|data| service.confirmation(&action.id, view_state, data));
+
+        if let Some(confirmation) = confirmation {
+            self.prompt_for_confirmation(action, confirmation);
+        } else if action.prompt.is_some() {
+            self.input_mode = InputMode::Prompt;
+            self.input_buffer.clear();
+            self.active_prompt = Some(action);
+        } else {
+            self.pending_action = Some(PendingAction::ServiceAction {
+                action_id: action.id,
+                input: None,
+            });
+        }
+    }
+
     fn handle_command_mode(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
             KeyCode::Esc => {
                 self.input_mode = InputMode::Normal;
                 self.input_buffer.clear();
+                self.history_cursor = None;
             }
             KeyCode::Enter => {
-                self.execute_command()?;
+                self.run_command()?;
                 self.input_mode = InputMode::Normal;
                 self.input_buffer.clear();
+                self.history_cursor = None;
             }
+            KeyCode::Up => self.browse_command_history(true),
+            KeyCode::Down => self.browse_command_history(false),
             KeyCode::Char(c) => {
                 self.input_buffer.push(c);
+                self.history_cursor = None;
             }
             KeyCode::Backspace => {
                 self.input_buffer.pop();
+                self.history_cursor = None;
             }
             _ => {}
         }
         Ok(())
     }
 
+    /// Run the command in the input buffer, recording it in the history unless it
+    /// wasn't recognized or set an error
+    fn run_command(&mut self) -> Result<()> {
+        let previous_error = self.error_message.clone();
+        let recognized = self.execute_command()?;
+        let failed = self.error_message.is_some() && self.error_message != previous_error;
+        if recognized && !failed {
+            self.push_command_history();
+        }
+        Ok(())
+    }
+
+    /// Record the command in the input buffer, skipping repeats of the previous one
+    fn push_command_history(&mut self) {
+        let command = self.input_buffer.trim();
+        if !command.is_empty() && self.command_history.last().map(String::as_str) != Some(command) {
+            self.command_history.push(command.to_string());
+        }
+    }
+
+    /// Step to an older (or newer) command, like a shell prompt. Stepping past the
+    /// newest entry clears the input again.
+    fn browse_command_history(&mut self, older: bool) {
+        if self.command_history.is_empty() {
+            return;
+        }
+        self.history_cursor = match (self.history_cursor, older) {
+            (None, true) => Some(self.command_history.len() - 1),
+            (Some(index), true) => Some(index.saturating_sub(1)),
+            (Some(index), false) if index + 1 < self.command_history.len() => Some(index + 1),
+            (_, false) => None,
+        };
+        self.input_buffer = self
+            .history_cursor
+            .map(|index| self.command_history[index].clone())
+            .unwrap_or_default();
+    }
+
     fn handle_search_mode(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
             KeyCode::Esc => {
                 self.input_mode = InputMode::Normal;
                 self.input_buffer.clear();
-                if let Some(view_state) = &mut self.current_view {
-                    view_state.search_filter.clear();
-                    view_state.selected_index = 0;
-                }
+                self.set_search_filter(String::new());
             }
             KeyCode::Enter => {
                 self.input_mode = InputMode::Normal;
-                if let Some(view_state) = &mut self.current_view {
-                    view_state.search_filter = self.input_buffer.clone();
-                    view_state.selected_index = 0;
-                }
+                self.set_search_filter(self.input_buffer.clone());
                 self.input_buffer.clear();
             }
+            KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.toggle_fuzzy_search();
+            }
             KeyCode::Char(c) => {
                 self.input_buffer.push(c);
-                if let Some(view_state) = &mut self.current_view {
-                    view_state.search_filter = self.input_buffer.clone();
-                    view_state.selected_index = 0;
-                }
+                self.set_search_filter(self.input_buffer.clone());
             }
             KeyCode::Backspace => {
                 self.input_buffer.pop();
-                if let Some(view_state) = &mut self.current_view {
-                    view_state.search_filter = self.input_buffer.clone();
-                    view_state.selected_index = 0;
-                }
+                self.set_search_filter(self.input_buffer.clone());
             }
             _ => {}
         }
         Ok(())
     }
 
-    fn execute_command(&mut self) -> Result<()> {
+    /// Switch the current view between substring and fuzzy search. Results reorder,
+    /// so marks are dropped and the selection returns to the best match.
+    fn toggle_fuzzy_search(&mut self) {
+        if let Some(view_state) = &mut self.current_view {
+            view_state.fuzzy_search = !view_state.fuzzy_search;
+            view_state.selected_index = 0;
+            view_state.selected_items.clear();
+        }
+    }
+
+    /// Apply `filter` to the current view, or to the services landing screen when
+    /// no view is active
+    fn set_search_filter(&mut self, filter: String) {
+        // Clamp rather than reset so the selection stays put while it's still visible
+        match self.current_view.take() {
+            Some(mut view_state) => {
+                if view_state.search_filter != filter {
+                    view_state.selected_items.clear();
+                }
+                view_state.search_filter = filter;
+                let max_index = self.get_filtered_data_count(&view_state).saturating_sub(1);
+                view_state.selected_index = view_state.selected_index.min(max_index);
+                self.current_view = Some(view_state);
+            }
+            None => {
+                self.landing_filter = filter;
+                let max_index = self.landing_services().len().saturating_sub(1);
+                self.landing_selected = self.landing_selected.min(max_index);
+            }
+        }
+    }
+
+    /// Run the command in the input buffer. Returns whether it was recognized.
+    fn execute_command(&mut self) -> Result<bool> {
         match self.input_buffer.as_str() {
             "quit" | "q" => self.running = false,
             "refresh" | "r" => {
                 self.refresh_data();
             }
+            "help" | "?" => self.show_help(),
+            "whoami" => self.pending_action = Some(PendingAction::WhoAmI),
+            "ping" => self.pending_action = Some(PendingAction::Ping),
+            "recent" => self.open_recent_picker(),
+            "palette" => self.open_command_palette(),
+            "logs" => self.show_notifications(),
+            command if command == "copy-tsv" || command.starts_with("copy-tsv ") => {
+                let args = command["copy-tsv".len()..].trim().to_string();
+                self.copy_table_as_tsv(&args);
+            }
+            "group" => {
+                if let Some(view_state) = &mut self.current_view {
+                    view_state.grouped = !view_state.grouped;
+                    view_state.selected_index = 0;
+                    view_state.selected_items.clear();
+                }
+            }
+            "services" => {
+                if let Some(current_view) = self.current_view.take() {
+                    self.view_stack.push(current_view);
+                }
+                self.landing_filter.clear();
+                self.landing_selected = 0;
+            }
+            "copy-link" => self.copy_deep_link(),
+            command if command.starts_with("goto ") => {
+                let link = command["goto ".len()..].trim().to_string();
+                self.goto_deep_link(&link);
+            }
+            "copyfmt" => {
+                self.copy_template = None;
+                self.set_status("✓ Copy format reset".to_string());
+            }
+            command if command.starts_with("copyfmt ") => {
+                let template = command["copyfmt ".len()..].trim().to_string();
+                self.set_status(format!("✓ Copy format: {}", template));
+                self.copy_template = Some(template);
+            }
+            command if command.starts_with("theme ") => {
+                let name = command["theme ".len()..].trim();
+                match Theme::by_name(name) {
+                    Some(theme) => {
+                        self.theme = theme;
+                        self.set_status(format!("✓ Theme: {}", name));
+                    }
+                    None => self.set_error(format!(
+                        "Unknown theme '{}'. Available: {}",
+                        name,
+                        THEME_NAMES.join(", ")
+                    )),
+                }
+            }
+            command if command.starts_with("profile ") => {
+                let profile = command["profile ".len()..].trim().to_string();
+                if profile_names().contains(&profile) {
+                    self.pending_action = Some(PendingAction::SwitchProfile { profile });
+                } else {
+                    self.set_error(format!(
+                        "Profile '{}' is not defined in the AWS credentials or config file",
+                        profile
+                    ));
+                }
+            }
+            command if command.starts_with("region ") => {
+                let region = command["region ".len()..].trim().to_string();
+                self.switch_region(&region);
+            }
+            command if command.starts_with("toggle ") => {
+                let name = command["toggle ".len()..].trim().to_string();
+                self.toggle_experimental(&name);
+            }
+            command if command == "export" || command.starts_with("export ") => {
+                let path = command["export".len()..].trim().to_string();
+                if path.is_empty() {
+                    self.set_status("✗ Usage: export <path>".to_string());
+                } else {
+                    self.export_table_as_csv(&path);
+                }
+            }
+            command if command == "export-all" || command.starts_with("export-all ") => {
+                let dir = command["export-all".len()..].trim().to_string();
+                if dir.is_empty() {
+                    self.set_status("✗ Usage: export-all <dir>".to_string());
+                    return Ok(false);
+                }
+                let mut service_ids = self.service_manager.list_services();
+                service_ids.sort_by(|a, b| a.0.cmp(&b.0));
+                if let Some(first) = service_ids.first() {
+                    self.progress_status =
+                        Some(format!("Exporting 1/{}: {}…", service_ids.len(), first));
+                }
+                self.pending_action = Some(PendingAction::ExportAll {
+                    dir: PathBuf::from(dir),
+                    total: service_ids.len(),
+                    remaining: service_ids,
+                    failed: Vec::new(),
+                });
+            }
+            // `<service> <path>` opens the view the service links at `path`, e.g. `iam roles`
+            command if command.contains(' ') => {
+                let (service, path) = command.split_once(' ').unwrap_or_default();
+                let link = format!("{}{}/{}", DEEP_LINK_SCHEME, service, path.trim());
+                self.goto_deep_link(&link);
+            }
             command => {
                 // Try to find service by command
-                if let Some((service_id, _)) = self.service_manager.get_service_by_command(command)
-                {
-                    let view_state = ViewState::new(service_id.clone(), ViewType::List);
-                    self.current_view = Some(view_state);
-                    self.refresh_data();
-                }
+                let Some((service_id, _)) = self.service_manager.get_service_by_command(command)
+                else {
+                    return Ok(false);
+                };
+                self.switch_to_service(service_id.clone());
             }
         }
-        Ok(())
+        Ok(true)
+    }
+
+    fn switch_to_service(&mut self, service_id: ServiceId) {
+        if self.state.last_service.as_ref() != Some(&service_id) {
+            self.state.last_service = Some(service_id.clone());
+            let _ = self.state.save();
+        }
+        self.current_view = Some(ViewState::new(service_id.clone(), ViewType::List));
+        // Prefetched list data can be shown as-is without another round trip
+        if !self.prefetched.remove(&service_id) {
+            self.refresh_data();
+        }
+    }
+
+    /// Create a client per service from `config` and register the services, replacing
+    /// any registered before
+    pub fn register_services(&mut self, config: SdkConfig) {
+        let rate_limiter = self.service_manager.rate_limiter();
+        // EC2 and VPC call the same API, so they share one client and its connections
+        let ec2_client = create_ec2_client(&config);
+        self.service_manager
+            .register_service(Arc::new(ECRService::new(
+                create_ecr_client(&config),
+                rate_limiter.clone(),
+            )));
+        self.service_manager
+            .register_service(Arc::new(StepFunctionsService::new(
+                create_sfn_client(&config),
+                rate_limiter.clone(),
+            )));
+        self.service_manager
+            .register_service(Arc::new(S3Service::new(
+                create_s3_client(&config),
+                rate_limiter.clone(),
+            )));
+        self.service_manager
+            .register_service(Arc::new(EC2Service::new(
+                ec2_client.clone(),
+                rate_limiter.clone(),
+            )));
+        self.service_manager
+            .register_service(Arc::new(VPCService::new(ec2_client, rate_limiter.clone())));
+        self.service_manager
+            .register_service(Arc::new(LambdaService::new(
+                create_lambda_client(&config),
+                rate_limiter.clone(),
+            )));
+        self.service_manager
+            .register_service(Arc::new(CloudWatchLogsService::new(
+                create_logs_client(&config),
+                rate_limiter.clone(),
+            )));
+        self.service_manager
+            .register_service(Arc::new(SecretsManagerService::new(
+                create_secrets_client(&config),
+                rate_limiter.clone(),
+            )));
+        self.service_manager
+            .register_service(Arc::new(IAMService::new(
+                create_iam_client(&config),
+                rate_limiter.clone(),
+            )));
+        self.service_manager
+            .register_service(Arc::new(RDSService::new(
+                create_rds_client(&config),
+                rate_limiter.clone(),
+            )));
+        self.service_manager
+            .register_service(Arc::new(Route53Service::new(
+                create_route53_client(&config),
+                rate_limiter,
+            )));
+        self.aws_config = Some(config);
+    }
+
+    /// Rebuild the services' clients for another region and reload the current
+    /// service's list there
+    fn switch_region(&mut self, region: &str) {
+        if !is_known_region(region) {
+            self.set_error(format!("Unknown region '{}'", region));
+            return;
+        }
+        let Some(config) = &self.aws_config else {
+            return;
+        };
+
+        let config = config
+            .to_builder()
+            .region(Region::new(region.to_string()))
+            .build();
+        self.register_services(config);
+        self.aws_region = region.to_string();
+        self.reset_loaded_data();
+    }
+
+    /// Rebuild the services' clients with another profile's credentials, staying in
+    /// the current region
+    async fn switch_profile(&mut self, profile: String) {
+        let config = load_aws_config(
+            Some(profile.clone()),
+            Some(self.aws_region.clone()),
+            self.endpoint_url.clone(),
+        )
+        .await;
+        self.register_services(config);
+        self.aws_profile = profile;
+        self.reset_loaded_data();
+    }
+
+    /// Drop everything loaded with the previous clients and reopen the current
+    /// service's list, since drill-down views may not exist with the new ones
+    fn reset_loaded_data(&mut self) {
+        self.service_manager.clear_data();
+        self.prefetch_tasks = JoinSet::new(); // Dropping the set aborts its tasks
+        self.prefetched.clear();
+        self.view_stack.clear();
+        self.last_closed_view = None;
+        if let Some(view_state) = &self.current_view {
+            self.current_view = Some(ViewState::new(
+                view_state.service_id.clone(),
+                ViewType::List,
+            ));
+            self.refresh_data();
+        }
+    }
+
+    /// All registered services in header tab order (by name)
+    pub fn tab_services(&self) -> Vec<ServiceMetadata> {
+        let mut services = self.service_manager.get_service_metadata();
+        services.sort_by(|a, b| a.name.cmp(&b.name));
+        services
+    }
+
+    /// Switch to the next (or previous) service tab, wrapping around at the ends
+    fn cycle_service(&mut self, forward: bool) {
+        let services = self.tab_services();
+        if services.is_empty() {
+            return;
+        }
+
+        let current = self.current_view.as_ref().and_then(|view_state| {
+            services
+                .iter()
+                .position(|metadata| metadata.id == view_state.service_id.0)
+        });
+        let next = match (current, forward) {
+            (Some(index), true) => (index + 1) % services.len(),
+            (Some(index), false) => (index + services.len() - 1) % services.len(),
+            (None, true) => 0,
+            (None, false) => services.len() - 1,
+        };
+
+        self.switch_to_service(ServiceId::new(&services[next].id));
+    }
+
+    /// Registered services shown on the landing screen, filtered and sorted by name
+    pub fn landing_services(&self) -> Vec<ServiceMetadata> {
+        let filter = self.landing_filter.to_lowercase();
+        let mut services: Vec<ServiceMetadata> = self
+            .service_manager
+            .get_service_metadata()
+            .into_iter()
+            .filter(|metadata| {
+                filter.is_empty()
+                    || metadata.name.to_lowercase().contains(&filter)
+                    || metadata.description.to_lowercase().contains(&filter)
+                    || metadata.command.to_lowercase().contains(&filter)
+                    || metadata
+                        .aliases
+                        .iter()
+                        .any(|alias| alias.to_lowercase().contains(&filter))
+            })
+            .collect();
+        services.sort_by(|a, b| a.name.cmp(&b.name));
+        services
     }
 
     pub fn refresh_data(&mut self) {
+        // Marks are row positions, which reloaded data may shift
+        if let Some(view_state) = &mut self.current_view {
+            view_state.selected_items.clear();
+        }
         self.loading = true;
+        self.needs_redraw = true;
         self.last_refresh = Instant::now();
         self.error_message = None;
         self.clear_expired_copy_status();
     }
 
     pub fn clear_expired_copy_status(&mut self) {
-        if let Some((_, copy_time)) = &self.copy_status {
-            if copy_time.elapsed() >= std::time::Duration::from_secs(3) {
-                self.copy_status = None;
-            }
+        if let Some((_, copy_time)) = &self.copy_status
+            && copy_time.elapsed() >= COPY_STATUS_TTL
+        {
+            self.copy_status = None;
+            self.needs_redraw = true;
         }
     }
 
     pub fn get_filtered_data_count(&self, view_state: &ViewState) -> usize {
-        if let Some(service) = self.service_manager.get_service(&view_state.service_id) {
-            if let Some(data) = self
+        if let Some(service) = self.service_manager.get_service(&view_state.service_id)
+            && let Some(data) = self
                 .service_manager
                 .get_service_data(&view_state.service_id)
-            {
-                return service.filter_data(data, &view_state.search_filter).len();
-            }
+        {
+            return service.visible_items(data, view_state).len();
         }
         0
     }
@@ -221,75 +1181,1772 @@ impl App {
         Ok(())
     }
 
-    pub fn set_error(&mut self, error: String) {
-        self.loading = false;
-        self.error_message = Some(error);
-    }
-
-    pub fn finish_loading(&mut self) {
-        self.loading = false;
-        self.error_message = None;
-
-        // Reset selected index if it's out of bounds
-        let max_index = if let Some(view_state) = &self.current_view {
-            self.get_filtered_data_count(view_state).saturating_sub(1)
-        } else {
-            0
+    pub async fn run_pending_action(&mut self) {
+        let Some(action) = self.pending_action.take() else {
+            return;
         };
+        self.needs_redraw = true;
 
-        if let Some(view_state) = &mut self.current_view {
-            if view_state.selected_index > max_index {
-                view_state.selected_index = max_index;
+        match action {
+            PendingAction::WhoAmI => self.show_whoami().await,
+            PendingAction::Ping => self.ping_current_service().await,
+            PendingAction::ExportAll {
+                dir,
+                remaining,
+                total,
+                failed,
+            } => {
+                self.export_next_service(dir, remaining, total, failed)
+                    .await
+            }
+            PendingAction::SwitchProfile { profile } => self.switch_profile(profile).await,
+            PendingAction::ServiceAction { action_id, input } => {
+                self.run_service_action(&action_id, input.as_deref()).await
             }
         }
     }
 
-    fn handle_enter_key(&mut self) {
-        if let Some(current_view) = &mut self.current_view {
-            if let Some(service) = self.service_manager.get_service(&current_view.service_id) {
-                if let Some(data) = self
-                    .service_manager
-                    .get_service_data(&current_view.service_id)
-                {
-                    if let Some(new_view) = service.handle_enter(current_view, data) {
-                        self.view_stack.push(current_view.clone());
-                        self.current_view = Some(new_view);
-                        self.refresh_data();
-                    }
-                }
-            }
+    /// Load the next of `remaining` services' list data and write it to
+    /// `<dir>/<service>.json`, then queue the rest so the footer shows progress
+    /// between services. Failures are collected and reported once all are done.
+    async fn export_next_service(
+        &mut self,
+        dir: PathBuf,
+        mut remaining: Vec<ServiceId>,
+        total: usize,
+        mut failed: Vec<String>,
+    ) {
+        if let Err(e) = fs::create_dir_all(&dir) {
+            self.progress_status = None;
+            self.set_status(format!("✗ Failed to create {}: {}", dir.display(), e));
+            return;
         }
-    }
 
-    fn handle_escape_key(&mut self) {
-        if let Some(previous_view) = self.view_stack.pop() {
-            self.current_view = Some(previous_view);
+        if !remaining.is_empty() {
+            let service_id = remaining.remove(0);
+            let view_state = ViewState::new(service_id.clone(), ViewType::List);
+            let result = match self
+                .service_manager
+                .fetch_service_data(&service_id, &view_state)
+                .await
+            {
+                Ok(Some(data)) => serde_json::to_string_pretty(&data.to_json())
+                    .map_err(anyhow::Error::from)
+                    .and_then(|json| {
+                        fs::write(dir.join(format!("{}.json", service_id)), json)
+                            .map_err(anyhow::Error::from)
+                    }),
+                Ok(None) => Ok(()),
+                Err(e) => Err(e),
+            };
+            if let Err(e) = result {
+                failed.push(format!("{} ({})", service_id, e));
+            }
+        }
+
+        if let Some(next) = remaining.first() {
+            self.progress_status = Some(format!(
+                "Exporting {}/{}: {}…",
+                total - remaining.len() + 1,
+                total,
+                next
+            ));
+            self.pending_action = Some(PendingAction::ExportAll {
+                dir,
+                remaining,
+                total,
+                failed,
+            });
+            return;
+        }
+
+        self.progress_status = None;
+        let exported = total - failed.len();
+        if failed.is_empty() {
+            self.set_status(format!(
+                "✓ Exported {} services to {}",
+                exported,
+                dir.display()
+            ));
+        } else {
+            // Not `set_error`, which would replace the current table with the message
+            self.set_status(format!(
+                "✗ Exported {}/{} services to {}; failed: {}",
+                exported,
+                total,
+                dir.display(),
+                failed.join(", ")
+            ));
+        }
+    }
+
+    async fn run_service_action(&mut self, action_id: &str, input: Option<&str>) {
+        let Some(view_state) = self.current_view.clone() else {
+            return;
+        };
+        let Some(service) = self
+            .service_manager
+            .get_service(&view_state.service_id)
+            .cloned()
+        else {
+            return;
+        };
+        let Some(data) = self
+            .service_manager
+            .get_service_data(&view_state.service_id)
+            .cloned()
+        else {
+            return;
+        };
+
+        match service
+            .run_action(action_id, &view_state, &data, input)
+            .await
+        {
+            Ok(message) => {
+                self.refresh_data();
+                self.set_status(message);
+            }
+            Err(e) => self.set_error(e.to_string()),
+        }
+    }
+
+    /// Check the current service's API is reachable and report the round-trip time
+    async fn ping_current_service(&mut self) {
+        let Some(view_state) = &self.current_view else {
+            return;
+        };
+        let Some(service) = self
+            .service_manager
+            .get_service(&view_state.service_id)
+            .cloned()
+        else {
+            return;
+        };
+        let label = self.service_manager.service_label(&view_state.service_id);
+
+        let started = Instant::now();
+        let result = service.health_check().await;
+        let latency_ms = started.elapsed().as_millis();
+
+        let message = match result {
+            Ok(()) => format!("✓ {} reachable ({} ms)", label, latency_ms),
+            Err(e) => format!("✗ {} unreachable after {} ms: {}", label, latency_ms, e),
+        };
+        self.set_status(message);
+    }
+
+    async fn show_whoami(&mut self) {
+        let mut lines = vec![("Profile".to_string(), self.aws_profile.clone())];
+
+        if let Some(config) = &self.aws_config {
+            let region = config
+                .region()
+                .map(|region| region.to_string())
+                .unwrap_or_else(|| "Not resolved".to_string());
+            lines.push(("Region".to_string(), region));
+
+            let source = match get_credential_source(config).await {
+                Ok(source) => source,
+                Err(e) => format!("Not resolved ({})", e),
+            };
+            lines.push(("Credential source".to_string(), source));
+
+            match get_caller_identity(config).await {
+                Ok(identity) => {
+                    lines.push(("Account".to_string(), identity.account));
+                    lines.push(("ARN".to_string(), identity.arn));
+                    lines.push(("User ID".to_string(), identity.user_id));
+                }
+                Err(e) => lines.push(("Identity".to_string(), format!("Unavailable ({})", e))),
+            }
+        } else {
+            lines.push(("Region".to_string(), self.aws_region.clone()));
+        }
+
+        self.popup = Some(Popup {
+            title: "Who Am I".to_string(),
+            lines,
+        });
+    }
+
+    /// Show the keys usable right now, grouped by where they apply: global keys, the
+    /// current view's table keys and service actions, then the input line keys
+    fn show_help(&mut self) {
+        let mut lines = Vec::new();
+        let mut title = "Help".to_string();
+        push_help_section(&mut lines, "Global", key_table(&GLOBAL_KEYS));
+
+        if let Some(view_state) = &self.current_view
+            && let Some(service) = self.service_manager.get_service(&view_state.service_id)
+        {
+            let label = self.service_manager.service_label(&view_state.service_id);
+            push_help_section(&mut lines, "Table", key_table(&VIEW_KEYS));
+
+            let actions: Vec<(String, String)> = service
+                .copy_actions(view_state)
+                .into_iter()
+                .chain(service.actions(view_state))
+                .map(|action| (action.key.to_string(), action.description))
+                .collect();
+            if !actions.is_empty() {
+                push_help_section(&mut lines, &label, actions);
+            }
+            title = format!("Help - {}", label);
+        }
+
+        push_help_section(&mut lines, "Command and search", key_table(&INPUT_KEYS));
+        self.popup = Some(Popup { title, lines });
+    }
+
+    /// Copy an `ats://` link to the current view that can be opened with `:goto`
+    fn copy_deep_link(&mut self) {
+        let link = self.current_view.as_ref().and_then(|view_state| {
+            let service = self.service_manager.get_service(&view_state.service_id)?;
+            let path = service.deep_link_path(view_state)?;
+            Some(if path.is_empty() {
+                format!("{}{}", DEEP_LINK_SCHEME, view_state.service_id)
+            } else {
+                format!("{}{}/{}", DEEP_LINK_SCHEME, view_state.service_id, path)
+            })
+        });
+
+        match link {
+            Some(link) => self.copy_to_clipboard(link.clone(), &link),
+            None => self.set_status("✗ This view can't be linked".to_string()),
+        }
+    }
+
+    /// View an `ats://<service>/<path>` link points at
+    fn view_from_deep_link(&self, link: &str) -> Option<ViewState> {
+        let rest = link.strip_prefix(DEEP_LINK_SCHEME)?;
+        let (service, path) = rest.split_once('/').unwrap_or((rest, ""));
+        let (service_id, service) = self.service_manager.get_service_by_command(service)?;
+        service.view_from_deep_link(
+            service_id,
+            path.trim_end_matches('/'),
+            self.service_manager.get_service_data(service_id),
+        )
+    }
+
+    /// View to open at startup for `start`, either a service command or an `ats://` link
+    pub fn startup_view(&self, start: &str) -> Option<ViewState> {
+        if start.starts_with(DEEP_LINK_SCHEME) {
+            return self.view_from_deep_link(start);
+        }
+        let (service_id, _) = self.service_manager.get_service_by_command(start)?;
+        Some(ViewState::new(service_id.clone(), ViewType::List))
+    }
+
+    /// Open the view an `ats://<service>/<path>` link points at
+    fn goto_deep_link(&mut self, link: &str) {
+        match self.view_from_deep_link(link) {
+            Some(view_state) => self.open_view(view_state),
+            None => self.set_status(format!("✗ Invalid link: {}", link)),
+        }
+    }
+
+    /// Flip an experimental feature flag for this session
+    fn toggle_experimental(&mut self, name: &str) {
+        let Some(flag) = self.experimental.get_mut(name) else {
+            self.set_status(format!(
+                "✗ Unknown feature '{}' (known: {})",
+                name,
+                ExperimentalFlags::NAMES.join(", ")
+            ));
+            return;
+        };
+        *flag = !*flag;
+        let enabled = *flag;
+
+        if name == "prefetch" {
+            if enabled {
+                self.start_prefetch();
+            } else {
+                self.prefetch_tasks.abort_all();
+            }
+        }
+
+        let state = if enabled { "enabled" } else { "disabled" };
+        self.set_status(format!("✓ {} {}", name, state));
+    }
+
+    /// Kick off background list loads for the configured prefetch services,
+    /// skipping unknown services and the one currently in view
+    pub fn start_prefetch(&mut self) {
+        if !self.experimental.prefetch {
+            return;
+        }
+        let current_service = self.current_view.as_ref().map(|v| v.service_id.clone());
+
+        for id in &self.config.prefetch {
+            let service_id = ServiceId::new(id);
+            if current_service.as_ref() == Some(&service_id) {
+                continue;
+            }
+
+            if let Some(service) = self.service_manager.get_service(&service_id) {
+                let service = service.clone();
+                self.prefetch_tasks.spawn(async move {
+                    let view_state = ViewState::new(service_id, ViewType::List);
+                    let result = service.load_data(&view_state).await;
+                    (view_state, result)
+                });
+            }
+        }
+    }
+
+    /// Collect finished prefetch loads into the service data cache
+    pub fn poll_prefetch(&mut self) {
+        while let Some(joined) = self.prefetch_tasks.try_join_next() {
+            // Failed prefetches are dropped; the regular load surfaces the error on switch
+            if let Ok((view_state, Ok(data))) = joined {
+                let in_view = self
+                    .current_view
+                    .as_ref()
+                    .is_some_and(|view| view.service_id == view_state.service_id);
+                if !in_view {
+                    self.service_manager.set_service_data(&view_state, data);
+                    self.prefetched.insert(view_state.service_id);
+                }
+            }
+            self.needs_redraw = true;
+        }
+    }
+
+    pub fn is_prefetching(&self) -> bool {
+        !self.prefetch_tasks.is_empty()
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.loading = false;
+        self.needs_redraw = true;
+        self.notify(NotificationLevel::Error, &error);
+        self.error_message = Some(error);
+    }
+
+    /// Show a short-lived message in the footer, e.g. "✓ Copied ..." or "✗ ..."
+    pub fn set_status(&mut self, message: String) {
+        let level = if message.starts_with('✗') {
+            NotificationLevel::Warning
+        } else {
+            NotificationLevel::Info
+        };
+        self.notify(level, &message);
+        self.copy_status = Some((message, Instant::now()));
+    }
+
+    fn notify(&mut self, level: NotificationLevel, message: &str) {
+        if self.notifications.len() == MAX_NOTIFICATIONS {
+            self.notifications.pop_front();
+        }
+        self.notifications.push_back(Notification {
+            at: Utc::now(),
+            level,
+            message: message.to_string(),
+        });
+    }
+
+    /// List recent status messages and errors, newest first
+    fn show_notifications(&mut self) {
+        let lines = if self.notifications.is_empty() {
+            vec![(String::new(), "No messages yet".to_string())]
+        } else {
+            self.notifications
+                .iter()
+                .rev()
+                .map(|notification| {
+                    let time = if self.utc_clock {
+                        notification.at.format("%H:%M:%SZ").to_string()
+                    } else {
+                        notification
+                            .at
+                            .with_timezone(&Local)
+                            .format("%H:%M:%S")
+                            .to_string()
+                    };
+                    let message = match notification.level {
+                        NotificationLevel::Error => format!("Error: {}", notification.message),
+                        _ => notification.message.clone(),
+                    };
+                    (time, message)
+                })
+                .collect()
+        };
+
+        self.popup = Some(Popup {
+            title: format!("Messages ({})", self.notifications.len()),
+            lines,
+        });
+    }
+
+    pub fn finish_loading(&mut self) {
+        self.loading = false;
+        self.needs_redraw = true;
+        self.error_message = None;
+
+        // Reset selected index if it's out of bounds
+        let max_index = if let Some(view_state) = &self.current_view {
+            self.get_filtered_data_count(view_state).saturating_sub(1)
+        } else {
+            0
+        };
+
+        if let Some(view_state) = &mut self.current_view
+            && view_state.selected_index > max_index
+        {
+            view_state.selected_index = max_index;
+        }
+        self.keep_selection_visible();
+    }
+
+    fn handle_enter_key(&mut self) {
+        if self.current_view.is_none() {
+            if let Some(metadata) = self.landing_services().get(self.landing_selected) {
+                self.switch_to_service(ServiceId::new(&metadata.id));
+            }
+            return;
+        }
+
+        let new_view = if let Some(current_view) = &mut self.current_view
+            && let Some(service) = self.service_manager.get_service(&current_view.service_id)
+            && let Some(data) = self
+                .service_manager
+                .get_service_data(&current_view.service_id)
+        {
+            service.handle_enter(current_view, data)
+        } else {
+            None
+        };
+
+        if let Some(new_view) = new_view {
+            self.open_view(new_view);
+        }
+    }
+
+    /// Drill-down path to the current view, e.g. "ECR > my-repo > platforms", from the
+    /// views of the same service that Esc would go back through
+    pub fn breadcrumb(&self) -> Option<String> {
+        let current = self.current_view.as_ref()?;
+        let same_service = self
+            .view_stack
+            .iter()
+            .rev()
+            .take_while(|view_state| view_state.service_id == current.service_id)
+            .count();
+        let views = self.view_stack[self.view_stack.len() - same_service..]
+            .iter()
+            .chain(std::iter::once(current));
+
+        let label = self.service_manager.service_label(&current.service_id);
+        let mut segments = Vec::new();
+        for view_state in views {
+            match &view_state.view_type {
+                ViewType::List => segments.push(label.clone()),
+                // Deep links can open a detail view with nothing behind it
+                view_type => {
+                    if segments.is_empty() {
+                        segments.push(label.clone());
+                    }
+                    segments.push(match view_type {
+                        ViewType::Custom(name) => name.clone(),
+                        _ => view_state
+                            .context
+                            .as_deref()
+                            .and_then(|context| context.split('|').next())
+                            .unwrap_or("detail")
+                            .to_string(),
+                    });
+                }
+            }
+        }
+        Some(segments.join(" > "))
+    }
+
+    /// Push the current view onto the back stack and switch to `view_state`
+    fn open_view(&mut self, view_state: ViewState) {
+        if view_state.context.is_some() {
+            self.state.record_view(&view_state);
+            let _ = self.state.save(); // Recents are best-effort
+        }
+
+        if let Some(current_view) = self.current_view.take() {
+            self.view_stack.push(current_view);
+        }
+        self.current_view = Some(view_state);
+        self.refresh_data();
+    }
+
+    fn open_recent_picker(&mut self) {
+        let items = self
+            .state
+            .recent_views
+            .iter()
+            .filter(|recent| {
+                self.service_manager
+                    .get_service(&recent.service_id)
+                    .is_some()
+            })
+            .map(|recent| PickerItem {
+                label: recent.label(),
+                target: PickerTarget::View(recent.to_view_state()),
+            })
+            .collect();
+
+        self.picker = Some(Picker {
+            title: "Recent Views".to_string(),
+            items,
+            selected_index: 0,
+            filter: None,
+            fuzzy: false,
+        });
+    }
+
+    /// Picker over every item already loaded by any service, to jump back to something
+    /// seen earlier without fetching again
+    fn open_quick_switcher(&mut self) {
+        let mut items = Vec::new();
+        for (view_state, data) in self.service_manager.loaded_data() {
+            let Some(service) = self.service_manager.get_service(&view_state.service_id) else {
+                continue;
+            };
+            // Select by position in the unfiltered view the item will be opened in
+            let mut target = view_state.clone();
+            target.search_filter.clear();
+            let view_label = RecentView::from(&target).label();
+
+            for (index, item) in service.visible_items(data, &target).iter().enumerate() {
+                let mut target = target.clone();
+                target.selected_index = index;
+                items.push(PickerItem {
+                    label: format!("{}  ({})", item.id(), view_label),
+                    target: PickerTarget::View(target),
+                });
+            }
+        }
+        items.sort_by(|a, b| a.label.cmp(&b.label));
+
+        self.picker = Some(Picker {
+            title: "Find in Loaded Views".to_string(),
+            items,
+            selected_index: 0,
+            filter: Some(String::new()),
+            fuzzy: false,
+        });
+    }
+
+    /// Fuzzy-filtered picker over every service and built-in command, for finding a
+    /// command without knowing its exact name
+    fn open_command_palette(&mut self) {
+        let mut items: Vec<PickerItem> = self
+            .service_manager
+            .get_service_metadata()
+            .into_iter()
+            .map(|metadata| PickerItem {
+                label: format!("{:<16}{}", metadata.command, metadata.name),
+                target: PickerTarget::Command(metadata.command),
+            })
+            .collect();
+        items.sort_by(|a, b| a.label.cmp(&b.label));
+
+        items.extend(
+            PALETTE_COMMANDS
+                .iter()
+                .map(|(command, description)| (command.to_string(), description.to_string()))
+                .chain(THEME_NAMES.iter().map(|name| {
+                    (
+                        format!("theme {}", name),
+                        format!("Switch to the {} color theme", name),
+                    )
+                }))
+                .map(|(command, description)| PickerItem {
+                    label: format!("{:<16}{}", command.trim_end(), description),
+                    target: PickerTarget::Command(command),
+                }),
+        );
+
+        self.picker = Some(Picker {
+            title: "Commands".to_string(),
+            items,
+            selected_index: 0,
+            filter: Some(String::new()),
+            fuzzy: true,
+        });
+    }
+
+    /// Run a command chosen in the palette, or leave it on the `:` line when it still
+    /// needs an argument
+    fn run_palette_command(&mut self, command: String) -> Result<()> {
+        self.input_buffer = command;
+        if self.input_buffer.ends_with(' ') {
+            self.input_mode = InputMode::Command;
+            return Ok(());
+        }
+        let result = self.run_command();
+        self.input_buffer.clear();
+        result
+    }
+
+    fn handle_escape_key(&mut self) {
+        if let Some(previous_view) = self.view_stack.pop() {
+            self.last_closed_view = self.current_view.replace(previous_view);
             self.refresh_data(); // Reload data for the previous view
         }
     }
 
+    /// Mark or unmark the selected row for bulk copy, then move to the next row
+    fn toggle_mark(&mut self) {
+        let Some(view_state) = &self.current_view else {
+            return;
+        };
+        if self.get_filtered_data_count(view_state) == 0 {
+            return;
+        }
+        if let Some(view_state) = &mut self.current_view {
+            let index = view_state.selected_index;
+            if !view_state.selected_items.remove(&index) {
+                view_state.selected_items.insert(index);
+            }
+        }
+        self.move_selection_by(1);
+    }
+
+    /// Copy the selected item, or every marked item one per line when rows are marked
     fn copy_selected_to_clipboard(&mut self) {
+        let Some(view_state) = &self.current_view else {
+            return;
+        };
+
+        if view_state.selected_items.is_empty() {
+            if let Some((content, display_name)) = self.copy_content(view_state) {
+                self.copy_to_clipboard(content, &display_name);
+            }
+            return;
+        }
+
+        let mut indices: Vec<usize> = view_state.selected_items.iter().copied().collect();
+        indices.sort_unstable();
+        let contents: Vec<String> = indices
+            .into_iter()
+            .filter_map(|index| {
+                let mut marked_view = view_state.clone();
+                marked_view.selected_index = index;
+                self.copy_content(&marked_view).map(|(content, _)| content)
+            })
+            .collect();
+        let display_name = format!("{} items", contents.len());
+        self.copy_to_clipboard(contents.join("\n"), &display_name);
+    }
+
+    /// What `c` copies for the selected item of `view_state`: the service's content,
+    /// or the `:copyfmt` template filled from the item's fields when the service has any
+    fn copy_content(&self, view_state: &ViewState) -> Option<(String, String)> {
+        let service = self.service_manager.get_service(&view_state.service_id)?;
+        let data = self
+            .service_manager
+            .get_service_data(&view_state.service_id)?;
+        let (content, display_name) = service.get_copy_content(view_state, data)?;
+
+        let fields = service.copy_fields(view_state, data);
+        match &self.copy_template {
+            Some(template) if !fields.is_empty() => {
+                Some((fill_template(template, &fields), display_name))
+            }
+            _ => Some((content, display_name)),
+        }
+    }
+
+    fn move_column_cursor(&mut self, forward: bool) {
+        let Some(view_state) = &self.current_view else {
+            return;
+        };
+        let Some(service) = self.service_manager.get_service(&view_state.service_id) else {
+            return;
+        };
+        let column_count = service.export_columns(view_state).len();
+
+        if let Some(view_state) = &mut self.current_view {
+            view_state.column_cursor = if forward {
+                (view_state.column_cursor + 1).min(column_count.saturating_sub(1))
+            } else {
+                view_state.column_cursor.saturating_sub(1)
+            };
+        }
+    }
+
+    /// Sort the table by the column at display position `number` (1-9). Picking the
+    /// sorted column again flips the direction; 0 restores the service's own order.
+    fn sort_by_column(&mut self, number: usize) {
+        let Some(view_state) = &self.current_view else {
+            return;
+        };
+        let Some(service) = self.service_manager.get_service(&view_state.service_id) else {
+            return;
+        };
+
+        let sort = if number == 0 {
+            None
+        } else {
+            // Numbers are display positions, so map them through the configured order
+            let columns = service.export_columns(view_state);
+            let names: Vec<&str> = columns.iter().map(String::as_str).collect();
+            let order = column_order(&names, self.config.column_order(&view_state.service_id.0));
+            let Some(&column) = order.get(number - 1) else {
+                return;
+            };
+
+            let first_value = self
+                .service_manager
+                .get_service_data(&view_state.service_id)
+                .and_then(|data| {
+                    data.items
+                        .iter()
+                        .find_map(|item| service.sort_value(item.as_ref(), column))
+                });
+            let Some(first_value) = first_value else {
+                // Not `set_error`, which would replace the table with the message
+                self.set_status(format!("✗ Can't sort by {}", columns[column]));
+                return;
+            };
+
+            // Sizes and dates start largest and newest first, text from A to Z
+            let ascending = if view_state.sort_key == Some(column) {
+                !view_state.sort_ascending
+            } else {
+                !matches!(first_value, SortValue::Number(_))
+            };
+            Some((column, ascending))
+        };
+
+        if let Some(view_state) = &mut self.current_view {
+            view_state.sort_key = sort.map(|(column, _)| column);
+            view_state.sort_ascending = sort.is_none_or(|(_, ascending)| ascending);
+            // Marks are row positions, which the new order shuffles
+            view_state.selected_index = 0;
+            view_state.scroll_offset = 0;
+            view_state.selected_items.clear();
+        }
+    }
+
+    /// Copy the selected row's value in the column under the column cursor
+    fn copy_selected_cell(&mut self) {
+        if let Some((value, column_name)) = self.selected_cell() {
+            self.copy_to_clipboard(value, &column_name);
+        }
+    }
+
+    /// Value and column name of the cell under the column cursor on the selected row
+    fn selected_cell(&self) -> Option<(String, String)> {
+        let view_state = self.current_view.as_ref()?;
+        let service = self.service_manager.get_service(&view_state.service_id)?;
+        let data = self
+            .service_manager
+            .get_service_data(&view_state.service_id)?;
+
+        // The cursor is a display position, so map it through the configured order
+        let columns = service.export_columns(view_state);
+        let names: Vec<&str> = columns.iter().map(String::as_str).collect();
+        let order = column_order(&names, self.config.column_order(&view_state.service_id.0));
+        let column = *order.get(view_state.column_cursor)?;
+
+        let rows = service.export_rows(view_state, data);
+        let value = rows.get(view_state.selected_index)?.get(column)?.clone();
+        Some((value, columns[column].clone()))
+    }
+
+    /// Show every field of the selected item in a popup, where values the table cuts
+    /// off (e.g. image digests) are shown in full. Services without their own field
+    /// list show the row's columns.
+    fn show_selected_row(&mut self) {
+        let Some(view_state) = &self.current_view else {
+            return;
+        };
+
+        // Prefer the service's full field list over the cells the table shows
+        let mut lines = match (
+            self.service_manager.get_service(&view_state.service_id),
+            self.service_manager
+                .get_service_data(&view_state.service_id),
+        ) {
+            (Some(service), Some(data)) => service.detail_lines(view_state, data),
+            _ => Vec::new(),
+        };
+        if lines.is_empty() {
+            let Some((columns, rows)) = self.current_table() else {
+                return;
+            };
+            let Some(row) = rows.into_iter().nth(view_state.selected_index) else {
+                return;
+            };
+            lines = columns.into_iter().zip(row).collect();
+        }
+
+        let title = format!(
+            "{} - Details",
+            self.service_manager.service_label(&view_state.service_id)
+        );
+        self.popup = Some(Popup { title, lines });
+    }
+
+    /// Quit, leaving the selected item's JSON for `main` to print once the terminal is
+    /// restored, so ats can be piped into e.g. `jq`
+    fn quit_with_selected_json(&mut self) {
+        if let Some(view_state) = &self.current_view
+            && let Some(service) = self.service_manager.get_service(&view_state.service_id)
+            && let Some(data) = self
+                .service_manager
+                .get_service_data(&view_state.service_id)
+            && let Some(json) = service.selected_json(view_state, data)
+        {
+            self.selected_json = Some(json);
+            self.running = false;
+        }
+    }
+
+    /// Open the selected item's AWS console page in the default browser
+    fn open_in_console(&mut self) {
+        if let Some(view_state) = &self.current_view
+            && let Some(service) = self.service_manager.get_service(&view_state.service_id)
+            && let Some(data) = self
+                .service_manager
+                .get_service_data(&view_state.service_id)
+            && let Some(url) = service.console_url(view_state, data)
+            // Silently do nothing when no browser is available, as with the clipboard
+            && open::that_detached(&url).is_ok()
+        {
+            self.set_status("✓ Opened in the AWS console".to_string());
+        }
+    }
+
+    /// Copy using one of the service's alternate copy formats
+    fn copy_selected_with(&mut self, action_id: &str) {
+        if let Some(view_state) = &self.current_view
+            && let Some(service) = self.service_manager.get_service(&view_state.service_id)
+            && let Some(data) = self
+                .service_manager
+                .get_service_data(&view_state.service_id)
+            && let Some((content, display_name)) =
+                service.get_copy_content_for(action_id, view_state, data)
+        {
+            self.copy_to_clipboard(content, &display_name);
+        }
+    }
+
+    /// Columns and visible rows of the current view, if its service can export them
+    fn current_table(&self) -> Option<(Vec<String>, Vec<Vec<String>>)> {
+        let view_state = self.current_view.as_ref()?;
+        let service = self.service_manager.get_service(&view_state.service_id)?;
+        let data = self
+            .service_manager
+            .get_service_data(&view_state.service_id)?;
+        let columns = service.export_columns(view_state);
+        if columns.is_empty() {
+            return None;
+        }
+        Some((columns, service.export_rows(view_state, data)))
+    }
+
+    /// Write the visible rows of the current view to `path` as CSV
+    fn export_table_as_csv(&mut self, path: &str) {
+        let Some((columns, rows)) = self.current_table() else {
+            self.set_status("✗ Nothing to export in this view".to_string());
+            return;
+        };
+
+        match fs::write(path, to_csv(&columns, &rows)) {
+            Ok(()) => {
+                self.set_status(format!("✓ Exported {} rows to {}", rows.len(), path));
+            }
+            Err(e) => self.set_error(format!("Failed to write {}: {}", path, e)),
+        }
+    }
+
+    /// Copy the visible rows as TSV; `args` may be `--columns a,b` to pick columns
+    fn copy_table_as_tsv(&mut self, args: &str) {
+        let wanted: Option<Vec<String>> = match args.strip_prefix("--columns") {
+            _ if args.is_empty() => None,
+            Some(list) if !list.trim().is_empty() => {
+                Some(list.split(',').map(|name| name.to_string()).collect())
+            }
+            _ => {
+                self.set_status("✗ Usage: copy-tsv [--columns a,b]".to_string());
+                return;
+            }
+        };
+
+        let Some((columns, rows)) = self.current_table() else {
+            return;
+        };
+
+        let (columns, rows) = match wanted {
+            Some(wanted) => match select_columns(&columns, &rows, &wanted) {
+                Ok(table) => table,
+                Err(e) => {
+                    self.set_status(format!("✗ {}", e));
+                    return;
+                }
+            },
+            None => (columns, rows),
+        };
+
+        let display_name = format!("{} rows as TSV", rows.len());
+        self.copy_to_clipboard(to_tsv(&columns, &rows), &display_name);
+    }
+
+    fn copy_to_clipboard(&mut self, content: String, display_name: &str) {
         let mut ctx = match ClipboardContext::new() {
             Ok(ctx) => ctx,
             Err(_) => return, // Silently fail if clipboard is not available
         };
 
-        if let Some(view_state) = &self.current_view {
-            if let Some(service) = self.service_manager.get_service(&view_state.service_id) {
-                if let Some(data) = self
-                    .service_manager
-                    .get_service_data(&view_state.service_id)
-                {
-                    if let Some((content, display_name)) =
-                        service.get_copy_content(view_state, data)
-                    {
-                        if ctx.set_contents(content).is_ok() {
-                            self.copy_status =
-                                Some((format!("✓ {} copied", display_name), Instant::now()));
-                        }
-                    }
-                }
+        let message = if self.config.copy_toast_shows_value {
+            // The footer truncates this to the terminal width
+            format!("✓ Copied {}", content.replace('\n', " "))
+        } else {
+            format!("✓ {} copied", display_name)
+        };
+
+        if ctx.set_contents(content).is_ok() {
+            self.set_status(message);
+        }
+    }
+}
+
+/// Append a group of keys to the help popup under a heading, separated from the
+/// previous group by a blank line. Headings are lines without a key.
+fn push_help_section(
+    lines: &mut Vec<(String, String)>,
+    heading: &str,
+    keys: impl IntoIterator<Item = (String, String)>,
+) {
+    if !lines.is_empty() {
+        lines.push((String::new(), String::new()));
+    }
+    lines.push((String::new(), heading.to_string()));
+    lines.extend(keys);
+}
+
+/// Owned copies of a key table's entries, for `push_help_section`
+fn key_table(keys: &[(&str, &str)]) -> Vec<(String, String)> {
+    keys.iter()
+        .map(|(key, description)| (key.to_string(), description.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::traits::{AwsService, ResourceItem};
+    use async_trait::async_trait;
+    use ratatui::{Frame, layout::Rect};
+    use std::any::Any;
+    use std::sync::Arc;
+
+    #[derive(Debug, Clone)]
+    struct StubItem(String);
+
+    impl ResourceItem for StubItem {
+        fn id(&self) -> String {
+            self.0.clone()
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn clone_box(&self) -> Box<dyn ResourceItem> {
+            Box::new(self.clone())
+        }
+
+        fn to_json(&self) -> serde_json::Value {
+            serde_json::Value::String(self.0.clone())
+        }
+    }
+
+    struct StubService;
+
+    #[async_trait]
+    impl AwsService for StubService {
+        fn metadata(&self) -> ServiceMetadata {
+            ServiceMetadata {
+                id: "stub".to_string(),
+                name: "Stub".to_string(),
+                description: "Service used in tests".to_string(),
+                command: "stub".to_string(),
+                aliases: Vec::new(),
+                short_label: None,
+                icon: None,
             }
         }
+
+        async fn load_data(&self, _view_state: &ViewState) -> Result<ResourceData> {
+            Ok(ResourceData::new(Vec::new()))
+        }
+
+        fn render(
+            &self,
+            _f: &mut Frame,
+            _area: Rect,
+            _app: &App,
+            _view_state: &ViewState,
+            _data: &ResourceData,
+        ) {
+        }
+
+        fn handle_enter(
+            &self,
+            _view_state: &mut ViewState,
+            _data: &ResourceData,
+        ) -> Option<ViewState> {
+            None
+        }
+
+        fn get_copy_content(
+            &self,
+            _view_state: &ViewState,
+            _data: &ResourceData,
+        ) -> Option<(String, String)> {
+            None
+        }
+
+        fn matches_filter(&self, item: &dyn ResourceItem, filter: &str) -> bool {
+            item.id().contains(filter)
+        }
+
+        fn fuzzy_key(&self, item: &dyn ResourceItem) -> Option<String> {
+            Some(item.id())
+        }
+
+        fn export_columns(&self, _view_state: &ViewState) -> Vec<String> {
+            vec!["ID".to_string()]
+        }
+
+        fn sort_value(&self, item: &dyn ResourceItem, column: usize) -> Option<SortValue> {
+            (column == 0).then(|| SortValue::Text(item.id()))
+        }
+
+        fn actions(&self, _view_state: &ViewState) -> Vec<ServiceAction> {
+            vec![ServiceAction {
+                id: "purge".to_string(),
+                key: 'P',
+                description: "Purge items".to_string(),
+                prompt: None,
+            }]
+        }
+
+        fn confirmation(
+            &self,
+            _action_id: &str,
+            _view_state: &ViewState,
+            data: &ResourceData,
+        ) -> Option<Confirmation> {
+            Some(Confirmation {
+                item_count: data.items.len(),
+                target: "stub-target".to_string(),
+            })
+        }
+    }
+
+    /// App showing the stub service's list view over the given item ids
+    fn app_with_items(ids: &[&str]) -> App {
+        let mut app = App::new("test".to_string(), "us-east-1".to_string());
+        app.service_manager.register_service(Arc::new(StubService));
+        let view_state = ViewState::new(ServiceId::new("stub"), ViewType::List);
+        app.service_manager.set_service_data(
+            &view_state,
+            ResourceData::new(
+                ids.iter()
+                    .map(|id| Box::new(StubItem(id.to_string())) as Box<dyn ResourceItem>)
+                    .collect(),
+            ),
+        );
+        app.current_view = Some(view_state);
+        app
+    }
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE))
+            .unwrap();
+    }
+
+    fn type_str(app: &mut App, text: &str) {
+        for c in text.chars() {
+            press(app, KeyCode::Char(c));
+        }
+    }
+
+    fn selected_index(app: &App) -> usize {
+        app.current_view.as_ref().unwrap().selected_index
+    }
+
+    #[test]
+    fn colon_enters_command_mode_and_esc_leaves_it() {
+        let mut app = app_with_items(&[]);
+
+        press(&mut app, KeyCode::Char(':'));
+        assert_eq!(app.input_mode, InputMode::Command);
+
+        type_str(&mut app, "refr");
+        assert_eq!(app.input_buffer, "refr");
+
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(app.input_buffer.is_empty());
+    }
+
+    #[test]
+    fn quit_command_stops_the_app() {
+        let mut app = app_with_items(&[]);
+
+        press(&mut app, KeyCode::Char(':'));
+        type_str(&mut app, "quit");
+        press(&mut app, KeyCode::Enter);
+
+        assert!(!app.running);
+        assert_eq!(app.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn typing_in_search_updates_the_filter_live() {
+        let mut app = app_with_items(&["alpha", "beta", "gamma"]);
+        app.current_view.as_mut().unwrap().selected_index = 2;
+
+        press(&mut app, KeyCode::Char('/'));
+        assert_eq!(app.input_mode, InputMode::Search);
+
+        type_str(&mut app, "be");
+        let view_state = app.current_view.as_ref().unwrap();
+        assert_eq!(view_state.search_filter, "be");
+        assert_eq!(view_state.selected_index, 0);
+        assert_eq!(app.get_filtered_data_count(view_state), 1);
+
+        press(&mut app, KeyCode::Backspace);
+        assert_eq!(app.current_view.as_ref().unwrap().search_filter, "b");
+
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(app.current_view.as_ref().unwrap().search_filter.is_empty());
+    }
+
+    #[test]
+    fn cached_search_results_follow_reloaded_data() {
+        let mut app = app_with_items(&["alpha", "beta", "gamma"]);
+        press(&mut app, KeyCode::Char('/'));
+        type_str(&mut app, "al");
+        let view_state = app.current_view.clone().unwrap();
+        assert_eq!(app.get_filtered_data_count(&view_state), 1);
+        assert_eq!(app.get_filtered_data_count(&view_state), 1);
+
+        // Same length and search, different items: the cache must not be reused
+        app.service_manager.set_service_data(
+            &view_state,
+            ResourceData::new(
+                ["alps", "alto", "beta"]
+                    .iter()
+                    .map(|id| Box::new(StubItem(id.to_string())) as Box<dyn ResourceItem>)
+                    .collect(),
+            ),
+        );
+        assert_eq!(app.get_filtered_data_count(&view_state), 2);
+    }
+
+    #[test]
+    fn narrowing_search_clamps_selection_to_the_filtered_rows() {
+        let mut app = app_with_items(&["app-1", "app-2", "db-1", "db-2", "db-3"]);
+        app.current_view.as_mut().unwrap().selected_index = 4;
+
+        press(&mut app, KeyCode::Char('/'));
+        type_str(&mut app, "app");
+        assert_eq!(selected_index(&app), 1);
+
+        press(&mut app, KeyCode::Backspace);
+        assert_eq!(selected_index(&app), 1);
+    }
+
+    #[test]
+    fn search_on_landing_screen_filters_services() {
+        let mut app = App::new("test".to_string(), "us-east-1".to_string());
+        app.landing_selected = 1;
+
+        press(&mut app, KeyCode::Char('/'));
+        type_str(&mut app, "ec");
+        press(&mut app, KeyCode::Enter);
+
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.landing_filter, "ec");
+        assert_eq!(app.landing_selected, 0);
+    }
+
+    #[test]
+    fn selection_moves_within_bounds() {
+        let mut app = app_with_items(&["a", "b"]);
+
+        press(&mut app, KeyCode::Char('k'));
+        assert_eq!(selected_index(&app), 0);
+
+        press(&mut app, KeyCode::Char('j'));
+        press(&mut app, KeyCode::Down);
+        assert_eq!(selected_index(&app), 1);
+    }
+
+    #[test]
+    fn ctrl_o_reopens_the_view_closed_with_esc() {
+        let mut app = app_with_items(&["a", "b"]);
+        let list_view = app.current_view.take().unwrap();
+        app.view_stack.push(list_view);
+        let mut detail_view = ViewState::new(ServiceId::new("stub"), ViewType::Detail);
+        detail_view.selected_index = 1;
+        app.current_view = Some(detail_view);
+
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.current_view.as_ref().unwrap().view_type, ViewType::List);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::CONTROL))
+            .unwrap();
+        let view_state = app.current_view.as_ref().unwrap();
+        assert_eq!(view_state.view_type, ViewType::Detail);
+        assert_eq!(view_state.selected_index, 1);
+        assert_eq!(app.view_stack.len(), 1);
+        assert!(app.last_closed_view.is_none());
+    }
+
+    #[test]
+    fn small_batches_confirm_with_y() {
+        let mut app = app_with_items(&["a", "b"]);
+        app.config.confirm_by_name_threshold = 2;
+
+        press(&mut app, KeyCode::Char('P'));
+        assert_eq!(app.input_mode, InputMode::Prompt);
+        type_str(&mut app, "Y");
+        press(&mut app, KeyCode::Enter);
+
+        assert!(matches!(
+            app.pending_action,
+            Some(PendingAction::ServiceAction { ref action_id, .. }) if action_id == "purge"
+        ));
+    }
+
+    #[test]
+    fn large_batches_require_typing_the_target_name() {
+        let mut app = app_with_items(&["a", "b", "c"]);
+        app.config.confirm_by_name_threshold = 2;
+
+        press(&mut app, KeyCode::Char('P'));
+        type_str(&mut app, "y");
+        press(&mut app, KeyCode::Enter);
+        assert!(app.pending_action.is_none());
+        assert_eq!(app.input_mode, InputMode::Normal);
+
+        press(&mut app, KeyCode::Char('P'));
+        type_str(&mut app, "stub-target");
+        press(&mut app, KeyCode::Enter);
+        assert!(app.pending_action.is_some());
+    }
+
+    #[test]
+    fn export_all_reports_progress_and_writes_each_service() {
+        let mut app = app_with_items(&["a"]);
+        let dir = std::env::temp_dir().join(format!("ats-export-all-{}", std::process::id()));
+
+        press(&mut app, KeyCode::Char(':'));
+        type_str(&mut app, &format!("export-all {}", dir.display()));
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.progress_status.as_deref(), Some("Exporting 1/1: stub…"));
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        while app.pending_action.is_some() {
+            runtime.block_on(app.run_pending_action());
+        }
+        assert!(app.progress_status.is_none());
+        assert!(app.error_message.is_none());
+        assert!(dir.join("stub.json").exists());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn export_all_without_a_directory_shows_usage() {
+        let mut app = app_with_items(&[]);
+
+        press(&mut app, KeyCode::Char(':'));
+        type_str(&mut app, "export-all  ");
+        press(&mut app, KeyCode::Enter);
+        assert!(app.pending_action.is_none());
+        assert_eq!(
+            app.copy_status
+                .as_ref()
+                .map(|(message, _)| message.as_str()),
+            Some("✗ Usage: export-all <dir>")
+        );
+        assert!(app.command_history.is_empty());
+    }
+
+    #[test]
+    fn toggle_flips_known_flags_and_warns_on_unknown_ones() {
+        let mut app = app_with_items(&[]);
+
+        press(&mut app, KeyCode::Char(':'));
+        type_str(&mut app, "toggle zebra_rows");
+        press(&mut app, KeyCode::Enter);
+        assert!(app.experimental.zebra_rows);
+
+        press(&mut app, KeyCode::Char(':'));
+        type_str(&mut app, "toggle warp_drive");
+        press(&mut app, KeyCode::Enter);
+        assert!(app.running);
+        let (message, _) = app.copy_status.as_ref().unwrap();
+        assert!(message.contains("Unknown feature 'warp_drive'"));
+    }
+
+    #[test]
+    fn tab_cycles_through_services_and_wraps() {
+        let mut app = app_with_items(&[]);
+        app.current_view = None;
+
+        press(&mut app, KeyCode::Tab);
+        assert_eq!(app.current_view.as_ref().unwrap().service_id.0, "stub");
+
+        // A single service wraps onto itself in both directions
+        press(&mut app, KeyCode::Tab);
+        press(&mut app, KeyCode::BackTab);
+        let view_state = app.current_view.as_ref().unwrap();
+        assert_eq!(view_state.service_id.0, "stub");
+        assert_eq!(view_state.view_type, ViewType::List);
+    }
+
+    #[test]
+    fn goto_opens_linked_views_and_rejects_bad_links() {
+        let mut app = app_with_items(&[]);
+        app.current_view = None;
+
+        app.goto_deep_link("ats://stub");
+        assert_eq!(app.current_view.as_ref().unwrap().service_id.0, "stub");
+
+        app.goto_deep_link("ats://stub/unknown/path");
+        let (message, _) = app.copy_status.as_ref().unwrap();
+        assert!(message.starts_with("✗ Invalid link"));
+        assert_eq!(app.view_stack.len(), 0);
+    }
+
+    #[test]
+    fn quick_switcher_filters_loaded_items_and_opens_their_view() {
+        let mut app = app_with_items(&["alpha", "beta", "gamma"]);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL))
+            .unwrap();
+        assert_eq!(app.picker.as_ref().unwrap().visible_items().len(), 3);
+
+        // Typed characters filter instead of navigating or closing
+        type_str(&mut app, "gam");
+        let picker = app.picker.as_ref().unwrap();
+        assert_eq!(picker.visible_items().len(), 1);
+
+        press(&mut app, KeyCode::Enter);
+        assert!(app.picker.is_none());
+        assert_eq!(selected_index(&app), 2);
+        assert_eq!(app.view_stack.len(), 1);
+    }
+
+    #[test]
+    fn number_keys_sort_by_column_and_flip_on_repeat() {
+        let mut app = app_with_items(&["bravo", "alpha", "charlie"]);
+        let ids = |app: &App| -> Vec<String> {
+            let view_state = app.current_view.as_ref().unwrap();
+            let data = app
+                .service_manager
+                .get_service_data(&view_state.service_id)
+                .unwrap();
+            StubService
+                .visible_items(data, view_state)
+                .iter()
+                .map(|item| item.id())
+                .collect()
+        };
+
+        press(&mut app, KeyCode::Char('1'));
+        assert_eq!(ids(&app), ["alpha", "bravo", "charlie"]);
+        press(&mut app, KeyCode::Char('1'));
+        assert_eq!(ids(&app), ["charlie", "bravo", "alpha"]);
+        press(&mut app, KeyCode::Char('0'));
+        assert_eq!(ids(&app), ["bravo", "alpha", "charlie"]);
+
+        // The stub has a single column
+        press(&mut app, KeyCode::Char('2'));
+        assert_eq!(app.current_view.as_ref().unwrap().sort_key, None);
+    }
+
+    #[test]
+    fn p_pauses_and_resumes_auto_refresh() {
+        let mut app = app_with_items(&["alpha"]);
+
+        press(&mut app, KeyCode::Char('p'));
+        assert!(app.paused);
+        press(&mut app, KeyCode::Char('p'));
+        assert!(!app.paused);
+    }
+
+    #[test]
+    fn copyfmt_sets_and_resets_the_copy_template() {
+        let mut app = app_with_items(&[]);
+
+        press(&mut app, KeyCode::Char(':'));
+        type_str(&mut app, "copyfmt docker pull {ref}");
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.copy_template.as_deref(), Some("docker pull {ref}"));
+
+        press(&mut app, KeyCode::Char(':'));
+        type_str(&mut app, "copyfmt");
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.copy_template, None);
+    }
+
+    #[test]
+    fn command_palette_fuzzy_matches_and_runs_commands() {
+        let mut app = app_with_items(&[]);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::CONTROL))
+            .unwrap();
+        type_str(&mut app, "rgn");
+        let picker = app.picker.as_ref().unwrap();
+        assert!(picker.visible_items()[0].label.starts_with("region"));
+
+        // Commands that take an argument wait for it on the `:` line
+        press(&mut app, KeyCode::Enter);
+        assert!(app.picker.is_none());
+        assert_eq!(app.input_mode, InputMode::Command);
+        assert_eq!(app.input_buffer, "region ");
+
+        press(&mut app, KeyCode::Esc);
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::CONTROL))
+            .unwrap();
+        type_str(&mut app, "quit");
+        press(&mut app, KeyCode::Enter);
+        assert!(!app.running);
+        assert_eq!(app.command_history, ["quit"]);
+    }
+
+    #[test]
+    fn moving_past_the_viewport_scrolls_one_row_at_a_time() {
+        let ids: Vec<String> = (0..20).map(|i| format!("item-{}", i)).collect();
+        let mut app = app_with_items(&ids.iter().map(String::as_str).collect::<Vec<_>>());
+        app.content_area.set(Rect::new(0, 0, 80, 8)); // 5 rows once borders and the header are drawn
+
+        for _ in 0..5 {
+            press(&mut app, KeyCode::Char('j'));
+        }
+        assert_eq!(app.current_view.as_ref().unwrap().scroll_offset, 1);
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(app.current_view.as_ref().unwrap().scroll_offset, 2);
+
+        // Moving back up keeps the window until the selection reaches its top
+        for _ in 0..4 {
+            press(&mut app, KeyCode::Char('k'));
+        }
+        assert_eq!(app.current_view.as_ref().unwrap().scroll_offset, 2);
+        press(&mut app, KeyCode::Char('k'));
+        assert_eq!(app.current_view.as_ref().unwrap().scroll_offset, 1);
+    }
+
+    #[test]
+    fn paging_keys_jump_by_the_viewport_and_clamp_to_the_list() {
+        let ids: Vec<String> = (0..30).map(|i| format!("item-{}", i)).collect();
+        let mut app = app_with_items(&ids.iter().map(String::as_str).collect::<Vec<_>>());
+        app.content_area.set(Rect::new(0, 0, 80, 23)); // 20 rows once borders and the header are drawn
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL))
+            .unwrap();
+        assert_eq!(selected_index(&app), 10);
+
+        press(&mut app, KeyCode::PageDown);
+        assert_eq!(selected_index(&app), 29);
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL))
+            .unwrap();
+        assert_eq!(selected_index(&app), 19);
+
+        press(&mut app, KeyCode::PageUp);
+        assert_eq!(selected_index(&app), 0);
+    }
+
+    #[test]
+    fn ctrl_y_quits_with_the_selected_item_as_json() {
+        let mut app = app_with_items(&["a", "b"]);
+        press(&mut app, KeyCode::Char('j'));
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::CONTROL))
+            .unwrap();
+        assert!(!app.running);
+        assert_eq!(app.selected_json.as_deref(), Some("\"b\""));
+    }
+
+    #[test]
+    fn gg_and_shift_g_jump_to_the_ends() {
+        let mut app = app_with_items(&["a", "b", "c", "d"]);
+
+        press(&mut app, KeyCode::Char('G'));
+        assert_eq!(selected_index(&app), 3);
+
+        // A single `g` waits for the second; another key in between cancels it
+        press(&mut app, KeyCode::Char('g'));
+        press(&mut app, KeyCode::Char('k'));
+        press(&mut app, KeyCode::Char('g'));
+        assert_eq!(selected_index(&app), 2);
+        press(&mut app, KeyCode::Char('g'));
+        assert_eq!(selected_index(&app), 0);
+    }
+
+    #[test]
+    fn help_lists_the_current_views_actions() {
+        let mut app = app_with_items(&[]);
+
+        press(&mut app, KeyCode::Char('?'));
+        let popup = app.popup.as_ref().unwrap();
+        assert_eq!(popup.title, "Help - stub");
+        assert!(popup.lines.iter().any(|(key, _)| key == "P"));
+        assert!(
+            popup
+                .lines
+                .iter()
+                .any(|line| line == &(String::new(), "stub".to_string()))
+        );
+
+        press(&mut app, KeyCode::Char('?'));
+        assert!(app.popup.is_none());
+        app.current_view = None;
+        press(&mut app, KeyCode::Char('?'));
+        let popup = app.popup.as_ref().unwrap();
+        assert!(
+            popup
+                .lines
+                .iter()
+                .all(|(key, _)| key != "P" && key != "Enter")
+        );
+    }
+
+    #[test]
+    fn startup_view_accepts_commands_and_links() {
+        let app = app_with_items(&[]);
+
+        let view_state = app.startup_view("stub").unwrap();
+        assert_eq!(view_state.service_id.0, "stub");
+        assert_eq!(view_state.view_type, ViewType::List);
+
+        assert!(app.startup_view("ats://stub").is_some());
+        assert!(app.startup_view("unknown").is_none());
+        assert!(app.startup_view("ats://stub/unknown/path").is_none());
+    }
+
+    #[test]
+    fn finish_loading_clamps_selection_to_the_data() {
+        let mut app = app_with_items(&["a", "b", "c"]);
+        app.current_view.as_mut().unwrap().selected_index = 10;
+        app.loading = true;
+
+        app.finish_loading();
+
+        assert!(!app.loading);
+        assert_eq!(selected_index(&app), 2);
+    }
+
+    #[test]
+    fn command_mode_recalls_previous_commands() {
+        let mut app = app_with_items(&[]);
+        for command in ["group", "group", "whoami"] {
+            press(&mut app, KeyCode::Char(':'));
+            type_str(&mut app, command);
+            press(&mut app, KeyCode::Enter);
+        }
+        assert_eq!(app.command_history, vec!["group", "whoami"]);
+
+        press(&mut app, KeyCode::Char(':'));
+        press(&mut app, KeyCode::Up);
+        assert_eq!(app.input_buffer, "whoami");
+        press(&mut app, KeyCode::Up);
+        press(&mut app, KeyCode::Up);
+        assert_eq!(app.input_buffer, "group");
+        press(&mut app, KeyCode::Down);
+        assert_eq!(app.input_buffer, "whoami");
+        press(&mut app, KeyCode::Down);
+        assert_eq!(app.input_buffer, "");
+    }
+
+    #[test]
+    fn failed_commands_are_not_recalled() {
+        let mut app = app_with_items(&[]);
+        for command in ["group", "nosuchservice", "theme nosuchtheme"] {
+            press(&mut app, KeyCode::Char(':'));
+            type_str(&mut app, command);
+            press(&mut app, KeyCode::Enter);
+        }
+        assert_eq!(app.command_history, vec!["group"]);
+    }
+
+    #[test]
+    fn space_marks_rows_until_the_view_refreshes() {
+        let mut app = app_with_items(&["a", "b", "c"]);
+
+        press(&mut app, KeyCode::Char(' '));
+        press(&mut app, KeyCode::Char(' '));
+        assert_eq!(selected_index(&app), 2);
+        press(&mut app, KeyCode::Up);
+        press(&mut app, KeyCode::Char(' '));
+
+        let marked = &app.current_view.as_ref().unwrap().selected_items;
+        assert_eq!(marked, &HashSet::from([0]));
+
+        press(&mut app, KeyCode::Char('r'));
+        assert!(app.current_view.as_ref().unwrap().selected_items.is_empty());
+    }
+
+    #[test]
+    fn region_command_rejects_unknown_regions() {
+        let mut app = app_with_items(&["a"]);
+        let region = app.aws_region.clone();
+
+        press(&mut app, KeyCode::Char(':'));
+        type_str(&mut app, "region us-esat-1");
+        press(&mut app, KeyCode::Enter);
+
+        assert_eq!(app.aws_region, region);
+        assert_eq!(
+            app.error_message.as_deref(),
+            Some("Unknown region 'us-esat-1'")
+        );
+    }
+
+    #[test]
+    fn theme_command_switches_known_themes_only() {
+        let mut app = app_with_items(&["a"]);
+
+        press(&mut app, KeyCode::Char(':'));
+        type_str(&mut app, "theme monochrome");
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(Some(app.theme), Theme::by_name("monochrome"));
+
+        press(&mut app, KeyCode::Char(':'));
+        type_str(&mut app, "theme neon");
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(Some(app.theme), Theme::by_name("monochrome"));
+        assert!(
+            app.error_message
+                .unwrap()
+                .starts_with("Unknown theme 'neon'")
+        );
+    }
+
+    #[test]
+    fn backspace_removes_whole_characters_from_the_search() {
+        let mut app = app_with_items(&["레포", "repo"]);
+
+        press(&mut app, KeyCode::Char('/'));
+        type_str(&mut app, "레포");
+        press(&mut app, KeyCode::Backspace);
+
+        assert_eq!(app.input_buffer, "레");
+        assert_eq!(
+            app.get_filtered_data_count(app.current_view.as_ref().unwrap()),
+            1
+        );
+    }
+
+    #[test]
+    fn ctrl_f_switches_to_fuzzy_search_with_best_matches_first() {
+        let mut app = app_with_items(&["rapid-deploy", "production-api-server", "api-gateway"]);
+        let visible_ids = |app: &App| {
+            let view_state = app.current_view.as_ref().unwrap();
+            let data = app
+                .service_manager
+                .get_service_data(&view_state.service_id)
+                .unwrap();
+            StubService
+                .visible_items(data, view_state)
+                .iter()
+                .map(|item| item.id())
+                .collect::<Vec<_>>()
+        };
+
+        press(&mut app, KeyCode::Char('/'));
+        type_str(&mut app, "prd-api");
+        assert!(visible_ids(&app).is_empty());
+
+        app.handle_key_event(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL))
+            .unwrap();
+        assert_eq!(app.input_buffer, "prd-api");
+        assert_eq!(visible_ids(&app), vec!["production-api-server"]);
+
+        for _ in 0.."prd-".len() {
+            press(&mut app, KeyCode::Backspace);
+        }
+        assert_eq!(
+            visible_ids(&app),
+            vec!["api-gateway", "production-api-server", "rapid-deploy"]
+        );
+    }
+
+    #[test]
+    fn breadcrumb_follows_the_drill_down_path() {
+        let mut app = app_with_items(&["a"]);
+        assert_eq!(app.breadcrumb().unwrap(), "stub");
+
+        let mut detail_view = ViewState::new(ServiceId::new("stub"), ViewType::Detail);
+        detail_view.context = Some("my-repo|extra".to_string());
+        let list_view = app.current_view.replace(detail_view).unwrap();
+        app.view_stack
+            .push(ViewState::new(ServiceId::new("other"), ViewType::List));
+        app.view_stack.push(list_view);
+        assert_eq!(app.breadcrumb().unwrap(), "stub > my-repo");
+
+        let detail_view = app
+            .current_view
+            .replace(ViewState::new(
+                ServiceId::new("stub"),
+                ViewType::Custom("platforms".to_string()),
+            ))
+            .unwrap();
+        app.view_stack.push(detail_view);
+        assert_eq!(app.breadcrumb().unwrap(), "stub > my-repo > platforms");
+
+        app.view_stack.clear();
+        assert_eq!(app.breadcrumb().unwrap(), "stub > platforms");
+    }
+
+    #[test]
+    fn notifications_keep_the_most_recent_messages() {
+        let mut app = app_with_items(&[]);
+        for i in 0..MAX_NOTIFICATIONS {
+            app.set_status(format!("✓ {}", i));
+        }
+        app.set_error("boom".to_string());
+
+        assert_eq!(app.notifications.len(), MAX_NOTIFICATIONS);
+        assert_eq!(app.notifications.front().unwrap().message, "✓ 1");
+        let newest = app.notifications.back().unwrap();
+        assert_eq!(newest.level, NotificationLevel::Error);
+        assert_eq!(newest.message, "boom");
+    }
+
+    #[test]
+    fn clicking_a_row_selects_it_and_a_second_click_opens_it() {
+        let mut app = app_with_items(&["a", "b", "c"]);
+        app.content_area.set(Rect::new(0, 3, 80, 10));
+        let click = |app: &mut App, row: u16| {
+            app.handle_mouse_event(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column: 10,
+                row,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+
+        // Border at y=3 and header at y=4, so the third item is at y=7
+        click(&mut app, 7);
+        assert_eq!(selected_index(&app), 2);
+        click(&mut app, 9); // Below the last item
+        assert_eq!(selected_index(&app), 2);
+
+        click(&mut app, 6);
+        assert_eq!(selected_index(&app), 1);
+        assert!(app.last_click.is_some());
+        click(&mut app, 6); // Opened like Enter, so the next click starts over
+        assert!(app.last_click.is_none());
     }
 }
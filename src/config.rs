@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fs, path::PathBuf};
+
+/// User configuration persisted as JSON under the platform config directory
+/// (e.g. `~/.config/ats/config.json`). Missing fields fall back to defaults.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Service ids whose list data is loaded in the background at startup
+    pub prefetch: Vec<String>,
+    /// Show the header clock in UTC instead of local time
+    pub utc_clock: bool,
+    /// Attempts made for the first data load before showing an error (1 disables retries)
+    pub initial_load_attempts: u32,
+    /// Cap on AWS requests per second shared by all services (0 disables the cap)
+    pub requests_per_second: f64,
+    /// Show the copied value itself in the copy toast instead of its display name
+    pub copy_toast_shows_value: bool,
+    /// Destructive batch actions affecting more items than this require typing the
+    /// target's name instead of `y` to confirm
+    pub confirm_by_name_threshold: usize,
+    /// Column order overrides per service id, e.g. `{"ecr": ["ENCRYPTION", "CREATED"]}`
+    pub column_order: HashMap<String, Vec<String>>,
+    /// View opened at startup per AWS profile, as a service command or an `ats://`
+    /// link, e.g. `{"prod": "sfn", "dev": "ats://ecr/my-repo/images"}`
+    pub default_views: HashMap<String, String>,
+    /// Opt-in features that can also be flipped at runtime with `:toggle <feature>`
+    pub experimental: ExperimentalFlags,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            prefetch: Vec::new(),
+            utc_clock: false,
+            initial_load_attempts: 3,
+            requests_per_second: 10.0,
+            copy_toast_shows_value: false,
+            confirm_by_name_threshold: 10,
+            column_order: HashMap::new(),
+            default_views: HashMap::new(),
+            experimental: ExperimentalFlags::default(),
+        }
+    }
+}
+
+/// Feature flags for experimental behavior, copied onto `App` at startup so they
+/// can be toggled without editing the config
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExperimentalFlags {
+    /// Load the `prefetch` services in the background
+    pub prefetch: bool,
+    /// Shade alternate table rows to make wide tables easier to scan
+    pub zebra_rows: bool,
+}
+
+impl Default for ExperimentalFlags {
+    fn default() -> Self {
+        Self {
+            prefetch: true,
+            zebra_rows: false,
+        }
+    }
+}
+
+impl ExperimentalFlags {
+    pub const NAMES: [&str; 2] = ["prefetch", "zebra_rows"];
+
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut bool> {
+        match name {
+            "prefetch" => Some(&mut self.prefetch),
+            "zebra_rows" => Some(&mut self.zebra_rows),
+            _ => None,
+        }
+    }
+}
+
+/// Directory holding ats' config and state files
+pub fn config_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("ats"))
+}
+
+impl Config {
+    pub fn path() -> Option<PathBuf> {
+        config_dir().map(|dir| dir.join("config.json"))
+    }
+
+    /// Load the config file, falling back to defaults if it is missing or invalid
+    pub fn load() -> Self {
+        Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Column order override for a service's tables (empty for the default order)
+    pub fn column_order(&self, service_id: &str) -> &[String] {
+        self.column_order
+            .get(service_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// View configured to open at startup for `profile`, if any
+    pub fn default_view(&self, profile: &str) -> Option<&str> {
+        self.default_views.get(profile).map(String::as_str)
+    }
+}
@@ -1,29 +1,43 @@
-use anyhow::Result;
-use clap::Parser;
+use anyhow::{Result, anyhow};
+use clap::{Parser, ValueEnum};
 use crossterm::{
+    cursor,
     event::{self, DisableMouseCapture, EnableMouseCapture, Event},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
 use std::{
-    io,
+    io::{self, Stderr},
+    panic, thread,
     time::{Duration, Instant},
 };
 use tokio::time::sleep;
 
 mod app;
+mod config;
 mod services;
+mod state;
 mod ui;
 mod utils;
 
-use app::App;
-use services::{
-    ecr::ECRService,
-    traits::{ServiceId, ViewState, ViewType},
+use app::{App, COPY_STATUS_TTL};
+use config::Config;
+use services::traits::ViewState;
+use state::State;
+use ui::{
+    layout::render_layout,
+    theme::{THEME_NAMES, Theme},
 };
-use std::sync::Arc;
-use ui::layout::render_layout;
+use utils::rate_limit::RateLimiter;
+
+/// Service printed by --output when neither --service, the profile's default view,
+/// nor the last used service names one; the TUI shows the services landing screen
+const DEFAULT_SERVICE: &str = "ecr";
+
+const CLOCK_TICK: Duration = Duration::from_secs(1);
+// Background prefetches can't wake the input poll, so check on them periodically
+const PREFETCH_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
 #[derive(Parser)]
 #[command(name = "ats")]
@@ -35,22 +49,52 @@ struct Args {
 
     #[arg(short = 'r', long = "region", help = "AWS region to use")]
     region: Option<String>,
+
+    #[arg(
+        long = "endpoint-url",
+        help = "Send all AWS requests to this endpoint, e.g. LocalStack [env: AWS_ENDPOINT_URL]"
+    )]
+    endpoint_url: Option<String>,
+
+    #[arg(
+        short = 's',
+        long = "service",
+        help = "Service to open, by command name or ats:// link [default: the profile's default view, else the last used service, else the services list]"
+    )]
+    service: Option<String>,
+
+    #[arg(
+        short = 'o',
+        long = "output",
+        value_enum,
+        help = "Print the service's data in this format and exit instead of starting the TUI"
+    )]
+    output: Option<OutputFormat>,
+
+    #[arg(
+        long = "refresh-interval",
+        value_name = "SECS",
+        help = "Seconds between automatic reloads of the current view, 0 to disable [default: 30]"
+    )]
+    refresh_interval: Option<u64>,
+
+    #[arg(
+        long = "theme",
+        value_name = "NAME",
+        help = "Color theme: default, monochrome, or solarized [default: default]"
+    )]
+    theme: Option<String>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Json,
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = Args::parse();
 
-    // Setup terminal
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
-
-    // Clear the terminal
-    terminal.clear()?;
-
     // Determine actual profile and region being used
     let actual_profile = args
         .profile
@@ -67,44 +111,163 @@ async fn main() -> Result<()> {
 
     // Create app state with actual AWS config
     let mut app = App::new(actual_profile, actual_region);
+    app.config = Config::load();
+    app.utc_clock = app.config.utc_clock;
+    app.experimental = app.config.experimental.clone();
+    app.state = State::load();
+    if let Some(secs) = args.refresh_interval {
+        app.refresh_interval = (secs > 0).then(|| Duration::from_secs(secs));
+    }
+    if let Some(name) = &args.theme {
+        app.theme = Theme::by_name(name).ok_or_else(|| {
+            anyhow!(
+                "Unknown theme '{}'. Available: {}",
+                name,
+                THEME_NAMES.join(", ")
+            )
+        })?;
+    }
+
+    // Resolve AWS config once and share it across clients
+    app.endpoint_url = args
+        .endpoint_url
+        .or_else(|| std::env::var("AWS_ENDPOINT_URL").ok())
+        .filter(|url| !url.is_empty());
+    let aws_config =
+        utils::aws::load_aws_config(args.profile, args.region, app.endpoint_url.clone()).await;
+
+    // Share one request budget across all services
+    app.service_manager
+        .set_rate_limiter(RateLimiter::new(app.config.requests_per_second));
+
+    // Create a client per service and register the services
+    app.register_services(aws_config);
 
-    // Create ECR client and service
-    let ecr_client = utils::aws::create_ecr_client(args.profile, args.region).await?;
-    let ecr_service = ECRService::new(ecr_client);
+    // An explicit --service wins over the profile's configured default view, which
+    // wins over the service used last time (if it is still registered)
+    let start = args
+        .service
+        .or_else(|| {
+            app.config
+                .default_view(&app.aws_profile)
+                .map(str::to_string)
+        })
+        .or_else(|| {
+            app.state
+                .last_service
+                .as_ref()
+                .filter(|service_id| app.service_manager.get_service(service_id).is_some())
+                .map(ToString::to_string)
+        });
+    let startup_view = |start: &str| {
+        app.startup_view(start)
+            .ok_or_else(|| anyhow!("Unknown service or link '{}'", start))
+    };
 
-    // Register ECR service
-    app.service_manager.register_service(Arc::new(ecr_service));
+    // Headless mode: print the data and exit without touching the terminal
+    if let Some(format) = args.output {
+        let initial_view = startup_view(start.as_deref().unwrap_or(DEFAULT_SERVICE))?;
+        return print_service_data(&app, &initial_view, format).await;
+    }
+
+    // With nothing to open, start on the services landing screen to pick one
+    app.current_view = start.as_deref().map(startup_view).transpose()?;
 
-    // Set initial view to ECR
-    let ecr_service_id = ServiceId::new("ecr");
-    let initial_view = ViewState::new(ecr_service_id, ViewType::List);
-    app.current_view = Some(initial_view);
+    // Setup terminal
+    install_panic_hook();
+    enable_raw_mode()?;
+    // Draw on stderr so stdout only carries what Ctrl-y prints, e.g. for `ats | jq`
+    let mut stderr = io::stderr();
+    execute!(stderr, EnterAlternateScreen, EnableMouseCapture)?;
+    let backend = CrosstermBackend::new(stderr);
+    let mut terminal = Terminal::new(backend)?;
+
+    // Errors leave the loop too, so restore the terminal before reporting them
+    let result = run_app(&mut terminal, &mut app).await;
+    restore_terminal();
+    terminal.show_cursor()?;
+    result?;
+
+    if let Some(json) = app.selected_json {
+        println!("{}", json);
+    }
+
+    Ok(())
+}
+
+/// Load the first view and run the event loop until the user quits
+async fn run_app(terminal: &mut Terminal<CrosstermBackend<Stderr>>, app: &mut App) -> Result<()> {
+    // Clear the terminal
+    terminal.clear()?;
 
     // Initial data load
     app.refresh_data();
-    if let Err(e) = app.load_current_service_data().await {
-        app.set_error(e.to_string());
-    } else {
-        app.finish_loading();
+    terminal.draw(|f| render_layout(f, app))?;
+
+    // Prefetch other configured services in the background
+    app.start_prefetch();
+
+    // Retry connection failures (e.g. a flaky VPN) with backoff before showing the error.
+    // Throttling and server errors are already retried per request, and credential,
+    // access, and not-found errors show right away.
+    let max_attempts = app.config.initial_load_attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        match app.load_current_service_data().await {
+            Ok(()) => {
+                app.finish_loading();
+                break;
+            }
+            Err(e) if attempt < max_attempts && utils::aws::is_connection_error(&e) => {
+                attempt += 1;
+                app.progress_status = Some(format!("Retrying ({}/{})…", attempt, max_attempts));
+                terminal.draw(|f| render_layout(f, app))?;
+                sleep(Duration::from_millis(500 * 2u64.pow(attempt - 2))).await;
+            }
+            Err(e) => {
+                app.set_error(utils::aws::describe_load_error(&e));
+                break;
+            }
+        }
     }
+    app.progress_status = None;
 
     // Main application loop
     let mut last_tick = Instant::now();
-    let _tick_rate = Duration::from_millis(250);
+
+    let mut last_draw = Instant::now();
 
     while app.running {
-        // Handle events
-        if event::poll(Duration::from_millis(50))? {
-            if let Event::Key(key) = event::read()? {
-                app.handle_key_event(key)?;
+        // Block on input until the next scheduled wake-up
+        if event::poll(poll_timeout(app, last_tick, last_draw))? {
+            match event::read()? {
+                Event::Key(key) => app.handle_key_event(key)?,
+                Event::Mouse(mouse) => app.handle_mouse_event(mouse),
+                Event::Resize(_, _) => app.needs_redraw = true,
+                _ => {}
             }
         }
 
-        // Auto-refresh data every 30 seconds or when requested
-        if app.loading || last_tick.elapsed() >= Duration::from_secs(30) {
+        // Reload when requested, and on the auto-refresh interval unless it's disabled
+        // or paused with `p`. Marked rows are positions in the current data, so don't
+        // reload under them.
+        let refresh_due = !app.paused
+            && app
+                .refresh_interval
+                .is_some_and(|interval| last_tick.elapsed() >= interval);
+        if refresh_due
+            && !app.loading
+            && app
+                .current_view
+                .as_ref()
+                .is_some_and(|view_state| view_state.selected_items.is_empty())
+        {
+            app.refresh_data();
+        }
+        if app.loading || refresh_due {
             if app.loading {
                 if let Err(e) = app.load_current_service_data().await {
-                    app.set_error(e.to_string());
+                    app.set_error(utils::aws::describe_load_error(&e));
                 } else {
                     app.finish_loading();
                 }
@@ -112,17 +275,66 @@ async fn main() -> Result<()> {
             last_tick = Instant::now();
         }
 
+        // Raw mode swallows Ctrl-z, so suspend explicitly and restore the screen on `fg`
+        if app.suspend_requested {
+            app.suspend_requested = false;
+            suspend(terminal)?;
+            app.needs_redraw = true;
+        }
+
+        // Run async work requested by commands
+        app.run_pending_action().await;
+
+        // Pick up any finished background prefetches
+        app.poll_prefetch();
+
         // Clear expired copy status
         app.clear_expired_copy_status();
 
-        // Draw UI
-        terminal.draw(|f| render_layout(f, &app))?;
-
-        // Control the loop timing
-        sleep(Duration::from_millis(16)).await; // ~60 FPS
+        // Draw UI only when state changed, or once a second for the header clock
+        if app.needs_redraw || last_draw.elapsed() >= CLOCK_TICK {
+            terminal.draw(|f| render_layout(f, app))?;
+            app.needs_redraw = false;
+            last_draw = Instant::now();
+        }
     }
 
-    // Restore terminal
+    Ok(())
+}
+
+/// Leave raw mode and the alternate screen. Best effort, as it also runs while
+/// panicking.
+fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(
+        io::stderr(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        cursor::Show
+    );
+}
+
+/// Put the terminal back before a panic message is printed, so it's readable and
+/// the shell isn't left in raw mode on the alternate screen. Panics in a service's
+/// `render` are caught and shown in the content area instead.
+fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if ui::content::in_service_render() {
+            return;
+        }
+        // Tokio catches panics in background tasks, and the UI keeps running after them
+        if thread::current().name() == Some("main") {
+            restore_terminal();
+        }
+        default_hook(info);
+    }));
+}
+
+/// Leave the alternate screen, stop the process like a normal Ctrl-z, and set the
+/// terminal back up once the shell resumes it
+#[cfg(unix)]
+fn suspend(terminal: &mut Terminal<CrosstermBackend<Stderr>>) -> Result<()> {
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
@@ -131,5 +343,63 @@ async fn main() -> Result<()> {
     )?;
     terminal.show_cursor()?;
 
+    // Blocks until SIGCONT
+    unsafe {
+        libc::raise(libc::SIGTSTP);
+    }
+
+    enable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        EnterAlternateScreen,
+        EnableMouseCapture
+    )?;
+    terminal.clear()?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn suspend(_terminal: &mut Terminal<CrosstermBackend<Stderr>>) -> Result<()> {
+    Ok(())
+}
+
+/// Load a service's data and write it to stdout in the requested format
+async fn print_service_data(app: &App, view_state: &ViewState, format: OutputFormat) -> Result<()> {
+    let service = app
+        .service_manager
+        .get_service(&view_state.service_id)
+        .ok_or_else(|| anyhow!("Service '{}' is not registered", view_state.service_id))?;
+    let data = service
+        .load_data(view_state)
+        .await
+        .map_err(|e| anyhow!(utils::aws::describe_load_error(&e)))?;
+
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&data.to_json())?),
+    }
     Ok(())
 }
+
+/// How long the main loop may wait for input before something else needs attention
+fn poll_timeout(app: &App, last_tick: Instant, last_draw: Instant) -> Duration {
+    if app.needs_redraw || app.loading || app.pending_action.is_some() {
+        return Duration::ZERO;
+    }
+
+    let mut deadlines = vec![last_draw + CLOCK_TICK];
+    if let Some(interval) = app.refresh_interval.filter(|_| !app.paused) {
+        deadlines.push(last_tick + interval);
+    }
+    if let Some((_, copied_at)) = &app.copy_status {
+        deadlines.push(*copied_at + COPY_STATUS_TTL);
+    }
+    if app.is_prefetching() {
+        deadlines.push(Instant::now() + PREFETCH_POLL_INTERVAL);
+    }
+
+    deadlines
+        .into_iter()
+        .min()
+        .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+        .unwrap_or(CLOCK_TICK)
+}
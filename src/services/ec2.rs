@@ -0,0 +1,480 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use aws_sdk_ec2::{Client, types::Instance};
+use chrono::{DateTime, Utc};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    style::Style,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+};
+use serde::{Deserialize, Serialize};
+use std::{any::Any, net::Ipv4Addr, sync::Arc};
+
+use super::traits::{
+    AwsService, ResourceData, ResourceItem, ServiceMetadata, SortValue, ViewState, ViewType,
+    highlight_pattern,
+};
+use crate::app::App;
+use crate::ui::{
+    content::{row_style, table_state},
+    skeleton::render_skeleton,
+    theme::Theme,
+};
+use crate::utils::{columns::sort_headers, rate_limit::RateLimiter, text::highlight_match};
+
+const EC2_SERVICE_ID: &str = "ec2";
+
+const INSTANCE_COLUMNS: [&str; 7] = [
+    "INSTANCE ID",
+    "NAME",
+    "TYPE",
+    "STATE",
+    "PRIVATE IP",
+    "PUBLIC IP",
+    "LAUNCHED",
+];
+
+const INSTANCE_WIDTHS: [Constraint; 7] = [
+    Constraint::Length(21), // Instance id
+    Constraint::Min(24),    // Name
+    Constraint::Length(14), // Type
+    Constraint::Length(14), // State
+    Constraint::Length(16), // Private IP
+    Constraint::Length(16), // Public IP
+    Constraint::Length(20), // Launched
+];
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EC2Instance {
+    pub instance_id: String,
+    pub name: Option<String>, // Value of the `Name` tag
+    pub instance_type: Option<String>,
+    pub state: Option<String>, // e.g. running, stopped, pending
+    pub private_ip: Option<String>,
+    pub public_ip: Option<String>,
+    pub launch_time: Option<DateTime<Utc>>,
+}
+
+impl ResourceItem for EC2Instance {
+    fn id(&self) -> String {
+        self.instance_id.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl From<&Instance> for EC2Instance {
+    fn from(instance: &Instance) -> Self {
+        Self {
+            instance_id: instance.instance_id().unwrap_or_default().to_string(),
+            name: instance
+                .tags()
+                .iter()
+                .find(|tag| tag.key() == Some("Name"))
+                .and_then(|tag| tag.value())
+                .map(str::to_string),
+            instance_type: instance
+                .instance_type()
+                .map(|instance_type| instance_type.as_str().to_string()),
+            state: instance
+                .state()
+                .and_then(|state| state.name())
+                .map(|name| name.as_str().to_string()),
+            private_ip: instance.private_ip_address().map(str::to_string),
+            public_ip: instance.public_ip_address().map(str::to_string),
+            launch_time: instance
+                .launch_time()
+                .and_then(|dt| DateTime::from_timestamp(dt.secs(), dt.subsec_nanos())),
+        }
+    }
+}
+
+impl EC2Instance {
+    fn cells(&self) -> Vec<String> {
+        let or_dash = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+        vec![
+            self.instance_id.clone(),
+            or_dash(&self.name),
+            or_dash(&self.instance_type),
+            or_dash(&self.state),
+            or_dash(&self.private_ip),
+            or_dash(&self.public_ip),
+            self.launch_time
+                .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+                .unwrap_or_else(|| "-".to_string()),
+        ]
+    }
+}
+
+/// STATE cell color for an instance state, so running and stopped instances stand out
+fn state_style(theme: &Theme, state: Option<&str>) -> Style {
+    match state {
+        Some("running") => Style::default().fg(theme.success),
+        Some("stopped") => Style::default().fg(theme.error),
+        _ => Style::default(),
+    }
+}
+
+pub struct EC2Service {
+    client: Client,
+    rate_limiter: Arc<RateLimiter>,
+}
+
+impl EC2Service {
+    pub fn new(client: Client, rate_limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            client,
+            rate_limiter,
+        }
+    }
+
+    pub async fn list_instances(&self) -> Result<Vec<EC2Instance>> {
+        let mut instances = Vec::new();
+        let mut next_token = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .describe_instances()
+                .set_next_token(next_token)
+                .send()
+                .await?;
+            instances.extend(
+                resp.reservations()
+                    .iter()
+                    .flat_map(|reservation| reservation.instances())
+                    .map(EC2Instance::from),
+            );
+
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        // Named instances first, by name; unnamed ones after, by id
+        instances.sort_by(|a, b| {
+            (a.name.is_none(), &a.name, &a.instance_id).cmp(&(
+                b.name.is_none(),
+                &b.name,
+                &b.instance_id,
+            ))
+        });
+        Ok(instances)
+    }
+
+    fn render_instances(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        let filtered_items = self.visible_items(data, view_state);
+        let title = if app.loading {
+            "EC2 Instances (Loading...)".to_string()
+        } else if view_state.search_filter.is_empty() {
+            format!("EC2 Instances ({})", filtered_items.len())
+        } else {
+            format!(
+                "EC2 Instances ({}/{}) - Filter: {}",
+                filtered_items.len(),
+                data.items.len(),
+                view_state.search_filter
+            )
+        };
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(
+                f,
+                area,
+                &app.theme,
+                &title,
+                &INSTANCE_COLUMNS,
+                &INSTANCE_WIDTHS,
+            );
+            return;
+        }
+
+        if filtered_items.is_empty() {
+            let message = if !view_state.search_filter.is_empty() {
+                "No instances match the current filter"
+            } else {
+                "No EC2 instances found"
+            };
+            let paragraph = Paragraph::new(message)
+                .block(Block::default().borders(Borders::ALL).title(title))
+                .style(Style::default().fg(app.theme.muted));
+            f.render_widget(paragraph, area);
+            return;
+        }
+
+        let header = Row::new(
+            sort_headers(
+                &INSTANCE_COLUMNS,
+                view_state.sort_key,
+                view_state.sort_ascending,
+            )
+            .into_iter()
+            .map(|h| Cell::from(h).style(app.theme.header_style())),
+        )
+        .height(1);
+
+        let rows: Vec<Row> = filtered_items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let instance = item.as_any().downcast_ref::<EC2Instance>()?;
+                let mut cells = instance.cells().into_iter();
+                let id = cells.next().unwrap_or_default();
+                let name = cells.next().unwrap_or_default();
+                let instance_type = cells.next().unwrap_or_default();
+                let state = cells.next().unwrap_or_default();
+
+                let state_cell_style = if i == view_state.selected_index {
+                    Style::default()
+                } else {
+                    state_style(&app.theme, instance.state.as_deref())
+                };
+
+                Some(
+                    Row::new(
+                        [
+                            Cell::from(highlight_match(
+                                &id,
+                                highlight_pattern(&view_state.search_filter, "id"),
+                            )),
+                            Cell::from(highlight_match(
+                                &name,
+                                highlight_pattern(&view_state.search_filter, "name"),
+                            )),
+                            Cell::from(instance_type),
+                            Cell::from(state).style(state_cell_style),
+                        ]
+                        .into_iter()
+                        .chain(cells.map(Cell::from)),
+                    )
+                    .style(row_style(app, view_state, i)),
+                )
+            })
+            .collect();
+
+        let table = Table::new(rows, INSTANCE_WIDTHS)
+            .header(header)
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_stateful_widget(
+            table,
+            area,
+            &mut table_state(view_state, view_state.selected_index),
+        );
+    }
+}
+
+#[async_trait]
+impl AwsService for EC2Service {
+    fn metadata(&self) -> ServiceMetadata {
+        ServiceMetadata {
+            id: EC2_SERVICE_ID.to_string(),
+            name: "EC2".to_string(),
+            description: "Virtual machine instances".to_string(),
+            command: "ec2".to_string(),
+            aliases: Vec::new(),
+            short_label: Some("EC2".to_string()),
+            icon: None,
+        }
+    }
+
+    async fn load_data(&self, view_state: &ViewState) -> Result<ResourceData> {
+        let items: Vec<Box<dyn ResourceItem>> = match view_state.view_type {
+            ViewType::List => self
+                .list_instances()
+                .await?
+                .into_iter()
+                .map(|instance| Box::new(instance) as Box<dyn ResourceItem>)
+                .collect(),
+            _ => Vec::new(),
+        };
+        Ok(ResourceData::new(items))
+    }
+
+    fn render(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        if view_state.view_type == ViewType::List {
+            self.render_instances(f, area, app, view_state, data);
+        }
+    }
+
+    fn handle_enter(&self, _view_state: &mut ViewState, _data: &ResourceData) -> Option<ViewState> {
+        None
+    }
+
+    fn get_copy_content(
+        &self,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) -> Option<(String, String)> {
+        let instance = self
+            .visible_items(data, view_state)
+            .get(view_state.selected_index)?
+            .as_any()
+            .downcast_ref::<EC2Instance>()?;
+        let display_name = match &instance.name {
+            Some(name) => format!("{} ({})", instance.instance_id, name),
+            None => instance.instance_id.clone(),
+        };
+        Some((instance.instance_id.clone(), display_name))
+    }
+
+    fn matches_filter(&self, item: &dyn ResourceItem, filter: &str) -> bool {
+        let filter = filter.to_lowercase();
+        item.as_any()
+            .downcast_ref::<EC2Instance>()
+            .is_some_and(|instance| {
+                instance.instance_id.to_lowercase().contains(&filter)
+                    || instance
+                        .name
+                        .as_ref()
+                        .is_some_and(|name| name.to_lowercase().contains(&filter))
+            })
+    }
+
+    fn filter_columns(&self, _view_state: &ViewState) -> Vec<&'static str> {
+        vec!["id", "name", "type", "state"]
+    }
+
+    fn matches_column(&self, item: &dyn ResourceItem, column: &str, value: &str) -> bool {
+        let Some(instance) = item.as_any().downcast_ref::<EC2Instance>() else {
+            return false;
+        };
+        let field = match column {
+            "id" => Some(instance.instance_id.as_str()),
+            "name" => instance.name.as_deref(),
+            "type" => instance.instance_type.as_deref(),
+            "state" => instance.state.as_deref(),
+            _ => None,
+        };
+
+        field.is_some_and(|field| field.to_lowercase().contains(&value.to_lowercase()))
+    }
+
+    fn export_columns(&self, view_state: &ViewState) -> Vec<String> {
+        match view_state.view_type {
+            ViewType::List => INSTANCE_COLUMNS.iter().map(|c| c.to_string()).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn export_rows(&self, view_state: &ViewState, data: &ResourceData) -> Vec<Vec<String>> {
+        self.visible_items(data, view_state)
+            .iter()
+            .filter_map(|item| item.as_any().downcast_ref::<EC2Instance>())
+            .map(EC2Instance::cells)
+            .collect()
+    }
+
+    fn sort_value(&self, item: &dyn ResourceItem, column: usize) -> Option<SortValue> {
+        let instance = item.as_any().downcast_ref::<EC2Instance>()?;
+        let text = |value: &str| Some(SortValue::Text(value.to_lowercase()));
+        // Zero-padded octets, so 10.0.0.9 sorts before 10.0.0.10 and the column
+        // starts ascending like other text
+        let ip = |ip: &Option<String>| {
+            let ip = ip.as_deref()?.parse::<Ipv4Addr>().ok()?;
+            let octets = ip.octets().map(|octet| format!("{:03}", octet));
+            Some(SortValue::Text(octets.join(".")))
+        };
+
+        match column {
+            0 => text(&instance.instance_id),
+            1 => instance.name.as_deref().and_then(text),
+            2 => instance.instance_type.as_deref().and_then(text),
+            3 => instance.state.as_deref().and_then(text),
+            4 => ip(&instance.private_ip),
+            5 => ip(&instance.public_ip),
+            6 => instance
+                .launch_time
+                .map(|dt| SortValue::Number(dt.timestamp() as f64)),
+            _ => None,
+        }
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.rate_limiter.acquire().await;
+        self.client
+            .describe_instances()
+            .max_results(5)
+            .send()
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::traits::ServiceId;
+
+    #[test]
+    fn instances_sort_by_launch_time_and_private_ip() {
+        let service = EC2Service::new(
+            Client::from_conf(
+                aws_sdk_ec2::Config::builder()
+                    .behavior_version(aws_sdk_ec2::config::BehaviorVersion::latest())
+                    .build(),
+            ),
+            Arc::new(RateLimiter::unlimited()),
+        );
+        let instance = |id: &str, private_ip: &str, launched: &str| -> Box<dyn ResourceItem> {
+            Box::new(EC2Instance {
+                instance_id: id.to_string(),
+                name: None,
+                instance_type: Some("t3.micro".to_string()),
+                state: Some("running".to_string()),
+                private_ip: Some(private_ip.to_string()),
+                public_ip: None,
+                launch_time: DateTime::parse_from_rfc3339(launched)
+                    .ok()
+                    .map(|dt| dt.with_timezone(&Utc)),
+            })
+        };
+        let data = ResourceData::new(vec![
+            instance("i-old", "10.0.0.10", "2023-06-01T00:00:00Z"),
+            instance("i-new", "10.0.0.9", "2024-03-15T12:00:00Z"),
+            instance("i-undated", "10.0.1.1", ""),
+        ]);
+        let mut view_state = ViewState::new(ServiceId::new(EC2_SERVICE_ID), ViewType::List);
+        let ids = |view_state: &ViewState| -> Vec<String> {
+            service
+                .visible_items(&data, view_state)
+                .iter()
+                .map(|item| item.id())
+                .collect()
+        };
+
+        view_state.sort_key = Some(6);
+        view_state.sort_ascending = false;
+        assert_eq!(ids(&view_state), ["i-new", "i-old", "i-undated"]);
+
+        view_state.sort_key = Some(4);
+        view_state.sort_ascending = true;
+        assert_eq!(ids(&view_state), ["i-new", "i-old", "i-undated"]);
+    }
+}
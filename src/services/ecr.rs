@@ -1,27 +1,44 @@
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use async_trait::async_trait;
 use aws_sdk_ecr::{
     Client,
-    types::{ImageDetail, Repository},
+    error::DisplayErrorContext,
+    operation::{put_image::PutImageError, start_image_scan::StartImageScanError},
+    types::{FindingSeverity, ImageDetail, ImageIdentifier, Repository},
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use ratatui::{
     Frame,
     layout::Constraint,
     layout::Rect,
     style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
     widgets::{Block, Borders, Cell, Row, Table},
 };
 use serde::{Deserialize, Serialize};
-use std::any::Any;
+use std::{any::Any, collections::HashMap, sync::Arc};
 
-use super::traits::{AwsService, ResourceData, ResourceItem, ServiceMetadata, ViewState, ViewType};
+use super::traits::{
+    AwsService, Confirmation, ResourceData, ResourceItem, ServiceAction, ServiceId,
+    ServiceMetadata, SortValue, ViewState, ViewType, highlight_pattern,
+};
 use crate::app::App;
+use crate::ui::{
+    content::{row_style, table_state},
+    skeleton::render_skeleton,
+};
+use crate::utils::{
+    aws::is_region_unavailable,
+    columns::{column_order, reorder, sort_headers},
+    rate_limit::RateLimiter,
+    text::{highlight_match, truncate_with_ellipsis, wrap_text},
+};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ECRRepository {
     pub repository_name: String,
     pub repository_uri: String,
+    pub repository_arn: String,
     pub registry_id: String,
     pub created_at: Option<DateTime<Utc>>,
     pub image_tag_mutability: String,
@@ -29,6 +46,31 @@ pub struct ECRRepository {
     pub encryption_configuration: String,
 }
 
+impl ECRRepository {
+    /// All repository fields, labelled for the details popup
+    pub fn detail_lines(&self) -> Vec<(String, String)> {
+        vec![
+            ("Name".to_string(), self.repository_name.clone()),
+            ("URI".to_string(), self.repository_uri.clone()),
+            ("ARN".to_string(), self.repository_arn.clone()),
+            ("Registry ID".to_string(), self.registry_id.clone()),
+            ("Created".to_string(), detail_time(self.created_at)),
+            (
+                "Tag mutability".to_string(),
+                self.image_tag_mutability.clone(),
+            ),
+            (
+                "Scan on push".to_string(),
+                self.image_scanning_configuration.to_string(),
+            ),
+            (
+                "Encryption".to_string(),
+                self.encryption_configuration.clone(),
+            ),
+        ]
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ECRImage {
     pub image_tag: Option<String>,
@@ -36,6 +78,142 @@ pub struct ECRImage {
     pub image_pushed_at: Option<DateTime<Utc>>,
     pub image_size_in_bytes: Option<i64>,
     pub vulnerability_scan_summary: Option<String>,
+    pub severity_counts: Option<SeverityCounts>,
+    pub scan_status: Option<String>, // e.g. COMPLETE, PENDING, FAILED
+    pub image_manifest_media_type: Option<String>,
+    pub artifact_media_type: Option<String>,
+}
+
+impl ECRImage {
+    /// Whether the image has no tags, making it a candidate for pruning
+    pub fn is_untagged(&self) -> bool {
+        self.image_tag.is_none()
+    }
+
+    /// Whether the manifest is a multi-platform index rather than a single image
+    pub fn is_index(&self) -> bool {
+        self.image_manifest_media_type
+            .as_deref()
+            .is_some_and(|media_type| {
+                media_type.contains("image.index") || media_type.contains("manifest.list")
+            })
+    }
+
+    /// Whether the last vulnerability scan errored, as opposed to never having run
+    pub fn scan_failed(&self) -> bool {
+        self.scan_status.as_deref() == Some("FAILED")
+    }
+
+    /// Short label distinguishing image indexes, attestations, and plain images
+    pub fn artifact_kind(&self) -> String {
+        if self.is_index() {
+            return "Index".to_string();
+        }
+
+        match self.artifact_media_type.as_deref() {
+            Some(media_type) if media_type.contains(".sig") || media_type.contains("signature") => {
+                "Signature".to_string()
+            }
+            Some(media_type) if media_type.contains("in-toto") || media_type.contains("attest") => {
+                "Attestation".to_string()
+            }
+            Some(media_type)
+                if media_type.contains("spdx")
+                    || media_type.contains("cyclonedx")
+                    || media_type.contains("sbom") =>
+            {
+                "SBOM".to_string()
+            }
+            // Regular container images report their config type as the artifact type
+            Some(media_type)
+                if !media_type.contains("container.image")
+                    && !media_type.contains("image.config") =>
+            {
+                "Artifact".to_string()
+            }
+            _ => "Image".to_string(),
+        }
+    }
+
+    /// Severity counts as compact "C:2 H:5" text, or the scan summary without findings
+    pub fn vulnerability_text(&self) -> String {
+        match &self.severity_counts {
+            Some(counts) if counts.total() > 0 => counts
+                .entries()
+                .iter()
+                .filter(|(_, count)| *count > 0)
+                .map(|(label, count)| format!("{}:{}", label, count))
+                .collect::<Vec<_>>()
+                .join(" "),
+            _ => self
+                .vulnerability_scan_summary
+                .clone()
+                .unwrap_or_else(|| "Not scanned".to_string()),
+        }
+    }
+
+    /// All image fields, labelled for the details popup, with the full digest and the
+    /// exact size in bytes the table abbreviates
+    pub fn detail_lines(&self) -> Vec<(String, String)> {
+        let or_dash = |value: &Option<String>| value.clone().unwrap_or_else(|| "-".to_string());
+        vec![
+            ("Tag".to_string(), or_dash(&self.image_tag)),
+            ("Digest".to_string(), self.image_digest.clone()),
+            ("Pushed".to_string(), detail_time(self.image_pushed_at)),
+            (
+                "Size (bytes)".to_string(),
+                self.image_size_in_bytes
+                    .map(|size| size.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+            ("Kind".to_string(), self.artifact_kind()),
+            ("Vulnerabilities".to_string(), self.vulnerability_text()),
+            ("Scan status".to_string(), or_dash(&self.scan_status)),
+            (
+                "Manifest media type".to_string(),
+                or_dash(&self.image_manifest_media_type),
+            ),
+            (
+                "Artifact media type".to_string(),
+                or_dash(&self.artifact_media_type),
+            ),
+        ]
+    }
+
+    /// The most specific media type available, preferring the artifact type
+    pub fn media_type(&self) -> Option<&str> {
+        self.artifact_media_type
+            .as_deref()
+            .or(self.image_manifest_media_type.as_deref())
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SeverityCounts {
+    pub critical: i32,
+    pub high: i32,
+    pub medium: i32,
+    pub low: i32,
+    pub informational: i32,
+    pub undefined: i32,
+}
+
+impl SeverityCounts {
+    pub fn total(&self) -> i32 {
+        self.entries().iter().map(|(_, count)| count).sum()
+    }
+
+    /// Counts paired with a one-letter label, most severe first
+    pub fn entries(&self) -> [(&'static str, i32); 6] {
+        [
+            ("C", self.critical),
+            ("H", self.high),
+            ("M", self.medium),
+            ("L", self.low),
+            ("I", self.informational),
+            ("U", self.undefined),
+        ]
+    }
 }
 
 impl ResourceItem for ECRRepository {
@@ -50,6 +228,10 @@ impl ResourceItem for ECRRepository {
     fn clone_box(&self) -> Box<dyn ResourceItem> {
         Box::new(self.clone())
     }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
 }
 
 impl ResourceItem for ECRImage {
@@ -64,6 +246,120 @@ impl ResourceItem for ECRImage {
     fn clone_box(&self) -> Box<dyn ResourceItem> {
         Box::new(self.clone())
     }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+/// Bytes in a `size>` / `size<` search value given in MB, e.g. `size>500`
+fn parse_size_mb(value: &str) -> Result<f64, String> {
+    let megabytes: f64 = value
+        .trim()
+        .trim_end_matches(['m', 'M', 'b', 'B'])
+        .parse()
+        .map_err(|_| "size takes a number of MB, e.g. size>500".to_string())?;
+    Ok(megabytes * 1_048_576.0)
+}
+
+/// Unix time at the start of the UTC day in a `pushed>` / `pushed<` search value
+fn parse_date(value: &str) -> Result<f64, String> {
+    let date = NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+        .map_err(|_| "pushed takes a date, e.g. pushed>2024-01-31".to_string())?;
+    Ok(date.and_time(NaiveTime::MIN).and_utc().timestamp() as f64)
+}
+
+/// Timestamp for the details popup, with seconds since there's room for them
+fn detail_time(time: Option<DateTime<Utc>>) -> String {
+    time.map(|time| time.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// One platform-specific image referenced by a multi-arch image index
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ImagePlatform {
+    pub os: String,
+    pub architecture: String,
+    pub variant: Option<String>,
+    pub digest: String,
+    pub media_type: Option<String>,
+    pub image_size_in_bytes: Option<i64>,
+}
+
+impl ImagePlatform {
+    /// Platform as `os/arch[/variant]`, e.g. `linux/arm64/v8`
+    pub fn platform(&self) -> String {
+        match &self.variant {
+            Some(variant) => format!("{}/{}/{}", self.os, self.architecture, variant),
+            None => format!("{}/{}", self.os, self.architecture),
+        }
+    }
+}
+
+impl ResourceItem for ImagePlatform {
+    fn id(&self) -> String {
+        self.digest.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+/// The parts of an OCI image index / Docker manifest list needed to list its platforms
+#[derive(Deserialize)]
+struct IndexManifest {
+    #[serde(default)]
+    manifests: Vec<ManifestDescriptor>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ManifestDescriptor {
+    media_type: Option<String>,
+    digest: String,
+    platform: Option<ManifestPlatform>,
+}
+
+#[derive(Deserialize)]
+struct ManifestPlatform {
+    os: String,
+    architecture: String,
+    variant: Option<String>,
+}
+
+/// Child images listed in an image index manifest. Descriptors without a platform
+/// (e.g. buildx attestations) are listed as `unknown/unknown`.
+fn parse_index_manifest(manifest: &str) -> Result<Vec<ImagePlatform>> {
+    let index: IndexManifest = serde_json::from_str(manifest)
+        .map_err(|e| anyhow!("Image index manifest could not be parsed: {}", e))?;
+
+    Ok(index
+        .manifests
+        .into_iter()
+        .map(|descriptor| {
+            let (os, architecture, variant) = match descriptor.platform {
+                Some(platform) => (platform.os, platform.architecture, platform.variant),
+                None => ("unknown".to_string(), "unknown".to_string(), None),
+            };
+            ImagePlatform {
+                os,
+                architecture,
+                variant,
+                digest: descriptor.digest,
+                media_type: descriptor.media_type,
+                image_size_in_bytes: None,
+            }
+        })
+        .collect())
 }
 
 impl From<&Repository> for ECRRepository {
@@ -71,6 +367,8 @@ impl From<&Repository> for ECRRepository {
         Self {
             repository_name: repo.repository_name().unwrap_or("").to_string(),
             repository_uri: repo.repository_uri().unwrap_or("").to_string(),
+            // As AWS reports it, so the partition, region, and account are exact
+            repository_arn: repo.repository_arn().unwrap_or("").to_string(),
             registry_id: repo.registry_id().unwrap_or("").to_string(),
             created_at: repo.created_at().map(|dt| -> DateTime<Utc> {
                 DateTime::from_timestamp(dt.secs(), dt.subsec_nanos()).unwrap_or_else(Utc::now)
@@ -95,18 +393,43 @@ impl From<&ImageDetail> for ECRImage {
     fn from(image: &ImageDetail) -> Self {
         let image_tag = image.image_tags().first().map(|tag| tag.to_string());
 
-        let vulnerability_summary = image.image_scan_findings_summary().map(|summary| {
-            if let Some(counts) = summary.finding_severity_counts() {
-                let total: i32 = counts.values().sum();
-                if total > 0 {
-                    format!("{} findings", total)
+        let severity_counts = image
+            .image_scan_findings_summary()
+            .and_then(|summary| summary.finding_severity_counts())
+            .map(|counts| {
+                let count = |severity: FindingSeverity| counts.get(&severity).copied().unwrap_or(0);
+                SeverityCounts {
+                    critical: count(FindingSeverity::Critical),
+                    high: count(FindingSeverity::High),
+                    medium: count(FindingSeverity::Medium),
+                    low: count(FindingSeverity::Low),
+                    informational: count(FindingSeverity::Informational),
+                    undefined: count(FindingSeverity::Undefined),
+                }
+            });
+
+        let scan_status = image
+            .image_scan_status()
+            .and_then(|status| status.status())
+            .map(|status| status.as_str().to_string());
+
+        // A failed scan may leave no findings summary at all, so check the status first
+        let vulnerability_summary = if scan_status.as_deref() == Some("FAILED") {
+            Some("Scan failed".to_string())
+        } else {
+            image.image_scan_findings_summary().map(|summary| {
+                if let Some(counts) = summary.finding_severity_counts() {
+                    let total: i32 = counts.values().sum();
+                    if total > 0 {
+                        format!("{} findings", total)
+                    } else {
+                        "No vulnerabilities".to_string()
+                    }
                 } else {
-                    "No vulnerabilities".to_string()
+                    "Scan pending".to_string()
                 }
-            } else {
-                "Scan pending".to_string()
-            }
-        });
+            })
+        };
 
         Self {
             image_tag,
@@ -116,40 +439,298 @@ impl From<&ImageDetail> for ECRImage {
             }),
             image_size_in_bytes: image.image_size_in_bytes(),
             vulnerability_scan_summary: vulnerability_summary,
+            severity_counts,
+            scan_status,
+            image_manifest_media_type: image.image_manifest_media_type().map(str::to_string),
+            artifact_media_type: image.artifact_media_type().map(str::to_string),
+        }
+    }
+}
+
+const ECR_SERVICE_ID: &str = "ecr";
+
+const REPOSITORY_COLUMNS: [&str; 6] = [
+    "REPOSITORY NAME",
+    "REGISTRY ID",
+    "CREATED",
+    "TAG MUTABILITY",
+    "SCAN ON PUSH",
+    "ENCRYPTION",
+];
+
+const IMAGE_COLUMNS: [&str; 7] = [
+    "IMAGE TAG",
+    "DIGEST",
+    "PUSHED AT",
+    "SIZE",
+    "% OF TOTAL",
+    "ARTIFACT TYPE",
+    "VULNERABILITIES",
+];
+
+const PLATFORM_COLUMNS: [&str; 4] = ["PLATFORM", "DIGEST", "SIZE", "MEDIA TYPE"];
+
+// Column widths also bound the cell text; longer values are cut with `…`
+const REPOSITORY_COLUMN_WIDTHS: [u16; 6] = [
+    30, // Repository name
+    15, // Registry ID
+    20, // Created
+    16, // Tag mutability
+    12, // Scan on push
+    12, // Encryption
+];
+
+const IMAGE_COLUMN_WIDTHS: [u16; 7] = [
+    25, // Image tag
+    30, // Digest
+    20, // Pushed at
+    12, // Size
+    11, // Share of the listed images' total size
+    14, // Artifact type
+    20, // Vulnerabilities
+];
+
+const PLATFORM_COLUMN_WIDTHS: [u16; 4] = [
+    20, // Platform
+    30, // Digest
+    12, // Size
+    50, // Media type
+];
+
+/// Custom view listing the platform images of a multi-arch image index. Its context
+/// is "repo_name|repo_uri|index_digest".
+const PLATFORMS_VIEW: &str = "platforms";
+
+/// Table row whose cells wrap onto extra lines instead of being cut off at the column width
+fn wrapped_row(
+    cells: Vec<String>,
+    widths: &[u16],
+    order: &[usize],
+    active_column: Option<usize>,
+) -> Row<'static> {
+    let wrapped: Vec<Vec<String>> = reorder(&cells, order)
+        .iter()
+        .zip(reorder(widths, order))
+        .map(|(cell, width)| wrap_text(cell, width as usize))
+        .collect();
+    let height = wrapped.iter().map(Vec::len).max().unwrap_or(1).max(1);
+
+    let cells = wrapped
+        .into_iter()
+        .map(|lines| Cell::from(Text::from_iter(lines)))
+        .collect();
+    Row::new(mark_active_cell(cells, active_column)).height(height as u16)
+}
+
+/// Highlight the cell under the column cursor on the selected row
+fn mark_active_cell(
+    mut cells: Vec<Cell<'static>>,
+    active_column: Option<usize>,
+) -> Vec<Cell<'static>> {
+    if let Some(column) = active_column
+        && let Some(cell) = cells.get_mut(column)
+    {
+        *cell = cell
+            .clone()
+            .style(Style::default().add_modifier(Modifier::REVERSED));
+    }
+    cells
+}
+
+/// Combined size of the images in a list, ignoring images without a known size
+fn total_image_size<'a>(images: impl Iterator<Item = &'a ECRImage>) -> i64 {
+    images.filter_map(|image| image.image_size_in_bytes).sum()
+}
+
+/// An image's size as a percentage of the list total
+fn size_share(image: &ECRImage, total_size: i64) -> String {
+    match image.image_size_in_bytes {
+        Some(size) if total_size > 0 => {
+            format!("{:.1}%", size as f64 * 100.0 / total_size as f64)
+        }
+        _ => "-".to_string(),
+    }
+}
+
+/// Plain-text cells for a platform image, matching `PLATFORM_COLUMNS`
+fn platform_cells(platform: &ImagePlatform) -> Vec<String> {
+    vec![
+        platform.platform(),
+        platform.digest.clone(),
+        platform
+            .image_size_in_bytes
+            .map(|s| format!("{:.1} MB", s as f64 / 1_048_576.0))
+            .unwrap_or_else(|| "-".to_string()),
+        platform
+            .media_type
+            .clone()
+            .unwrap_or_else(|| "-".to_string()),
+    ]
+}
+
+/// ECR console page for a repository, or for one of its images when `digest` is given
+fn console_url(region: &str, account: &str, repo_name: &str, digest: Option<&str>) -> String {
+    let repository = format!(
+        "https://{}.console.aws.amazon.com/ecr/repositories/private/{}/{}",
+        region, account, repo_name
+    );
+    match digest {
+        Some(digest) => format!(
+            "{}/_/image/{}/details?region={}",
+            repository, digest, region
+        ),
+        None => format!("{}?region={}", repository, region),
+    }
+}
+
+/// Account id from a repository URI like `123456789012.dkr.ecr.<region>.amazonaws.com/name`
+fn uri_account(repository_uri: &str) -> Option<&str> {
+    repository_uri
+        .split('.')
+        .next()
+        .filter(|account| !account.is_empty())
+}
+
+/// Short `repo:tag` name for an image, falling back to a truncated digest when untagged
+fn image_reference(repo_name: &str, image: &ECRImage) -> String {
+    match &image.image_tag {
+        Some(tag) => format!("{}:{}", repo_name, tag),
+        None => {
+            let short_digest: String = image.image_digest.chars().take(19).collect();
+            format!("{}@{}", repo_name, short_digest)
         }
     }
 }
 
+/// Namespace of a repository name, i.e. the part before the first `/`
+fn repository_namespace(name: &str) -> &str {
+    name.split_once('/')
+        .map(|(namespace, _)| namespace)
+        .unwrap_or("")
+}
+
+// Narrower tables squeeze the vulnerabilities column, so fall back to text
+const SEVERITY_BAR_MIN_AREA_WIDTH: u16 = 130;
+const SEVERITY_BAR_WIDTH: usize = 12;
+
+fn severity_color(label: &str) -> Color {
+    match label {
+        "C" => Color::Red,
+        "H" => Color::LightRed,
+        "M" => Color::Yellow,
+        "L" => Color::Blue,
+        _ => Color::Gray,
+    }
+}
+
+/// Render severity counts as a proportional bar of colored blocks followed by
+/// the total, or as compact "C:2 H:5" text when `as_bar` is false
+fn severity_line(counts: &SeverityCounts, as_bar: bool) -> Line<'static> {
+    let present: Vec<(&str, i32)> = counts
+        .entries()
+        .into_iter()
+        .filter(|(_, count)| *count > 0)
+        .collect();
+
+    if !as_bar {
+        let spans: Vec<Span> = present
+            .iter()
+            .enumerate()
+            .map(|(i, (label, count))| {
+                let separator = if i == 0 { "" } else { " " };
+                Span::styled(
+                    format!("{}{}:{}", separator, label, count),
+                    Style::default().fg(severity_color(label)),
+                )
+            })
+            .collect();
+        return Line::from(spans);
+    }
+
+    // Every present severity gets at least one block so criticals never vanish
+    let total = counts.total() as usize;
+    let spare = SEVERITY_BAR_WIDTH.saturating_sub(present.len());
+    let mut spans: Vec<Span> = present
+        .iter()
+        .map(|(label, count)| {
+            let width = 1 + spare * (*count as usize) / total;
+            Span::styled(
+                "█".repeat(width),
+                Style::default().fg(severity_color(label)),
+            )
+        })
+        .collect();
+    spans.push(Span::raw(format!(" {}", total)));
+
+    Line::from(spans)
+}
+
 pub struct ECRService {
     client: Client,
+    rate_limiter: Arc<RateLimiter>,
 }
 
 impl ECRService {
-    pub fn new(client: Client) -> Self {
-        Self { client }
+    pub fn new(client: Client, rate_limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            client,
+            rate_limiter,
+        }
     }
 
     pub async fn list_repositories(&self) -> Result<Vec<ECRRepository>> {
-        let resp = self.client.describe_repositories().send().await?;
+        let mut repositories = Vec::new();
+        let mut next_token = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .describe_repositories()
+                .set_next_token(next_token)
+                .send()
+                .await
+                .map_err(|e| {
+                    if is_region_unavailable(&e) {
+                        anyhow!(
+                            "ECR not available in {}. The region may not be enabled for this \
+                             account (opt-in required) or the credentials are invalid there. \
+                             Try another region with --region.",
+                            self.region()
+                        )
+                    } else {
+                        e.into()
+                    }
+                })?;
+            repositories.extend(resp.repositories().iter().map(ECRRepository::from));
 
-        let repositories = resp
-            .repositories()
-            .iter()
-            .map(ECRRepository::from)
-            .collect();
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
 
         Ok(repositories)
     }
 
     pub async fn get_repository_images(&self, repository_name: &str) -> Result<Vec<ECRImage>> {
-        let resp = self
-            .client
-            .describe_images()
-            .repository_name(repository_name)
-            .send()
-            .await?;
+        let mut images = Vec::new();
+        let mut next_token = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .describe_images()
+                .repository_name(repository_name)
+                .set_next_token(next_token)
+                .send()
+                .await?;
+            images.extend(resp.image_details().iter().map(ECRImage::from));
 
-        let mut images: Vec<ECRImage> = resp.image_details().iter().map(ECRImage::from).collect();
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
 
         // Sort images by pushed_at date, latest first
         images.sort_by(|a, b| {
@@ -163,16 +744,279 @@ impl ECRService {
 
         Ok(images)
     }
+
+    /// Platform images referenced by a multi-arch image index, with their sizes
+    pub async fn get_index_platforms(
+        &self,
+        repository_name: &str,
+        index_digest: &str,
+    ) -> Result<Vec<ImagePlatform>> {
+        self.rate_limiter.acquire().await;
+        let resp = self
+            .client
+            .batch_get_image()
+            .repository_name(repository_name)
+            .image_ids(
+                ImageIdentifier::builder()
+                    .image_digest(index_digest)
+                    .build(),
+            )
+            .accepted_media_types("application/vnd.docker.distribution.manifest.list.v2+json")
+            .accepted_media_types("application/vnd.oci.image.index.v1+json")
+            .send()
+            .await?;
+
+        let manifest = resp
+            .images()
+            .first()
+            .and_then(|image| image.image_manifest())
+            .ok_or_else(|| {
+                anyhow!(
+                    "Image index {} not found in {}",
+                    index_digest,
+                    repository_name
+                )
+            })?;
+        let mut platforms = parse_index_manifest(manifest)?;
+        if platforms.is_empty() {
+            return Ok(platforms);
+        }
+
+        // The index only records manifest sizes, so look up the child images themselves.
+        // Sizes are a nicety; the platforms are still worth showing if this fails.
+        self.rate_limiter.acquire().await;
+        let sizes = self
+            .client
+            .describe_images()
+            .repository_name(repository_name)
+            .set_image_ids(Some(
+                platforms
+                    .iter()
+                    .take(100)
+                    .map(|platform| {
+                        ImageIdentifier::builder()
+                            .image_digest(&platform.digest)
+                            .build()
+                    })
+                    .collect(),
+            ))
+            .send()
+            .await
+            .map(|resp| {
+                resp.image_details()
+                    .iter()
+                    .filter_map(|detail| {
+                        Some((
+                            detail.image_digest()?.to_string(),
+                            detail.image_size_in_bytes()?,
+                        ))
+                    })
+                    .collect::<HashMap<_, _>>()
+            })
+            .unwrap_or_default();
+        for platform in &mut platforms {
+            platform.image_size_in_bytes = sizes.get(&platform.digest).copied();
+        }
+
+        Ok(platforms)
+    }
+
+    /// Add a tag to an existing image by re-putting its manifest under the new tag
+    pub async fn tag_image(
+        &self,
+        repository_name: &str,
+        image_digest: &str,
+        new_tag: &str,
+    ) -> Result<()> {
+        self.rate_limiter.acquire().await;
+        let resp = self
+            .client
+            .batch_get_image()
+            .repository_name(repository_name)
+            .image_ids(
+                ImageIdentifier::builder()
+                    .image_digest(image_digest)
+                    .build(),
+            )
+            // Request the manifest as pushed instead of letting ECR convert it
+            .accepted_media_types("application/vnd.docker.distribution.manifest.v2+json")
+            .accepted_media_types("application/vnd.docker.distribution.manifest.list.v2+json")
+            .accepted_media_types("application/vnd.oci.image.manifest.v1+json")
+            .accepted_media_types("application/vnd.oci.image.index.v1+json")
+            .send()
+            .await?;
+
+        let image = resp
+            .images()
+            .first()
+            .ok_or_else(|| anyhow!("Image {} not found in {}", image_digest, repository_name))?;
+        let manifest = image
+            .image_manifest()
+            .ok_or_else(|| anyhow!("Image {} has no manifest", image_digest))?;
+
+        let mut request = self
+            .client
+            .put_image()
+            .repository_name(repository_name)
+            .image_manifest(manifest)
+            .image_tag(new_tag);
+        if let Some(media_type) = image.image_manifest_media_type() {
+            request = request.image_manifest_media_type(media_type);
+        }
+
+        request
+            .send()
+            .await
+            .map_err(|e| match e.into_service_error() {
+                PutImageError::ImageTagAlreadyExistsException(_) => anyhow!(
+                    "Tag '{}' already exists in {} and the repository's tags are immutable",
+                    new_tag,
+                    repository_name
+                ),
+                PutImageError::ImageAlreadyExistsException(_) => {
+                    anyhow!("Image is already tagged '{}'", new_tag)
+                }
+                other => other.into(),
+            })?;
+
+        Ok(())
+    }
+
+    /// Start a basic vulnerability scan of an image. Returns `false` when ECR refuses
+    /// because the image was already scanned recently (one scan per image per day).
+    pub async fn start_image_scan(
+        &self,
+        repository_name: &str,
+        image_digest: &str,
+    ) -> Result<bool> {
+        self.rate_limiter.acquire().await;
+        match self
+            .client
+            .start_image_scan()
+            .repository_name(repository_name)
+            .image_id(
+                ImageIdentifier::builder()
+                    .image_digest(image_digest)
+                    .build(),
+            )
+            .send()
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(e) => match e.into_service_error() {
+                StartImageScanError::LimitExceededException(_) => Ok(false),
+                other => Err(other.into()),
+            },
+        }
+    }
+
+    /// Delete images by digest, returning how many ECR reports as deleted
+    pub async fn delete_images(&self, repository_name: &str, digests: &[String]) -> Result<usize> {
+        let mut deleted = 0;
+        // BatchDeleteImage accepts at most 100 image ids per call
+        for chunk in digests.chunks(100) {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .batch_delete_image()
+                .repository_name(repository_name)
+                .set_image_ids(Some(
+                    chunk
+                        .iter()
+                        .map(|digest| ImageIdentifier::builder().image_digest(digest).build())
+                        .collect(),
+                ))
+                .send()
+                .await?;
+            deleted += resp.image_ids().len();
+
+            if let Some(failure) = resp.failures().first() {
+                return Err(anyhow!(
+                    "Deleted {} images, then failed: {}",
+                    deleted,
+                    failure.failure_reason().unwrap_or("unknown reason")
+                ));
+            }
+        }
+        Ok(deleted)
+    }
+
+    /// Delete a single image by digest, removing every tag that points at it
+    pub async fn delete_image(&self, repository_name: &str, digest: &str) -> Result<()> {
+        self.delete_images(repository_name, &[digest.to_string()])
+            .await
+            .map(|_| ())
+    }
+
+    /// Digests of the untagged images currently shown in the image view
+    fn visible_untagged_digests(&self, view_state: &ViewState, data: &ResourceData) -> Vec<String> {
+        self.visible_items(data, view_state)
+            .iter()
+            .filter_map(|item| item.as_any().downcast_ref::<ECRImage>())
+            .filter(|image| image.is_untagged())
+            .map(|image| image.image_digest.clone())
+            .collect()
+    }
+
+    fn selected_repository<'a>(
+        &self,
+        view_state: &ViewState,
+        data: &'a ResourceData,
+    ) -> Option<&'a ECRRepository> {
+        self.visible_items(data, view_state)
+            .get(view_state.selected_index)
+            .and_then(|item| item.as_any().downcast_ref::<ECRRepository>())
+    }
+
+    fn region(&self) -> String {
+        self.client
+            .config()
+            .region()
+            .map(|region| region.to_string())
+            .unwrap_or_else(|| "us-east-1".to_string())
+    }
+
+    fn selected_image<'a>(
+        &self,
+        view_state: &ViewState,
+        data: &'a ResourceData,
+    ) -> Option<&'a ECRImage> {
+        self.visible_items(data, view_state)
+            .get(view_state.selected_index)
+            .and_then(|item| item.as_any().downcast_ref::<ECRImage>())
+    }
+
+    /// Images `d` deletes: the rows marked with Space, or the selected one when none are
+    fn images_to_delete<'a>(
+        &self,
+        view_state: &ViewState,
+        data: &'a ResourceData,
+    ) -> Vec<&'a ECRImage> {
+        if view_state.selected_items.is_empty() {
+            return self.selected_image(view_state, data).into_iter().collect();
+        }
+
+        let items = self.visible_items(data, view_state);
+        let mut indices: Vec<usize> = view_state.selected_items.iter().copied().collect();
+        indices.sort_unstable();
+        indices
+            .into_iter()
+            .filter_map(|index| items.get(index)?.as_any().downcast_ref::<ECRImage>())
+            .collect()
+    }
 }
 
 #[async_trait]
 impl AwsService for ECRService {
     fn metadata(&self) -> ServiceMetadata {
         ServiceMetadata {
-            id: "ecr".to_string(),
+            id: ECR_SERVICE_ID.to_string(),
             name: "Elastic Container Registry".to_string(),
             description: "AWS Container Registry for Docker images".to_string(),
             command: "ecr".to_string(),
+            aliases: vec!["repos".to_string()],
+            short_label: Some("ECR".to_string()),
+            icon: Some("◆".to_string()),
         }
     }
 
@@ -180,29 +1024,46 @@ impl AwsService for ECRService {
         match view_state.view_type {
             ViewType::List => {
                 let repositories = self.list_repositories().await?;
-                Ok(ResourceData {
-                    items: repositories
+                Ok(ResourceData::new(
+                    repositories
                         .into_iter()
                         .map(|repo| Box::new(repo) as Box<dyn ResourceItem>)
                         .collect(),
-                })
+                ))
             }
             ViewType::Detail => {
                 if let Some(context) = &view_state.context {
                     // Extract repository name from "name|uri" format
                     let repo_name = context.split('|').next().unwrap_or(context);
                     let images = self.get_repository_images(repo_name).await?;
-                    Ok(ResourceData {
-                        items: images
+                    Ok(ResourceData::new(
+                        images
                             .into_iter()
                             .map(|img| Box::new(img) as Box<dyn ResourceItem>)
                             .collect(),
-                    })
+                    ))
                 } else {
-                    Ok(ResourceData { items: Vec::new() })
+                    Ok(ResourceData::new(Vec::new()))
                 }
             }
-            ViewType::Custom(_) => Ok(ResourceData { items: Vec::new() }),
+            ViewType::Custom(ref name) if name == PLATFORMS_VIEW => {
+                let context = view_state
+                    .context
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("No image index selected"))?;
+                let mut parts = context.split('|');
+                let (Some(repo_name), Some(index_digest)) = (parts.next(), parts.nth(1)) else {
+                    return Ok(ResourceData::new(Vec::new()));
+                };
+                let platforms = self.get_index_platforms(repo_name, index_digest).await?;
+                Ok(ResourceData::new(
+                    platforms
+                        .into_iter()
+                        .map(|platform| Box::new(platform) as Box<dyn ResourceItem>)
+                        .collect(),
+                ))
+            }
+            ViewType::Custom(_) => Ok(ResourceData::new(Vec::new())),
         }
     }
 
@@ -217,6 +1078,9 @@ impl AwsService for ECRService {
         match view_state.view_type {
             ViewType::List => self.render_repositories(f, area, app, view_state, data),
             ViewType::Detail => self.render_images(f, area, app, view_state, data),
+            ViewType::Custom(ref name) if name == PLATFORMS_VIEW => {
+                self.render_platforms(f, area, app, view_state, data)
+            }
             ViewType::Custom(_) => {}
         }
     }
@@ -224,23 +1088,38 @@ impl AwsService for ECRService {
     fn handle_enter(&self, view_state: &mut ViewState, data: &ResourceData) -> Option<ViewState> {
         match view_state.view_type {
             ViewType::List => {
-                let filtered_items = self.filter_data(data, &view_state.search_filter);
-                if view_state.selected_index < filtered_items.len() {
-                    if let Some(repo) = filtered_items[view_state.selected_index]
+                let filtered_items = self.visible_items(data, view_state);
+                if view_state.selected_index < filtered_items.len()
+                    && let Some(repo) = filtered_items[view_state.selected_index]
                         .as_any()
                         .downcast_ref::<ECRRepository>()
-                    {
-                        let mut new_view =
-                            ViewState::new(view_state.service_id.clone(), ViewType::Detail);
-                        // Store both repository name and URI separated by "|"
-                        new_view.context =
-                            Some(format!("{}|{}", repo.repository_name, repo.repository_uri));
-                        return Some(new_view);
-                    }
+                {
+                    let mut new_view =
+                        ViewState::new(view_state.service_id.clone(), ViewType::Detail);
+                    // Store both repository name and URI separated by "|"
+                    new_view.context =
+                        Some(format!("{}|{}", repo.repository_name, repo.repository_uri));
+                    return Some(new_view);
                 }
                 None
             }
-            ViewType::Detail => None, // Future: handle image drill-down
+            // Image indexes open a list of their per-platform images
+            ViewType::Detail => {
+                let image = self.selected_image(view_state, data)?;
+                if !image.is_index() {
+                    return None;
+                }
+                let mut new_view = ViewState::new(
+                    view_state.service_id.clone(),
+                    ViewType::Custom(PLATFORMS_VIEW.to_string()),
+                );
+                new_view.context = Some(format!(
+                    "{}|{}",
+                    view_state.context.as_deref()?,
+                    image.image_digest
+                ));
+                Some(new_view)
+            }
             ViewType::Custom(_) => None,
         }
     }
@@ -250,7 +1129,7 @@ impl AwsService for ECRService {
         view_state: &ViewState,
         data: &ResourceData,
     ) -> Option<(String, String)> {
-        let filtered_items = self.filter_data(data, &view_state.search_filter);
+        let filtered_items = self.visible_items(data, view_state);
         if view_state.selected_index >= filtered_items.len() {
             return None;
         }
@@ -276,7 +1155,7 @@ impl AwsService for ECRService {
                     if let Some(context) = &view_state.context {
                         // Parse "repo_name|repo_uri" format
                         let parts: Vec<&str> = context.split('|').collect();
-                        let repo_name = parts.get(0).unwrap_or(&"unknown");
+                        let repo_name = parts.first().unwrap_or(&"unknown");
                         let repo_uri = parts
                             .get(1)
                             .unwrap_or(&"unknown.dkr.ecr.region.amazonaws.com");
@@ -302,11 +1181,567 @@ impl AwsService for ECRService {
                     None
                 }
             }
-            ViewType::Custom(_) => None,
+            ViewType::Custom(_) => {
+                let platform = filtered_items[view_state.selected_index]
+                    .as_any()
+                    .downcast_ref::<ImagePlatform>()?;
+                let mut parts = view_state.context.as_ref()?.split('|');
+                let repo_name = parts.next()?;
+                let repo_uri = parts.next()?;
+                Some((
+                    format!("{}@{}", repo_uri, platform.digest),
+                    format!("{} ({})", repo_name, platform.platform()),
+                ))
+            }
         }
     }
 
-    fn matches_filter(&self, item: &dyn ResourceItem, filter: &str) -> bool {
+    fn copy_actions(&self, view_state: &ViewState) -> Vec<ServiceAction> {
+        match view_state.view_type {
+            ViewType::List => vec![
+                ServiceAction {
+                    id: "repository-arn".to_string(),
+                    key: 'C',
+                    description: "Copy repository ARN".to_string(),
+                    prompt: None,
+                },
+                ServiceAction {
+                    id: "scan-on-push-command".to_string(),
+                    key: 'S',
+                    description: "Copy CLI command enabling scan on push".to_string(),
+                    prompt: None,
+                },
+            ],
+            ViewType::Detail => vec![
+                ServiceAction {
+                    id: "media-type".to_string(),
+                    key: 'M',
+                    description: "Copy image media type".to_string(),
+                    prompt: None,
+                },
+                ServiceAction {
+                    id: "digest".to_string(),
+                    key: 'D',
+                    description: "Copy image digest".to_string(),
+                    prompt: None,
+                },
+                ServiceAction {
+                    id: "untagged-digests".to_string(),
+                    key: 'U',
+                    description: "Copy digests of shown untagged images".to_string(),
+                    prompt: None,
+                },
+            ],
+            _ => Vec::new(),
+        }
+    }
+
+    fn copy_fields(&self, view_state: &ViewState, data: &ResourceData) -> Vec<(&str, String)> {
+        let Some(item) = self
+            .visible_items(data, view_state)
+            .get(view_state.selected_index)
+            .copied()
+        else {
+            return Vec::new();
+        };
+        if let Some(repo) = item.as_any().downcast_ref::<ECRRepository>() {
+            return vec![
+                ("name", repo.repository_name.clone()),
+                ("uri", repo.repository_uri.clone()),
+                ("arn", repo.repository_arn.clone()),
+            ];
+        }
+
+        // Image and platform views carry the repository as "name|uri"
+        let Some((repo_name, repo_uri)) = view_state
+            .context
+            .as_deref()
+            .and_then(|context| context.split_once('|'))
+        else {
+            return Vec::new();
+        };
+        let repo_uri = repo_uri.split('|').next().unwrap_or(repo_uri);
+        let (tag, digest) = if let Some(image) = item.as_any().downcast_ref::<ECRImage>() {
+            (image.image_tag.clone(), image.image_digest.clone())
+        } else if let Some(platform) = item.as_any().downcast_ref::<ImagePlatform>() {
+            (None, platform.digest.clone())
+        } else {
+            return Vec::new();
+        };
+        // What `docker pull` takes: the tag when there is one, the digest otherwise
+        let reference = match &tag {
+            Some(tag) => format!("{}:{}", repo_uri, tag),
+            None => format!("{}@{}", repo_uri, digest),
+        };
+        vec![
+            ("name", repo_name.to_string()),
+            ("uri", repo_uri.to_string()),
+            ("tag", tag.unwrap_or_default()),
+            ("digest", digest),
+            ("ref", reference),
+        ]
+    }
+
+    fn get_copy_content_for(
+        &self,
+        action_id: &str,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) -> Option<(String, String)> {
+        match action_id {
+            "repository-arn" => {
+                let repo = self.selected_repository(view_state, data)?;
+                if repo.repository_arn.is_empty() {
+                    return None;
+                }
+                Some((
+                    repo.repository_arn.clone(),
+                    format!("ARN of {}", repo.repository_name),
+                ))
+            }
+            "scan-on-push-command" => {
+                let repo = self.selected_repository(view_state, data)?;
+                let command = format!(
+                    "aws ecr put-image-scanning-configuration --repository-name {} \
+                     --image-scanning-configuration scanOnPush=true --region {}",
+                    repo.repository_name,
+                    self.region()
+                );
+                Some((
+                    command,
+                    format!("Scan-on-push command for {}", repo.repository_name),
+                ))
+            }
+            "media-type" => {
+                let image = self.selected_image(view_state, data)?;
+                let media_type = image.media_type()?;
+                Some((media_type.to_string(), media_type.to_string()))
+            }
+            "digest" => {
+                let image = self.selected_image(view_state, data)?;
+                Some((image.image_digest.clone(), image.image_digest.clone()))
+            }
+            "untagged-digests" => {
+                let digests = self.visible_untagged_digests(view_state, data);
+                if digests.is_empty() {
+                    return None;
+                }
+                let display_name = format!("{} untagged digests", digests.len());
+                Some((digests.join("\n"), display_name))
+            }
+            _ => None,
+        }
+    }
+
+    fn export_columns(&self, view_state: &ViewState) -> Vec<String> {
+        match view_state.view_type {
+            ViewType::List => REPOSITORY_COLUMNS.iter().map(|c| c.to_string()).collect(),
+            ViewType::Detail => IMAGE_COLUMNS.iter().map(|c| c.to_string()).collect(),
+            ViewType::Custom(ref name) if name == PLATFORMS_VIEW => {
+                PLATFORM_COLUMNS.iter().map(|c| c.to_string()).collect()
+            }
+            ViewType::Custom(_) => Vec::new(),
+        }
+    }
+
+    fn export_rows(&self, view_state: &ViewState, data: &ResourceData) -> Vec<Vec<String>> {
+        let format_date = |dt: Option<DateTime<Utc>>| {
+            dt.map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_else(|| "Unknown".to_string())
+        };
+
+        let items = self.visible_items(data, view_state);
+        let total_size = total_image_size(
+            items
+                .iter()
+                .filter_map(|item| item.as_any().downcast_ref::<ECRImage>()),
+        );
+
+        items
+            .iter()
+            .filter_map(|item| {
+                if let Some(repo) = item.as_any().downcast_ref::<ECRRepository>() {
+                    Some(vec![
+                        repo.repository_name.clone(),
+                        repo.registry_id.clone(),
+                        format_date(repo.created_at),
+                        repo.image_tag_mutability.clone(),
+                        if repo.image_scanning_configuration {
+                            "Yes".to_string()
+                        } else {
+                            "No".to_string()
+                        },
+                        repo.encryption_configuration.clone(),
+                    ])
+                } else if let Some(platform) = item.as_any().downcast_ref::<ImagePlatform>() {
+                    Some(platform_cells(platform))
+                } else {
+                    item.as_any().downcast_ref::<ECRImage>().map(|image| {
+                        vec![
+                            image
+                                .image_tag
+                                .clone()
+                                .unwrap_or_else(|| "<none>".to_string()),
+                            image.image_digest.clone(),
+                            format_date(image.image_pushed_at),
+                            image
+                                .image_size_in_bytes
+                                .map(|s| format!("{:.1} MB", s as f64 / 1_048_576.0))
+                                .unwrap_or_else(|| "Unknown".to_string()),
+                            size_share(image, total_size),
+                            image.artifact_kind(),
+                            image.vulnerability_text(),
+                        ]
+                    })
+                }
+            })
+            .collect()
+    }
+
+    fn group_key(&self, item: &dyn ResourceItem) -> Option<String> {
+        item.as_any()
+            .downcast_ref::<ECRRepository>()
+            .map(|repo| repository_namespace(&repo.repository_name).to_string())
+    }
+
+    fn actions(&self, view_state: &ViewState) -> Vec<ServiceAction> {
+        match view_state.view_type {
+            ViewType::Detail => vec![
+                ServiceAction {
+                    id: "tag".to_string(),
+                    key: 'T',
+                    description: "Add tag to image".to_string(),
+                    prompt: Some("New tag".to_string()),
+                },
+                ServiceAction {
+                    id: "scan".to_string(),
+                    key: 's',
+                    description: "Start vulnerability scan".to_string(),
+                    prompt: None,
+                },
+                ServiceAction {
+                    id: "delete".to_string(),
+                    key: 'd',
+                    description: "Delete marked or selected images".to_string(),
+                    prompt: None,
+                },
+            ],
+            _ => Vec::new(),
+        }
+    }
+
+    fn confirmation(
+        &self,
+        action_id: &str,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) -> Option<Confirmation> {
+        match action_id {
+            "delete" => {
+                let images = self.images_to_delete(view_state, data);
+                let repo_name = view_state.context.as_ref()?.split('|').next()?;
+                // A single image is confirmed by its reference, a batch by the repository
+                let target = match images.as_slice() {
+                    [] => return None,
+                    [image] => image_reference(repo_name, image),
+                    _ => repo_name.to_string(),
+                };
+                Some(Confirmation {
+                    item_count: images.len(),
+                    target,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    async fn run_action(
+        &self,
+        action_id: &str,
+        view_state: &ViewState,
+        data: &ResourceData,
+        input: Option<&str>,
+    ) -> Result<String> {
+        let repo_name = view_state
+            .context
+            .as_ref()
+            .map(|context| context.split('|').next().unwrap_or(context))
+            .ok_or_else(|| anyhow!("No repository selected"))?;
+
+        match action_id {
+            "tag" => {
+                let image = self
+                    .selected_image(view_state, data)
+                    .ok_or_else(|| anyhow!("No image selected"))?;
+                let new_tag = input.map(str::trim).unwrap_or_default();
+                if new_tag.is_empty() {
+                    return Err(anyhow!("Tag must not be empty"));
+                }
+                self.tag_image(repo_name, &image.image_digest, new_tag)
+                    .await?;
+                Ok(format!("✓ Tagged {}:{}", repo_name, new_tag))
+            }
+            "scan" => {
+                let image = self
+                    .selected_image(view_state, data)
+                    .ok_or_else(|| anyhow!("No image selected"))?;
+                let reference = image_reference(repo_name, image);
+                if self
+                    .start_image_scan(repo_name, &image.image_digest)
+                    .await?
+                {
+                    Ok(format!("✓ Scan started for {}", reference))
+                } else {
+                    // Not worth an error popup; the earlier scan's results are still current
+                    Ok(format!("✗ {} was already scanned recently", reference))
+                }
+            }
+            "delete" => match self.images_to_delete(view_state, data).as_slice() {
+                [] => Err(anyhow!("No image selected")),
+                [image] => {
+                    self.delete_image(repo_name, &image.image_digest).await?;
+                    Ok(format!("✓ Deleted {}", image_reference(repo_name, image)))
+                }
+                images => {
+                    let digests: Vec<String> = images
+                        .iter()
+                        .map(|image| image.image_digest.clone())
+                        .collect();
+                    let deleted = self.delete_images(repo_name, &digests).await?;
+                    Ok(format!("✓ Deleted {} images from {}", deleted, repo_name))
+                }
+            },
+            _ => Err(anyhow!("Action '{}' is not supported", action_id)),
+        }
+    }
+
+    fn console_url(&self, view_state: &ViewState, data: &ResourceData) -> Option<String> {
+        let region = self.region();
+        match view_state.view_type {
+            ViewType::List => {
+                let repo = self.selected_repository(view_state, data)?;
+                Some(console_url(
+                    &region,
+                    &repo.registry_id,
+                    &repo.repository_name,
+                    None,
+                ))
+            }
+            ViewType::Detail => {
+                let mut parts = view_state.context.as_ref()?.split('|');
+                let repo_name = parts.next()?;
+                let account = uri_account(parts.next()?)?;
+                let image = self.selected_image(view_state, data)?;
+                Some(console_url(
+                    &region,
+                    account,
+                    repo_name,
+                    Some(&image.image_digest),
+                ))
+            }
+            ViewType::Custom(ref name) if name == PLATFORMS_VIEW => {
+                let mut parts = view_state.context.as_ref()?.split('|');
+                let repo_name = parts.next()?;
+                let account = uri_account(parts.next()?)?;
+                let platform = self
+                    .visible_items(data, view_state)
+                    .get(view_state.selected_index)?
+                    .as_any()
+                    .downcast_ref::<ImagePlatform>()?;
+                Some(console_url(
+                    &region,
+                    account,
+                    repo_name,
+                    Some(&platform.digest),
+                ))
+            }
+            ViewType::Custom(_) => None,
+        }
+    }
+
+    fn detail_lines(&self, view_state: &ViewState, data: &ResourceData) -> Vec<(String, String)> {
+        match view_state.view_type {
+            ViewType::List => self
+                .selected_repository(view_state, data)
+                .map(ECRRepository::detail_lines),
+            ViewType::Detail => self
+                .selected_image(view_state, data)
+                .map(ECRImage::detail_lines),
+            ViewType::Custom(_) => None,
+        }
+        .unwrap_or_default()
+    }
+
+    fn deep_link_path(&self, view_state: &ViewState) -> Option<String> {
+        match view_state.view_type {
+            ViewType::List => Some(String::new()),
+            ViewType::Detail => {
+                let repo_name = view_state.context.as_ref()?.split('|').next()?;
+                Some(format!("{}/images", repo_name))
+            }
+            ViewType::Custom(_) => None,
+        }
+    }
+
+    fn view_from_deep_link(
+        &self,
+        service_id: &ServiceId,
+        path: &str,
+        cached: Option<&ResourceData>,
+    ) -> Option<ViewState> {
+        if path.is_empty() {
+            return Some(ViewState::new(service_id.clone(), ViewType::List));
+        }
+
+        // Repository names may contain `/`, so only the suffix marks the view
+        let repo_name = path.strip_suffix("/images")?;
+        // The link only carries the name; recover the URI from the repository list if loaded
+        let repo_uri = cached
+            .into_iter()
+            .flat_map(|data| &data.items)
+            .filter_map(|item| item.as_any().downcast_ref::<ECRRepository>())
+            .find(|repo| repo.repository_name == repo_name)
+            .map(|repo| repo.repository_uri.clone())
+            .unwrap_or_default();
+
+        let mut view_state = ViewState::new(service_id.clone(), ViewType::Detail);
+        view_state.context = Some(format!("{}|{}", repo_name, repo_uri));
+        Some(view_state)
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.rate_limiter.acquire().await;
+        // Include the underlying cause (DNS, TLS, timeout) to help diagnose connectivity
+        self.client
+            .describe_registry()
+            .send()
+            .await
+            .map_err(|e| anyhow!("{}", DisplayErrorContext(&e)))?;
+        Ok(())
+    }
+
+    fn filter_columns(&self, view_state: &ViewState) -> Vec<&'static str> {
+        match view_state.view_type {
+            ViewType::List => vec!["name", "mutability", "encryption", "scan"],
+            ViewType::Detail => vec!["tag", "digest", "type", "size", "pushed"],
+            ViewType::Custom(_) => Vec::new(),
+        }
+    }
+
+    fn matches_column(&self, item: &dyn ResourceItem, column: &str, value: &str) -> bool {
+        let field = if let Some(repo) = item.as_any().downcast_ref::<ECRRepository>() {
+            match column {
+                "name" => Some(repo.repository_name.clone()),
+                "mutability" => Some(repo.image_tag_mutability.clone()),
+                "encryption" => Some(repo.encryption_configuration.clone()),
+                // Scan on push, e.g. `scan:no`
+                "scan" => Some(
+                    if repo.image_scanning_configuration {
+                        "yes"
+                    } else {
+                        "no"
+                    }
+                    .to_string(),
+                ),
+                _ => None,
+            }
+        } else if let Some(image) = item.as_any().downcast_ref::<ECRImage>() {
+            match column {
+                "tag" => image.image_tag.clone(),
+                "digest" => Some(image.image_digest.clone()),
+                "type" => Some(image.artifact_kind()),
+                // Both match the text shown in the table, e.g. `size:800.0` or `pushed:2024-03`
+                "size" => image
+                    .image_size_in_bytes
+                    .map(|s| format!("{:.1} MB", s as f64 / 1_048_576.0)),
+                "pushed" => image
+                    .image_pushed_at
+                    .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string()),
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        field.is_some_and(|field| field.to_lowercase().contains(&value.to_lowercase()))
+    }
+
+    fn compare_key(&self, item: &dyn ResourceItem, column: &str) -> Option<f64> {
+        let image = item.as_any().downcast_ref::<ECRImage>()?;
+        match column {
+            "size" => image.image_size_in_bytes.map(|size| size as f64),
+            "pushed" => image.image_pushed_at.map(|dt| dt.timestamp() as f64),
+            _ => None,
+        }
+    }
+
+    fn sort_value(&self, item: &dyn ResourceItem, column: usize) -> Option<SortValue> {
+        let text = |value: &str| Some(SortValue::Text(value.to_lowercase()));
+        let timestamp =
+            |dt: Option<DateTime<Utc>>| dt.map(|dt| SortValue::Number(dt.timestamp() as f64));
+        let size = |bytes: Option<i64>| bytes.map(|bytes| SortValue::Number(bytes as f64));
+
+        if let Some(repo) = item.as_any().downcast_ref::<ECRRepository>() {
+            match column {
+                0 => text(&repo.repository_name),
+                1 => text(&repo.registry_id),
+                2 => timestamp(repo.created_at),
+                3 => text(&repo.image_tag_mutability),
+                4 => text(if repo.image_scanning_configuration {
+                    "yes"
+                } else {
+                    "no"
+                }),
+                5 => text(&repo.encryption_configuration),
+                _ => None,
+            }
+        } else if let Some(image) = item.as_any().downcast_ref::<ECRImage>() {
+            match column {
+                0 => image.image_tag.as_deref().and_then(text),
+                1 => text(&image.image_digest),
+                2 => timestamp(image.image_pushed_at),
+                // The share of the total follows the size
+                3 | 4 => size(image.image_size_in_bytes),
+                5 => text(&image.artifact_kind()),
+                6 => image
+                    .severity_counts
+                    .as_ref()
+                    .map(|counts| SortValue::Number(counts.total() as f64)),
+                _ => None,
+            }
+        } else {
+            let platform = item.as_any().downcast_ref::<ImagePlatform>()?;
+            match column {
+                0 => text(&platform.platform()),
+                1 => text(&platform.digest),
+                2 => size(platform.image_size_in_bytes),
+                3 => platform.media_type.as_deref().and_then(text),
+                _ => None,
+            }
+        }
+    }
+
+    fn parse_compare_value(&self, column: &str, value: &str) -> Result<f64, String> {
+        match column {
+            "size" => parse_size_mb(value),
+            "pushed" => parse_date(value),
+            _ => Err(format!("{} can't be compared with > or <", column)),
+        }
+    }
+
+    fn fuzzy_key(&self, item: &dyn ResourceItem) -> Option<String> {
+        if let Some(repo) = item.as_any().downcast_ref::<ECRRepository>() {
+            Some(repo.repository_name.clone())
+        } else if let Some(image) = item.as_any().downcast_ref::<ECRImage>() {
+            image.image_tag.clone()
+        } else {
+            item.as_any()
+                .downcast_ref::<ImagePlatform>()
+                .map(ImagePlatform::platform)
+        }
+    }
+
+    fn matches_filter(&self, item: &dyn ResourceItem, filter: &str) -> bool {
         if let Some(repo) = item.as_any().downcast_ref::<ECRRepository>() {
             repo.repository_name
                 .to_lowercase()
@@ -317,6 +1752,8 @@ impl AwsService for ECRService {
                 .as_ref()
                 .map(|tag| tag.to_lowercase().contains(&filter.to_lowercase()))
                 .unwrap_or(false)
+        } else if let Some(platform) = item.as_any().downcast_ref::<ImagePlatform>() {
+            platform.platform().contains(&filter.to_lowercase())
         } else {
             false
         }
@@ -332,9 +1769,9 @@ impl ECRService {
         view_state: &ViewState,
         data: &ResourceData,
     ) {
-        let filtered_items = self.filter_data(data, &view_state.search_filter);
+        let filtered_items = self.visible_items(data, view_state);
 
-        let title = if app.loading {
+        let mut title = if app.loading {
             "ECR Repositories (Loading...)".to_string()
         } else if view_state.search_filter.is_empty() {
             format!("ECR Repositories ({})", filtered_items.len())
@@ -346,48 +1783,106 @@ impl ECRService {
                 view_state.search_filter
             )
         };
+        if view_state.grouped {
+            title.push_str(" - Grouped by namespace");
+        }
+
+        let order = column_order(&REPOSITORY_COLUMNS, app.config.column_order(ECR_SERVICE_ID));
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(
+                f,
+                area,
+                &app.theme,
+                &title,
+                &reorder(&REPOSITORY_COLUMNS, &order),
+                &reorder(&REPOSITORY_COLUMN_WIDTHS, &order)
+                    .into_iter()
+                    .map(Constraint::Length)
+                    .collect::<Vec<_>>(),
+            );
+            return;
+        }
 
         if filtered_items.is_empty() {
-            let message = if app.loading {
-                "Loading ECR repositories..."
-            } else if !view_state.search_filter.is_empty() {
+            let message = if !view_state.search_filter.is_empty() {
                 "No repositories match the current filter"
             } else {
-                "No ECR repositories found"
+                &format!("No ECR repositories found in {}", self.region())
             };
 
             let empty_paragraph = ratatui::widgets::Paragraph::new(message)
                 .block(Block::default().borders(Borders::ALL).title(title))
-                .style(Style::default().fg(Color::Gray));
+                .style(Style::default().fg(app.theme.muted));
 
             f.render_widget(empty_paragraph, area);
             return;
         }
 
-        let header_cells = [
-            "REPOSITORY NAME",
-            "REGISTRY ID",
-            "CREATED",
-            "TAG MUTABILITY",
-            "SCAN ON PUSH",
-            "ENCRYPTION",
-        ]
-        .iter()
-        .map(|h| {
-            Cell::from(*h).style(
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-            )
-        });
+        let header_cells = reorder(
+            &sort_headers(
+                &REPOSITORY_COLUMNS,
+                view_state.sort_key,
+                view_state.sort_ascending,
+            ),
+            &order,
+        )
+        .into_iter()
+        .map(|h| Cell::from(h).style(app.theme.header_style()));
 
         let header = Row::new(header_cells).height(1).bottom_margin(0);
 
+        // Item counts per namespace for the group header rows
+        let mut group_sizes: HashMap<&str, usize> = HashMap::new();
+        if view_state.grouped {
+            for item in &filtered_items {
+                if let Some(repo) = item.as_any().downcast_ref::<ECRRepository>() {
+                    *group_sizes
+                        .entry(repository_namespace(&repo.repository_name))
+                        .or_default() += 1;
+                }
+            }
+        }
+
+        // Group header rows push the selected item further down the table
+        let mut selected_row = view_state.selected_index;
         let rows: Vec<Row> = filtered_items
             .iter()
             .enumerate()
-            .filter_map(|(i, item)| {
+            .flat_map(|(i, item)| {
+                let mut rows = Vec::new();
                 if let Some(repo) = item.as_any().downcast_ref::<ECRRepository>() {
+                    let namespace = repository_namespace(&repo.repository_name);
+                    let starts_group = i == 0
+                        || filtered_items[i - 1]
+                            .as_any()
+                            .downcast_ref::<ECRRepository>()
+                            .is_none_or(|prev| {
+                                repository_namespace(&prev.repository_name) != namespace
+                            });
+                    if view_state.grouped && starts_group {
+                        if i <= view_state.selected_index {
+                            selected_row += 1;
+                        }
+                        let label = if namespace.is_empty() {
+                            "(no namespace)".to_string()
+                        } else {
+                            format!("{}/", namespace)
+                        };
+                        rows.push(
+                            Row::new(vec![Cell::from(format!(
+                                "▾ {} ({})",
+                                label,
+                                group_sizes.get(namespace).copied().unwrap_or(0)
+                            ))])
+                            .style(
+                                Style::default()
+                                    .fg(app.theme.accent)
+                                    .add_modifier(Modifier::BOLD),
+                            ),
+                        );
+                    }
+
                     let created_str = repo
                         .created_at
                         .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
@@ -399,45 +1894,62 @@ impl ECRService {
                         "No"
                     };
 
-                    let style = if i == view_state.selected_index {
-                        Style::default().bg(Color::Yellow).fg(Color::Black)
+                    let style = row_style(app, view_state, i);
+                    let active_column =
+                        (i == view_state.selected_index).then_some(view_state.column_cursor);
+
+                    let row = if view_state.wrap_cells {
+                        wrapped_row(
+                            vec![
+                                repo.repository_name.clone(),
+                                repo.registry_id.clone(),
+                                created_str,
+                                repo.image_tag_mutability.clone(),
+                                scan_on_push.to_string(),
+                                repo.encryption_configuration.clone(),
+                            ],
+                            &REPOSITORY_COLUMN_WIDTHS,
+                            &order,
+                            active_column,
+                        )
                     } else {
-                        Style::default()
+                        Row::new(mark_active_cell(
+                            reorder(
+                                &[
+                                    Cell::from(highlight_match(
+                                        &truncate_with_ellipsis(
+                                            &repo.repository_name,
+                                            REPOSITORY_COLUMN_WIDTHS[0] as usize,
+                                        ),
+                                        highlight_pattern(&view_state.search_filter, "name"),
+                                    )),
+                                    Cell::from(repo.registry_id.clone()),
+                                    Cell::from(created_str),
+                                    Cell::from(repo.image_tag_mutability.clone()),
+                                    Cell::from(scan_on_push),
+                                    Cell::from(repo.encryption_configuration.clone()),
+                                ],
+                                &order,
+                            ),
+                            active_column,
+                        ))
                     };
-
-                    Some(
-                        Row::new(vec![
-                            Cell::from(repo.repository_name.clone()),
-                            Cell::from(repo.registry_id.clone()),
-                            Cell::from(created_str),
-                            Cell::from(repo.image_tag_mutability.clone()),
-                            Cell::from(scan_on_push),
-                            Cell::from(repo.encryption_configuration.clone()),
-                        ])
-                        .style(style),
-                    )
-                } else {
-                    None
+                    rows.push(row.style(style));
                 }
+                rows
             })
             .collect();
 
         let table = Table::new(
             rows,
-            [
-                Constraint::Length(30), // Repository name
-                Constraint::Length(15), // Registry ID
-                Constraint::Length(20), // Created
-                Constraint::Length(16), // Tag mutability
-                Constraint::Length(12), // Scan on push
-                Constraint::Length(12), // Encryption
-            ],
+            reorder(&REPOSITORY_COLUMN_WIDTHS, &order)
+                .into_iter()
+                .map(Constraint::Length),
         )
         .header(header)
-        .block(Block::default().borders(Borders::ALL).title(title))
-        .row_highlight_style(Style::default().bg(Color::Blue).fg(Color::White));
+        .block(Block::default().borders(Borders::ALL).title(title));
 
-        f.render_widget(table, area);
+        f.render_stateful_widget(table, area, &mut table_state(view_state, selected_row));
     }
 
     fn render_images(
@@ -448,7 +1960,7 @@ impl ECRService {
         view_state: &ViewState,
         data: &ResourceData,
     ) {
-        let filtered_items = self.filter_data(data, &view_state.search_filter);
+        let filtered_items = self.visible_items(data, view_state);
         let default_repo = "Unknown".to_string();
         let repo_name = view_state
             .context
@@ -474,10 +1986,25 @@ impl ECRService {
             )
         };
 
+        let order = column_order(&IMAGE_COLUMNS, app.config.column_order(ECR_SERVICE_ID));
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(
+                f,
+                area,
+                &app.theme,
+                &title,
+                &reorder(&IMAGE_COLUMNS, &order),
+                &reorder(&IMAGE_COLUMN_WIDTHS, &order)
+                    .into_iter()
+                    .map(Constraint::Length)
+                    .collect::<Vec<_>>(),
+            );
+            return;
+        }
+
         if filtered_items.is_empty() {
-            let message = if app.loading {
-                "Loading ECR images..."
-            } else if !view_state.search_filter.is_empty() {
+            let message = if !view_state.search_filter.is_empty() {
                 "No images match the current filter"
             } else {
                 "No ECR images found"
@@ -485,27 +2012,28 @@ impl ECRService {
 
             let empty_paragraph = ratatui::widgets::Paragraph::new(message)
                 .block(Block::default().borders(Borders::ALL).title(title))
-                .style(Style::default().fg(Color::Gray));
+                .style(Style::default().fg(app.theme.muted));
 
             f.render_widget(empty_paragraph, area);
             return;
         }
 
-        let header_cells = [
-            "IMAGE TAG",
-            "DIGEST",
-            "PUSHED AT",
-            "SIZE",
-            "VULNERABILITIES",
-        ]
-        .iter()
-        .map(|h| {
-            Cell::from(*h).style(
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-            )
-        });
+        let total_size = total_image_size(
+            filtered_items
+                .iter()
+                .filter_map(|item| item.as_any().downcast_ref::<ECRImage>()),
+        );
+
+        let header_cells = reorder(
+            &sort_headers(
+                &IMAGE_COLUMNS,
+                view_state.sort_key,
+                view_state.sort_ascending,
+            ),
+            &order,
+        )
+        .into_iter()
+        .map(|h| Cell::from(h).style(app.theme.header_style()));
 
         let header = Row::new(header_cells).height(1).bottom_margin(0);
 
@@ -528,28 +2056,70 @@ impl ECRService {
                         .image_size_in_bytes
                         .map(|s| format!("{:.1} MB", s as f64 / 1_048_576.0))
                         .unwrap_or_else(|| "Unknown".to_string());
-                    let vulnerabilities = image
-                        .vulnerability_scan_summary
-                        .as_ref()
-                        .unwrap_or(&"Not scanned".to_string())
-                        .clone();
+                    let vulnerabilities = match &image.severity_counts {
+                        Some(counts) if counts.total() > 0 => {
+                            severity_line(counts, area.width >= SEVERITY_BAR_MIN_AREA_WIDTH)
+                        }
+                        // Keep the text readable on the selection highlight
+                        _ if image.scan_failed() && i != view_state.selected_index => Line::styled(
+                            image.vulnerability_text(),
+                            Style::default().fg(app.theme.error),
+                        ),
+                        _ => Line::from(
+                            image
+                                .vulnerability_scan_summary
+                                .as_ref()
+                                .unwrap_or(&"Not scanned".to_string())
+                                .clone(),
+                        ),
+                    };
+
+                    let style = row_style(app, view_state, i);
+                    let active_column =
+                        (i == view_state.selected_index).then_some(view_state.column_cursor);
 
-                    let style = if i == view_state.selected_index {
-                        Style::default().bg(Color::Yellow).fg(Color::Black)
+                    let row = if view_state.wrap_cells {
+                        wrapped_row(
+                            vec![
+                                tag,
+                                digest,
+                                pushed_at,
+                                size,
+                                size_share(image, total_size),
+                                image.artifact_kind(),
+                                image.vulnerability_text(),
+                            ],
+                            &IMAGE_COLUMN_WIDTHS,
+                            &order,
+                            active_column,
+                        )
                     } else {
-                        Style::default()
+                        Row::new(mark_active_cell(
+                            reorder(
+                                &[
+                                    Cell::from(highlight_match(
+                                        &truncate_with_ellipsis(
+                                            &tag,
+                                            IMAGE_COLUMN_WIDTHS[0] as usize,
+                                        ),
+                                        highlight_pattern(&view_state.search_filter, "tag"),
+                                    )),
+                                    Cell::from(truncate_with_ellipsis(
+                                        &digest,
+                                        IMAGE_COLUMN_WIDTHS[1] as usize,
+                                    )),
+                                    Cell::from(pushed_at),
+                                    Cell::from(size),
+                                    Cell::from(size_share(image, total_size)),
+                                    Cell::from(image.artifact_kind()),
+                                    Cell::from(vulnerabilities),
+                                ],
+                                &order,
+                            ),
+                            active_column,
+                        ))
                     };
-
-                    Some(
-                        Row::new(vec![
-                            Cell::from(tag),
-                            Cell::from(digest),
-                            Cell::from(pushed_at),
-                            Cell::from(size),
-                            Cell::from(vulnerabilities),
-                        ])
-                        .style(style),
-                    )
+                    Some(row.style(style))
                 } else {
                     None
                 }
@@ -558,18 +2128,359 @@ impl ECRService {
 
         let table = Table::new(
             rows,
-            [
-                Constraint::Length(25), // Image tag
-                Constraint::Length(30), // Digest
-                Constraint::Length(20), // Pushed at
-                Constraint::Length(12), // Size
-                Constraint::Length(20), // Vulnerabilities
-            ],
+            reorder(&IMAGE_COLUMN_WIDTHS, &order)
+                .into_iter()
+                .map(Constraint::Length),
         )
         .header(header)
-        .block(Block::default().borders(Borders::ALL).title(title))
-        .row_highlight_style(Style::default().bg(Color::Blue).fg(Color::White));
+        .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_stateful_widget(
+            table,
+            area,
+            &mut table_state(view_state, view_state.selected_index),
+        );
+    }
+
+    fn render_platforms(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        let filtered_items = self.visible_items(data, view_state);
+        let mut parts = view_state.context.as_deref().unwrap_or_default().split('|');
+        let repo_name = parts.next().unwrap_or("Unknown");
+        let index_digest = parts.nth(1).unwrap_or_default();
+        let short_digest: String = index_digest.chars().take(19).collect();
+
+        let name = format!(
+            "ECR Repositories: {} > {} > Platforms",
+            repo_name, short_digest
+        );
+        let title = if app.loading {
+            format!("{} (Loading...)", name)
+        } else {
+            format!("{} ({})", name, filtered_items.len())
+        };
+
+        let order = column_order(&PLATFORM_COLUMNS, app.config.column_order(ECR_SERVICE_ID));
+        let widths: Vec<Constraint> = reorder(&PLATFORM_COLUMN_WIDTHS, &order)
+            .into_iter()
+            .map(Constraint::Length)
+            .collect();
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(
+                f,
+                area,
+                &app.theme,
+                &title,
+                &reorder(&PLATFORM_COLUMNS, &order),
+                &widths,
+            );
+            return;
+        }
+
+        if filtered_items.is_empty() {
+            let message = if view_state.search_filter.is_empty() {
+                "No platform images found in this index"
+            } else {
+                "No platforms match the current filter"
+            };
+
+            let empty_paragraph = ratatui::widgets::Paragraph::new(message)
+                .block(Block::default().borders(Borders::ALL).title(title))
+                .style(Style::default().fg(app.theme.muted));
+
+            f.render_widget(empty_paragraph, area);
+            return;
+        }
+
+        let header_cells = reorder(
+            &sort_headers(
+                &PLATFORM_COLUMNS,
+                view_state.sort_key,
+                view_state.sort_ascending,
+            ),
+            &order,
+        )
+        .into_iter()
+        .map(|h| Cell::from(h).style(app.theme.header_style()));
+        let header = Row::new(header_cells).height(1).bottom_margin(0);
+
+        let rows: Vec<Row> = filtered_items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let platform = item.as_any().downcast_ref::<ImagePlatform>()?;
+                let cells = platform_cells(platform)
+                    .iter()
+                    .zip(PLATFORM_COLUMN_WIDTHS)
+                    .map(|(cell, width)| Cell::from(truncate_with_ellipsis(cell, width as usize)))
+                    .collect::<Vec<_>>();
+                let active_column =
+                    (i == view_state.selected_index).then_some(view_state.column_cursor);
+                Some(
+                    Row::new(mark_active_cell(reorder(&cells, &order), active_column))
+                        .style(row_style(app, view_state, i)),
+                )
+            })
+            .collect();
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_stateful_widget(
+            table,
+            area,
+            &mut table_state(view_state, view_state.selected_index),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_index_manifest_lists_platforms() {
+        let manifest = r#"{
+            "schemaVersion": 2,
+            "mediaType": "application/vnd.oci.image.index.v1+json",
+            "manifests": [
+                {
+                    "mediaType": "application/vnd.oci.image.manifest.v1+json",
+                    "digest": "sha256:aaa",
+                    "size": 1234,
+                    "platform": { "os": "linux", "architecture": "arm64", "variant": "v8" }
+                },
+                {
+                    "mediaType": "application/vnd.oci.image.manifest.v1+json",
+                    "digest": "sha256:bbb",
+                    "size": 567
+                }
+            ]
+        }"#;
+
+        let platforms = parse_index_manifest(manifest).unwrap();
+        assert_eq!(platforms.len(), 2);
+        assert_eq!(platforms[0].platform(), "linux/arm64/v8");
+        assert_eq!(platforms[0].digest, "sha256:aaa");
+        assert_eq!(platforms[1].platform(), "unknown/unknown");
+        assert!(parse_index_manifest("not json").is_err());
+    }
+
+    #[test]
+    fn console_urls_point_at_the_repository_or_image() {
+        let uri = "123456789012.dkr.ecr.eu-west-1.amazonaws.com/team/api";
+        let account = uri_account(uri).unwrap();
+        assert_eq!(account, "123456789012");
+        assert_eq!(
+            console_url("eu-west-1", account, "team/api", None),
+            "https://eu-west-1.console.aws.amazon.com/ecr/repositories/private/\
+             123456789012/team/api?region=eu-west-1"
+        );
+        assert_eq!(
+            console_url("eu-west-1", account, "team/api", Some("sha256:abc")),
+            "https://eu-west-1.console.aws.amazon.com/ecr/repositories/private/\
+             123456789012/team/api/_/image/sha256:abc/details?region=eu-west-1"
+        );
+    }
+
+    #[test]
+    fn long_digests_are_clipped_to_their_column_with_an_ellipsis() {
+        let digest = format!("sha256:{}", "a".repeat(64));
+        let cell = truncate_with_ellipsis(&digest, IMAGE_COLUMN_WIDTHS[1] as usize);
+        assert_eq!(cell.chars().count(), IMAGE_COLUMN_WIDTHS[1] as usize);
+        assert!(cell.starts_with("sha256:aaa"));
+        assert!(cell.ends_with('…'));
+
+        assert_eq!(
+            truncate_with_ellipsis("latest", IMAGE_COLUMN_WIDTHS[0] as usize),
+            "latest"
+        );
+    }
+
+    #[test]
+    fn field_queries_compare_image_size_and_push_date() {
+        let service = ECRService::new(
+            Client::from_conf(
+                aws_sdk_ecr::Config::builder()
+                    .behavior_version(aws_sdk_ecr::config::BehaviorVersion::latest())
+                    .build(),
+            ),
+            Arc::new(RateLimiter::unlimited()),
+        );
+        let image = |tag: &str, megabytes: i64, pushed: &str| -> Box<dyn ResourceItem> {
+            Box::new(ECRImage {
+                image_tag: Some(tag.to_string()),
+                image_digest: format!("sha256:{}", tag),
+                image_pushed_at: DateTime::parse_from_rfc3339(pushed)
+                    .ok()
+                    .map(|dt| dt.with_timezone(&Utc)),
+                image_size_in_bytes: Some(megabytes * 1_048_576),
+                vulnerability_scan_summary: None,
+                severity_counts: None,
+                scan_status: None,
+                image_manifest_media_type: None,
+                artifact_media_type: None,
+            })
+        };
+        let data = ResourceData::new(vec![
+            image("small", 120, "2023-06-01T00:00:00Z"),
+            image("large", 800, "2024-03-15T12:00:00Z"),
+        ]);
+        let tags = |filter: &str| -> Vec<String> {
+            service
+                .filter_data(&data, filter)
+                .iter()
+                .map(|(_, item)| item.id())
+                .collect()
+        };
+
+        assert_eq!(tags("size>500"), vec!["sha256:large"]);
+        assert_eq!(tags("size<500MB"), vec!["sha256:small"]);
+        assert_eq!(tags("pushed>2024-01-01"), vec!["sha256:large"]);
+        assert_eq!(tags("tag:sma"), vec!["sha256:small"]);
+        assert_eq!(tags("size:800.0"), vec!["sha256:large"]);
+        assert_eq!(tags("pushed:2023-06"), vec!["sha256:small"]);
+        assert!(tags("size>big").is_empty());
+        assert!(service.parse_compare_value("size", "big").is_err());
+        assert!(service.parse_compare_value("pushed", "2024-13-01").is_err());
+        assert!(service.parse_compare_value("tag", "1").is_err());
+    }
+
+    #[test]
+    fn images_sort_by_size_and_keep_undated_ones_last() {
+        let service = ECRService::new(
+            Client::from_conf(
+                aws_sdk_ecr::Config::builder()
+                    .behavior_version(aws_sdk_ecr::config::BehaviorVersion::latest())
+                    .build(),
+            ),
+            Arc::new(RateLimiter::unlimited()),
+        );
+        let image = |tag: &str, megabytes: Option<i64>| -> Box<dyn ResourceItem> {
+            Box::new(ECRImage {
+                image_tag: Some(tag.to_string()),
+                image_digest: format!("sha256:{}", tag),
+                image_pushed_at: None,
+                image_size_in_bytes: megabytes.map(|mb| mb * 1_048_576),
+                vulnerability_scan_summary: None,
+                severity_counts: None,
+                scan_status: None,
+                image_manifest_media_type: None,
+                artifact_media_type: None,
+            })
+        };
+        let data = ResourceData::new(vec![
+            image("mid", Some(300)),
+            image("unknown", None),
+            image("large", Some(800)),
+            image("small", Some(120)),
+        ]);
+        let mut view_state = ViewState::new(ServiceId::new(ECR_SERVICE_ID), ViewType::Detail);
+        let tags = |view_state: &ViewState| -> Vec<String> {
+            service
+                .visible_items(&data, view_state)
+                .iter()
+                .map(|item| item.id())
+                .collect()
+        };
+
+        view_state.sort_key = Some(3);
+        view_state.sort_ascending = false;
+        assert_eq!(
+            tags(&view_state),
+            [
+                "sha256:large",
+                "sha256:mid",
+                "sha256:small",
+                "sha256:unknown"
+            ]
+        );
+        view_state.sort_ascending = true;
+        assert_eq!(
+            tags(&view_state),
+            [
+                "sha256:small",
+                "sha256:mid",
+                "sha256:large",
+                "sha256:unknown"
+            ]
+        );
+    }
+
+    #[test]
+    fn image_details_show_the_full_digest_and_exact_size() {
+        let digest = format!("sha256:{}", "a".repeat(64));
+        let image = ECRImage {
+            image_tag: None,
+            image_digest: digest.clone(),
+            image_pushed_at: None,
+            image_size_in_bytes: Some(52_428_801),
+            vulnerability_scan_summary: None,
+            severity_counts: None,
+            scan_status: None,
+            image_manifest_media_type: None,
+            artifact_media_type: None,
+        };
+
+        let lines = image.detail_lines();
+        let value = |key: &str| {
+            lines
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str())
+        };
+        assert_eq!(value("Digest"), Some(digest.as_str()));
+        assert_eq!(value("Size (bytes)"), Some("52428801"));
+        assert_eq!(value("Tag"), Some("-"));
+    }
+
+    #[test]
+    fn delete_confirms_marked_images_by_repository_name() {
+        let service = ECRService::new(
+            Client::from_conf(
+                aws_sdk_ecr::Config::builder()
+                    .behavior_version(aws_sdk_ecr::config::BehaviorVersion::latest())
+                    .build(),
+            ),
+            Arc::new(RateLimiter::unlimited()),
+        );
+        let image = |tag: &str| -> Box<dyn ResourceItem> {
+            Box::new(ECRImage {
+                image_tag: Some(tag.to_string()),
+                image_digest: format!("sha256:{}", tag),
+                image_pushed_at: None,
+                image_size_in_bytes: None,
+                vulnerability_scan_summary: None,
+                severity_counts: None,
+                scan_status: None,
+                image_manifest_media_type: None,
+                artifact_media_type: None,
+            })
+        };
+        let data = ResourceData::new(vec![image("a"), image("b"), image("c")]);
+        let mut view_state = ViewState::new(ServiceId::new(ECR_SERVICE_ID), ViewType::Detail);
+        view_state.context = Some("app|123.dkr.ecr.us-east-1.amazonaws.com/app".to_string());
+
+        let single = service.confirmation("delete", &view_state, &data).unwrap();
+        assert_eq!(single.item_count, 1);
+        assert_eq!(single.target, "app:a");
 
-        f.render_widget(table, area);
+        view_state.selected_items.extend([0, 2]);
+        let batch = service.confirmation("delete", &view_state, &data).unwrap();
+        assert_eq!(batch.item_count, 2);
+        assert_eq!(batch.target, "app");
+        assert!(
+            service
+                .confirmation("prune-untagged", &view_state, &data)
+                .is_none()
+        );
     }
 }
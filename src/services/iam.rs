@@ -0,0 +1,692 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use aws_sdk_iam::{
+    Client,
+    primitives::DateTime as SmithyDateTime,
+    types::{AttachedPolicy, Role, User},
+};
+use chrono::{DateTime, Utc};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    widgets::{Block, Borders, Cell, Row, Table},
+};
+use serde::{Deserialize, Serialize};
+use std::{any::Any, sync::Arc};
+
+use super::traits::{
+    AwsService, ResourceData, ResourceItem, ServiceId, ServiceMetadata, ViewState, ViewType,
+    highlight_pattern,
+};
+use crate::app::App;
+use crate::ui::{
+    content::{header_row, list_title, render_empty, row_style, table_state},
+    skeleton::render_skeleton,
+};
+use crate::utils::{rate_limit::RateLimiter, text::highlight_match};
+
+const IAM_SERVICE_ID: &str = "iam";
+
+/// Custom view listing roles instead of users, opened with `:iam roles`
+const ROLES_VIEW: &str = "roles";
+
+/// AWS managed policies live under this account-less ARN prefix
+const AWS_MANAGED_POLICY_PREFIX: &str = "arn:aws:iam::aws:policy/";
+
+const USER_COLUMNS: [&str; 4] = ["NAME", "CREATED", "PASSWORD LAST USED", "MFA"];
+
+const USER_WIDTHS: [Constraint; 4] = [
+    Constraint::Min(30),    // Name
+    Constraint::Length(20), // Created
+    Constraint::Length(20), // Password last used
+    Constraint::Length(8),  // MFA
+];
+
+const ROLE_COLUMNS: [&str; 3] = ["NAME", "CREATED", "DESCRIPTION"];
+
+const ROLE_WIDTHS: [Constraint; 3] = [
+    Constraint::Min(30),    // Name
+    Constraint::Length(20), // Created
+    Constraint::Min(30),    // Description
+];
+
+const POLICY_COLUMNS: [&str; 3] = ["NAME", "TYPE", "ARN"];
+
+const POLICY_WIDTHS: [Constraint; 3] = [
+    Constraint::Min(30),    // Name
+    Constraint::Length(16), // Type
+    Constraint::Min(40),    // ARN
+];
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IAMUser {
+    pub name: String,
+    pub arn: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub password_last_used: Option<DateTime<Utc>>, // None if the user never signed in
+    pub mfa_enabled: Option<bool>,                 // None if the MFA lookup failed
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IAMRole {
+    pub name: String,
+    pub arn: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub description: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IAMPolicy {
+    pub name: String,
+    pub arn: String,
+}
+
+impl ResourceItem for IAMUser {
+    fn id(&self) -> String {
+        self.name.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl ResourceItem for IAMRole {
+    fn id(&self) -> String {
+        self.name.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl ResourceItem for IAMPolicy {
+    fn id(&self) -> String {
+        self.arn.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+fn to_utc(dt: &SmithyDateTime) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(dt.secs(), dt.subsec_nanos())
+}
+
+fn format_date(dt: Option<DateTime<Utc>>) -> String {
+    dt.map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+impl From<&Role> for IAMRole {
+    fn from(role: &Role) -> Self {
+        Self {
+            name: role.role_name().to_string(),
+            arn: role.arn().to_string(),
+            created_at: to_utc(role.create_date()),
+            description: role
+                .description()
+                .filter(|description| !description.is_empty())
+                .map(str::to_string),
+        }
+    }
+}
+
+impl From<&AttachedPolicy> for IAMPolicy {
+    fn from(policy: &AttachedPolicy) -> Self {
+        Self {
+            name: policy.policy_name().unwrap_or_default().to_string(),
+            arn: policy.policy_arn().unwrap_or_default().to_string(),
+        }
+    }
+}
+
+impl IAMUser {
+    fn from_user(user: &User, mfa_enabled: Option<bool>) -> Self {
+        Self {
+            name: user.user_name().to_string(),
+            arn: user.arn().to_string(),
+            created_at: to_utc(user.create_date()),
+            password_last_used: user.password_last_used().and_then(to_utc),
+            mfa_enabled,
+        }
+    }
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            format_date(self.created_at),
+            self.password_last_used
+                .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+                .unwrap_or_else(|| "Never".to_string()),
+            match self.mfa_enabled {
+                Some(true) => "Yes".to_string(),
+                Some(false) => "No".to_string(),
+                None => "-".to_string(),
+            },
+        ]
+    }
+}
+
+impl IAMRole {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            format_date(self.created_at),
+            self.description.clone().unwrap_or_else(|| "-".to_string()),
+        ]
+    }
+}
+
+impl IAMPolicy {
+    fn kind(&self) -> &'static str {
+        if self.arn.starts_with(AWS_MANAGED_POLICY_PREFIX) {
+            "AWS managed"
+        } else {
+            "Customer managed"
+        }
+    }
+
+    fn cells(&self) -> Vec<String> {
+        vec![self.name.clone(), self.kind().to_string(), self.arn.clone()]
+    }
+}
+
+/// Whose policies a detail view lists, from its "name|user" or "name|role" context
+fn principal_context(view_state: &ViewState) -> Option<(&str, &str)> {
+    view_state.context.as_deref()?.split_once('|')
+}
+
+/// Link path for a user's or role's policies, e.g. `roles/deploy/policies`
+fn policies_link(name: &str, kind: &str) -> String {
+    format!("{}s/{}/policies", kind, name)
+}
+
+/// The (name, kind) a `policies_link` path points at
+fn parse_policies_link(path: &str) -> Option<(&str, &str)> {
+    let (kinds, name) = path.strip_suffix("/policies")?.split_once('/')?;
+    match kinds {
+        "users" => Some((name, "user")),
+        "roles" => Some((name, "role")),
+        _ => None,
+    }
+}
+
+pub struct IAMService {
+    client: Client,
+    rate_limiter: Arc<RateLimiter>,
+}
+
+impl IAMService {
+    pub fn new(client: Client, rate_limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            client,
+            rate_limiter,
+        }
+    }
+
+    /// Users with whether each has an MFA device, which takes a call per user
+    pub async fn list_users(&self) -> Result<Vec<IAMUser>> {
+        let mut users = Vec::new();
+        let mut marker = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self.client.list_users().set_marker(marker).send().await?;
+            users.extend(resp.users().iter().cloned());
+
+            marker = resp.marker().map(str::to_string);
+            if !resp.is_truncated() || marker.is_none() {
+                break;
+            }
+        }
+
+        let mut items = Vec::with_capacity(users.len());
+        for user in &users {
+            self.rate_limiter.acquire().await;
+            let mfa_enabled = self
+                .client
+                .list_mfa_devices()
+                .user_name(user.user_name())
+                .send()
+                .await
+                .ok()
+                .map(|resp| !resp.mfa_devices().is_empty());
+            items.push(IAMUser::from_user(user, mfa_enabled));
+        }
+
+        items.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(items)
+    }
+
+    pub async fn list_roles(&self) -> Result<Vec<IAMRole>> {
+        let mut roles = Vec::new();
+        let mut marker = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self.client.list_roles().set_marker(marker).send().await?;
+            roles.extend(resp.roles().iter().map(IAMRole::from));
+
+            marker = resp.marker().map(str::to_string);
+            if !resp.is_truncated() || marker.is_none() {
+                break;
+            }
+        }
+
+        roles.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(roles)
+    }
+
+    /// Managed policies attached to a user or role; inline policies aren't listed
+    pub async fn list_attached_policies(&self, name: &str, kind: &str) -> Result<Vec<IAMPolicy>> {
+        let mut policies = Vec::new();
+        let mut marker = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let (attached, next_marker) = if kind == "role" {
+                let resp = self
+                    .client
+                    .list_attached_role_policies()
+                    .role_name(name)
+                    .set_marker(marker)
+                    .send()
+                    .await?;
+                (
+                    resp.attached_policies().to_vec(),
+                    resp.marker()
+                        .filter(|_| resp.is_truncated())
+                        .map(str::to_string),
+                )
+            } else {
+                let resp = self
+                    .client
+                    .list_attached_user_policies()
+                    .user_name(name)
+                    .set_marker(marker)
+                    .send()
+                    .await?;
+                (
+                    resp.attached_policies().to_vec(),
+                    resp.marker()
+                        .filter(|_| resp.is_truncated())
+                        .map(str::to_string),
+                )
+            };
+            policies.extend(attached.iter().map(IAMPolicy::from));
+
+            marker = next_marker;
+            if marker.is_none() {
+                break;
+            }
+        }
+
+        policies.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(policies)
+    }
+
+    fn render_table(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        let filtered_items = self.visible_items(data, view_state);
+        // IAM is global, so the header's region has no bearing on these lists
+        let (name, columns, widths, noun) = match &view_state.view_type {
+            ViewType::List => (
+                "IAM Users - Global".to_string(),
+                &USER_COLUMNS[..],
+                &USER_WIDTHS[..],
+                "users",
+            ),
+            ViewType::Custom(_) => (
+                "IAM Roles - Global".to_string(),
+                &ROLE_COLUMNS[..],
+                &ROLE_WIDTHS[..],
+                "roles",
+            ),
+            ViewType::Detail => {
+                let (principal, kind) = principal_context(view_state).unwrap_or(("Unknown", ""));
+                let parent = if kind == "role" { "Roles" } else { "Users" };
+                (
+                    format!("IAM {}: {} > Attached Policies", parent, principal),
+                    &POLICY_COLUMNS[..],
+                    &POLICY_WIDTHS[..],
+                    "policies",
+                )
+            }
+        };
+        let title = list_title(&name, app, view_state, filtered_items.len(), data);
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(f, area, &app.theme, &title, columns, widths);
+            return;
+        }
+
+        if filtered_items.is_empty() {
+            let message = if !view_state.search_filter.is_empty() {
+                format!("No {} match the current filter", noun)
+            } else {
+                format!("No {} found", noun)
+            };
+            render_empty(f, area, &app.theme, &title, &message);
+            return;
+        }
+
+        let rows: Vec<Row> = filtered_items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let cells = if let Some(user) = item.as_any().downcast_ref::<IAMUser>() {
+                    user.cells()
+                } else if let Some(role) = item.as_any().downcast_ref::<IAMRole>() {
+                    role.cells()
+                } else {
+                    item.as_any().downcast_ref::<IAMPolicy>()?.cells()
+                };
+                let mut cells = cells.into_iter();
+                let name = cells.next().unwrap_or_default();
+                Some(
+                    Row::new(
+                        std::iter::once(Cell::from(highlight_match(
+                            &name,
+                            highlight_pattern(&view_state.search_filter, "name"),
+                        )))
+                        .chain(cells.map(Cell::from)),
+                    )
+                    .style(row_style(app, view_state, i)),
+                )
+            })
+            .collect();
+
+        let table = Table::new(rows, widths.to_vec())
+            .header(header_row(&app.theme, columns))
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_stateful_widget(
+            table,
+            area,
+            &mut table_state(view_state, view_state.selected_index),
+        );
+    }
+
+    fn selected_item<'a, T: 'static>(
+        &self,
+        view_state: &ViewState,
+        data: &'a ResourceData,
+    ) -> Option<&'a T> {
+        self.visible_items(data, view_state)
+            .get(view_state.selected_index)?
+            .as_any()
+            .downcast_ref::<T>()
+    }
+}
+
+#[async_trait]
+impl AwsService for IAMService {
+    fn metadata(&self) -> ServiceMetadata {
+        ServiceMetadata {
+            id: IAM_SERVICE_ID.to_string(),
+            name: "IAM".to_string(),
+            description: "Users, roles, and their attached policies".to_string(),
+            command: "iam".to_string(),
+            aliases: Vec::new(),
+            short_label: Some("IAM".to_string()),
+            icon: None,
+        }
+    }
+
+    async fn load_data(&self, view_state: &ViewState) -> Result<ResourceData> {
+        let items: Vec<Box<dyn ResourceItem>> = match &view_state.view_type {
+            ViewType::List => self
+                .list_users()
+                .await?
+                .into_iter()
+                .map(|user| Box::new(user) as Box<dyn ResourceItem>)
+                .collect(),
+            ViewType::Custom(name) if name == ROLES_VIEW => self
+                .list_roles()
+                .await?
+                .into_iter()
+                .map(|role| Box::new(role) as Box<dyn ResourceItem>)
+                .collect(),
+            ViewType::Detail => match principal_context(view_state) {
+                Some((name, kind)) => self
+                    .list_attached_policies(name, kind)
+                    .await?
+                    .into_iter()
+                    .map(|policy| Box::new(policy) as Box<dyn ResourceItem>)
+                    .collect(),
+                None => Vec::new(),
+            },
+            ViewType::Custom(_) => Vec::new(),
+        };
+        Ok(ResourceData::new(items))
+    }
+
+    fn render(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        match &view_state.view_type {
+            ViewType::Custom(name) if name != ROLES_VIEW => {}
+            _ => self.render_table(f, area, app, view_state, data),
+        }
+    }
+
+    fn handle_enter(&self, view_state: &mut ViewState, data: &ResourceData) -> Option<ViewState> {
+        let context = match &view_state.view_type {
+            ViewType::List => {
+                let user = self.selected_item::<IAMUser>(view_state, data)?;
+                format!("{}|user", user.name)
+            }
+            ViewType::Custom(name) if name == ROLES_VIEW => {
+                let role = self.selected_item::<IAMRole>(view_state, data)?;
+                format!("{}|role", role.name)
+            }
+            _ => return None,
+        };
+        let mut new_view = ViewState::new(view_state.service_id.clone(), ViewType::Detail);
+        new_view.context = Some(context);
+        Some(new_view)
+    }
+
+    fn get_copy_content(
+        &self,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) -> Option<(String, String)> {
+        let item = self
+            .visible_items(data, view_state)
+            .get(view_state.selected_index)?
+            .as_any();
+        if let Some(user) = item.downcast_ref::<IAMUser>() {
+            Some((user.arn.clone(), user.name.clone()))
+        } else if let Some(role) = item.downcast_ref::<IAMRole>() {
+            Some((role.arn.clone(), role.name.clone()))
+        } else {
+            let policy = item.downcast_ref::<IAMPolicy>()?;
+            Some((policy.arn.clone(), policy.name.clone()))
+        }
+    }
+
+    fn matches_filter(&self, item: &dyn ResourceItem, filter: &str) -> bool {
+        let filter = filter.to_lowercase();
+        if let Some(user) = item.as_any().downcast_ref::<IAMUser>() {
+            user.name.to_lowercase().contains(&filter)
+        } else if let Some(role) = item.as_any().downcast_ref::<IAMRole>() {
+            role.name.to_lowercase().contains(&filter)
+        } else if let Some(policy) = item.as_any().downcast_ref::<IAMPolicy>() {
+            policy.name.to_lowercase().contains(&filter)
+        } else {
+            false
+        }
+    }
+
+    fn filter_columns(&self, view_state: &ViewState) -> Vec<&'static str> {
+        match &view_state.view_type {
+            ViewType::List => vec!["name", "mfa"],
+            ViewType::Custom(name) if name == ROLES_VIEW => vec!["name", "description"],
+            ViewType::Detail => vec!["name", "type"],
+            ViewType::Custom(_) => Vec::new(),
+        }
+    }
+
+    fn matches_column(&self, item: &dyn ResourceItem, column: &str, value: &str) -> bool {
+        let value = value.to_lowercase();
+        let field = if let Some(user) = item.as_any().downcast_ref::<IAMUser>() {
+            match column {
+                "name" => Some(user.name.clone()),
+                "mfa" => user.cells().pop(),
+                _ => None,
+            }
+        } else if let Some(role) = item.as_any().downcast_ref::<IAMRole>() {
+            match column {
+                "name" => Some(role.name.clone()),
+                "description" => role.description.clone(),
+                _ => None,
+            }
+        } else if let Some(policy) = item.as_any().downcast_ref::<IAMPolicy>() {
+            match column {
+                "name" => Some(policy.name.clone()),
+                "type" => Some(policy.kind().to_string()),
+                _ => None,
+            }
+        } else {
+            None
+        };
+        field.is_some_and(|field| field.to_lowercase().contains(&value))
+    }
+
+    fn export_columns(&self, view_state: &ViewState) -> Vec<String> {
+        let columns: &[&str] = match &view_state.view_type {
+            ViewType::List => &USER_COLUMNS,
+            ViewType::Custom(name) if name == ROLES_VIEW => &ROLE_COLUMNS,
+            ViewType::Detail => &POLICY_COLUMNS,
+            ViewType::Custom(_) => &[],
+        };
+        columns.iter().map(|c| c.to_string()).collect()
+    }
+
+    fn export_rows(&self, view_state: &ViewState, data: &ResourceData) -> Vec<Vec<String>> {
+        self.visible_items(data, view_state)
+            .iter()
+            .filter_map(|item| {
+                let item = item.as_any();
+                if let Some(user) = item.downcast_ref::<IAMUser>() {
+                    Some(user.cells())
+                } else if let Some(role) = item.downcast_ref::<IAMRole>() {
+                    Some(role.cells())
+                } else {
+                    item.downcast_ref::<IAMPolicy>().map(IAMPolicy::cells)
+                }
+            })
+            .collect()
+    }
+
+    fn deep_link_path(&self, view_state: &ViewState) -> Option<String> {
+        match &view_state.view_type {
+            ViewType::List => Some(String::new()),
+            ViewType::Custom(name) if name == ROLES_VIEW => Some(ROLES_VIEW.to_string()),
+            ViewType::Detail => {
+                let (name, kind) = principal_context(view_state)?;
+                Some(policies_link(name, kind))
+            }
+            ViewType::Custom(_) => None,
+        }
+    }
+
+    fn view_from_deep_link(
+        &self,
+        service_id: &ServiceId,
+        path: &str,
+        _cached: Option<&ResourceData>,
+    ) -> Option<ViewState> {
+        match path {
+            "" | "users" => Some(ViewState::new(service_id.clone(), ViewType::List)),
+            ROLES_VIEW => Some(ViewState::new(
+                service_id.clone(),
+                ViewType::Custom(ROLES_VIEW.to_string()),
+            )),
+            _ => {
+                let (name, kind) = parse_policies_link(path)?;
+                let mut view_state = ViewState::new(service_id.clone(), ViewType::Detail);
+                view_state.context = Some(format!("{}|{}", name, kind));
+                Some(view_state)
+            }
+        }
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.rate_limiter.acquire().await;
+        self.client.list_users().max_items(1).send().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_links_round_trip() {
+        assert_eq!(policies_link("deploy", "role"), "roles/deploy/policies");
+        assert_eq!(
+            parse_policies_link("roles/deploy/policies"),
+            Some(("deploy", "role"))
+        );
+        assert_eq!(
+            parse_policies_link("users/alice/policies"),
+            Some(("alice", "user"))
+        );
+        assert_eq!(parse_policies_link("groups/admins/policies"), None);
+        assert_eq!(parse_policies_link("users/alice"), None);
+    }
+
+    #[test]
+    fn aws_managed_policies_are_told_apart() {
+        let policy = |arn: &str| IAMPolicy {
+            name: "ReadOnlyAccess".to_string(),
+            arn: arn.to_string(),
+        };
+        assert_eq!(
+            policy("arn:aws:iam::aws:policy/ReadOnlyAccess").kind(),
+            "AWS managed"
+        );
+        assert_eq!(
+            policy("arn:aws:iam::123456789012:policy/ReadOnlyAccess").kind(),
+            "Customer managed"
+        );
+    }
+}
@@ -0,0 +1,634 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use aws_sdk_lambda::{Client, types::FunctionConfiguration};
+use chrono::{DateTime, Utc};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    widgets::{Block, Borders, Cell, Row, Table},
+};
+use serde::{Deserialize, Serialize};
+use std::{any::Any, collections::HashMap, sync::Arc};
+
+use super::traits::{
+    AwsService, ResourceData, ResourceItem, ServiceId, ServiceMetadata, ViewState, ViewType,
+    highlight_pattern,
+};
+use crate::app::App;
+use crate::ui::{
+    content::{header_row, list_title, render_empty, row_style, table_state},
+    skeleton::render_skeleton,
+};
+use crate::utils::{rate_limit::RateLimiter, text::highlight_match};
+
+const LAMBDA_SERVICE_ID: &str = "lambda";
+
+/// Version name Lambda gives the unpublished, editable code
+const LATEST_VERSION: &str = "$LATEST";
+
+const FUNCTION_COLUMNS: [&str; 5] = ["NAME", "RUNTIME", "MEMORY", "TIMEOUT", "LAST MODIFIED"];
+
+const FUNCTION_WIDTHS: [Constraint; 5] = [
+    Constraint::Min(30),    // Name
+    Constraint::Length(14), // Runtime
+    Constraint::Length(10), // Memory
+    Constraint::Length(9),  // Timeout
+    Constraint::Length(20), // Last modified
+];
+
+const VERSION_COLUMNS: [&str; 4] = ["VERSION", "ALIASES", "DESCRIPTION", "LAST MODIFIED"];
+
+const VERSION_WIDTHS: [Constraint; 4] = [
+    Constraint::Length(10), // Version
+    Constraint::Length(24), // Aliases
+    Constraint::Min(30),    // Description
+    Constraint::Length(20), // Last modified
+];
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LambdaFunction {
+    pub function_name: String,
+    pub function_arn: String,
+    pub runtime: Option<String>, // None for container image functions
+    pub memory_size_mb: Option<i32>,
+    pub timeout_seconds: Option<i32>,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LambdaVersion {
+    pub version: String,
+    pub function_arn: String, // Qualified with the version
+    pub aliases: Vec<String>, // Aliases pointing at this version
+    pub description: Option<String>,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+impl ResourceItem for LambdaFunction {
+    fn id(&self) -> String {
+        self.function_name.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl ResourceItem for LambdaVersion {
+    fn id(&self) -> String {
+        self.version.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+/// Lambda reports modification times as strings like `2024-05-01T12:34:56.789+0000`
+fn parse_last_modified(value: Option<&str>) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_str(value?, "%Y-%m-%dT%H:%M:%S%.f%z")
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+fn format_date(dt: Option<DateTime<Utc>>) -> String {
+    dt.map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+impl From<&FunctionConfiguration> for LambdaFunction {
+    fn from(function: &FunctionConfiguration) -> Self {
+        Self {
+            function_name: function.function_name().unwrap_or_default().to_string(),
+            function_arn: function.function_arn().unwrap_or_default().to_string(),
+            runtime: function
+                .runtime()
+                .map(|runtime| runtime.as_str().to_string()),
+            memory_size_mb: function.memory_size(),
+            timeout_seconds: function.timeout(),
+            last_modified: parse_last_modified(function.last_modified()),
+        }
+    }
+}
+
+impl LambdaFunction {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.function_name.clone(),
+            self.runtime.clone().unwrap_or_else(|| "-".to_string()),
+            self.memory_size_mb
+                .map(|memory| format!("{} MB", memory))
+                .unwrap_or_else(|| "-".to_string()),
+            self.timeout_seconds
+                .map(|timeout| format!("{}s", timeout))
+                .unwrap_or_else(|| "-".to_string()),
+            format_date(self.last_modified),
+        ]
+    }
+}
+
+impl LambdaVersion {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.version.clone(),
+            if self.aliases.is_empty() {
+                "-".to_string()
+            } else {
+                self.aliases.join(", ")
+            },
+            self.description
+                .clone()
+                .filter(|description| !description.is_empty())
+                .unwrap_or_else(|| "-".to_string()),
+            format_date(self.last_modified),
+        ]
+    }
+}
+
+/// `$LATEST` first, then published versions newest first
+fn version_order(version: &str) -> (bool, std::cmp::Reverse<u64>) {
+    (
+        version != LATEST_VERSION,
+        std::cmp::Reverse(version.parse().unwrap_or(0)),
+    )
+}
+
+pub struct LambdaService {
+    client: Client,
+    rate_limiter: Arc<RateLimiter>,
+}
+
+impl LambdaService {
+    pub fn new(client: Client, rate_limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            client,
+            rate_limiter,
+        }
+    }
+
+    pub async fn list_functions(&self) -> Result<Vec<LambdaFunction>> {
+        let mut functions = Vec::new();
+        let mut marker = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .list_functions()
+                .set_marker(marker)
+                .send()
+                .await?;
+            functions.extend(resp.functions().iter().map(LambdaFunction::from));
+
+            marker = resp.next_marker().map(str::to_string);
+            if marker.is_none() {
+                break;
+            }
+        }
+
+        functions.sort_by(|a, b| a.function_name.cmp(&b.function_name));
+        Ok(functions)
+    }
+
+    /// Versions of a function with the aliases that point at each
+    pub async fn list_versions(&self, function_name: &str) -> Result<Vec<LambdaVersion>> {
+        let mut aliases: HashMap<String, Vec<String>> = HashMap::new();
+        let mut marker = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .list_aliases()
+                .function_name(function_name)
+                .set_marker(marker)
+                .send()
+                .await?;
+            for alias in resp.aliases() {
+                if let (Some(name), Some(version)) = (alias.name(), alias.function_version()) {
+                    aliases
+                        .entry(version.to_string())
+                        .or_default()
+                        .push(name.to_string());
+                }
+            }
+
+            marker = resp.next_marker().map(str::to_string);
+            if marker.is_none() {
+                break;
+            }
+        }
+
+        let mut versions = Vec::new();
+        let mut marker = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .list_versions_by_function()
+                .function_name(function_name)
+                .set_marker(marker)
+                .send()
+                .await?;
+            versions.extend(resp.versions().iter().map(|version| {
+                let number = version.version().unwrap_or_default().to_string();
+                let mut version_aliases = aliases.remove(&number).unwrap_or_default();
+                version_aliases.sort();
+                LambdaVersion {
+                    function_arn: version.function_arn().unwrap_or_default().to_string(),
+                    aliases: version_aliases,
+                    description: version.description().map(str::to_string),
+                    last_modified: parse_last_modified(version.last_modified()),
+                    version: number,
+                }
+            }));
+
+            marker = resp.next_marker().map(str::to_string);
+            if marker.is_none() {
+                break;
+            }
+        }
+
+        versions.sort_by_key(|version| version_order(&version.version));
+        Ok(versions)
+    }
+
+    fn render_functions(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        let filtered_items = self.visible_items(data, view_state);
+        let title = list_title(
+            "Lambda Functions",
+            app,
+            view_state,
+            filtered_items.len(),
+            data,
+        );
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(
+                f,
+                area,
+                &app.theme,
+                &title,
+                &FUNCTION_COLUMNS,
+                &FUNCTION_WIDTHS,
+            );
+            return;
+        }
+
+        if filtered_items.is_empty() {
+            let message = if !view_state.search_filter.is_empty() {
+                "No functions match the current filter"
+            } else {
+                "No Lambda functions found"
+            };
+            render_empty(f, area, &app.theme, &title, message);
+            return;
+        }
+
+        let rows: Vec<Row> = filtered_items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let function = item.as_any().downcast_ref::<LambdaFunction>()?;
+                let mut cells = function.cells().into_iter();
+                let name = cells.next().unwrap_or_default();
+                Some(
+                    Row::new(
+                        std::iter::once(Cell::from(highlight_match(
+                            &name,
+                            highlight_pattern(&view_state.search_filter, "name"),
+                        )))
+                        .chain(cells.map(Cell::from)),
+                    )
+                    .style(row_style(app, view_state, i)),
+                )
+            })
+            .collect();
+
+        let table = Table::new(rows, FUNCTION_WIDTHS)
+            .header(header_row(&app.theme, &FUNCTION_COLUMNS))
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_stateful_widget(
+            table,
+            area,
+            &mut table_state(view_state, view_state.selected_index),
+        );
+    }
+
+    fn render_versions(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        let filtered_items = self.visible_items(data, view_state);
+        let function = function_context(view_state).unwrap_or("Unknown");
+        let title = list_title(
+            &format!("Lambda Functions: {} > Versions", function),
+            app,
+            view_state,
+            filtered_items.len(),
+            data,
+        );
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(
+                f,
+                area,
+                &app.theme,
+                &title,
+                &VERSION_COLUMNS,
+                &VERSION_WIDTHS,
+            );
+            return;
+        }
+
+        if filtered_items.is_empty() {
+            let message = if !view_state.search_filter.is_empty() {
+                "No versions match the current filter"
+            } else {
+                "No versions found"
+            };
+            render_empty(f, area, &app.theme, &title, message);
+            return;
+        }
+
+        let rows: Vec<Row> = filtered_items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let version = item.as_any().downcast_ref::<LambdaVersion>()?;
+                Some(
+                    Row::new(version.cells().into_iter().map(Cell::from))
+                        .style(row_style(app, view_state, i)),
+                )
+            })
+            .collect();
+
+        let table = Table::new(rows, VERSION_WIDTHS)
+            .header(header_row(&app.theme, &VERSION_COLUMNS))
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_stateful_widget(
+            table,
+            area,
+            &mut table_state(view_state, view_state.selected_index),
+        );
+    }
+
+    fn selected_item<'a, T: 'static>(
+        &self,
+        view_state: &ViewState,
+        data: &'a ResourceData,
+    ) -> Option<&'a T> {
+        self.visible_items(data, view_state)
+            .get(view_state.selected_index)?
+            .as_any()
+            .downcast_ref::<T>()
+    }
+}
+
+/// Function name from a "name|arn" view context
+fn function_context(view_state: &ViewState) -> Option<&str> {
+    view_state.context.as_deref()?.split('|').next()
+}
+
+#[async_trait]
+impl AwsService for LambdaService {
+    fn metadata(&self) -> ServiceMetadata {
+        ServiceMetadata {
+            id: LAMBDA_SERVICE_ID.to_string(),
+            name: "Lambda".to_string(),
+            description: "Serverless functions and their versions".to_string(),
+            command: "lambda".to_string(),
+            aliases: Vec::new(),
+            short_label: Some("Lambda".to_string()),
+            icon: None,
+        }
+    }
+
+    async fn load_data(&self, view_state: &ViewState) -> Result<ResourceData> {
+        let items: Vec<Box<dyn ResourceItem>> = match view_state.view_type {
+            ViewType::List => self
+                .list_functions()
+                .await?
+                .into_iter()
+                .map(|function| Box::new(function) as Box<dyn ResourceItem>)
+                .collect(),
+            ViewType::Detail => match function_context(view_state) {
+                Some(function_name) => self
+                    .list_versions(function_name)
+                    .await?
+                    .into_iter()
+                    .map(|version| Box::new(version) as Box<dyn ResourceItem>)
+                    .collect(),
+                None => Vec::new(),
+            },
+            ViewType::Custom(_) => Vec::new(),
+        };
+        Ok(ResourceData::new(items))
+    }
+
+    fn render(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        match view_state.view_type {
+            ViewType::List => self.render_functions(f, area, app, view_state, data),
+            ViewType::Detail => self.render_versions(f, area, app, view_state, data),
+            ViewType::Custom(_) => {}
+        }
+    }
+
+    fn handle_enter(&self, view_state: &mut ViewState, data: &ResourceData) -> Option<ViewState> {
+        if view_state.view_type != ViewType::List {
+            return None;
+        }
+        let function = self.selected_item::<LambdaFunction>(view_state, data)?;
+        let mut new_view = ViewState::new(view_state.service_id.clone(), ViewType::Detail);
+        new_view.context = Some(format!(
+            "{}|{}",
+            function.function_name, function.function_arn
+        ));
+        Some(new_view)
+    }
+
+    fn get_copy_content(
+        &self,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) -> Option<(String, String)> {
+        match view_state.view_type {
+            ViewType::List => {
+                let function = self.selected_item::<LambdaFunction>(view_state, data)?;
+                Some((
+                    function.function_arn.clone(),
+                    function.function_name.clone(),
+                ))
+            }
+            ViewType::Detail => {
+                let version = self.selected_item::<LambdaVersion>(view_state, data)?;
+                let function = function_context(view_state)?;
+                Some((
+                    version.function_arn.clone(),
+                    format!("{}:{}", function, version.version),
+                ))
+            }
+            ViewType::Custom(_) => None,
+        }
+    }
+
+    fn matches_filter(&self, item: &dyn ResourceItem, filter: &str) -> bool {
+        let filter = filter.to_lowercase();
+        if let Some(function) = item.as_any().downcast_ref::<LambdaFunction>() {
+            function.function_name.to_lowercase().contains(&filter)
+        } else if let Some(version) = item.as_any().downcast_ref::<LambdaVersion>() {
+            version.version.to_lowercase().contains(&filter)
+                || version
+                    .aliases
+                    .iter()
+                    .any(|alias| alias.to_lowercase().contains(&filter))
+        } else {
+            false
+        }
+    }
+
+    fn filter_columns(&self, view_state: &ViewState) -> Vec<&'static str> {
+        match view_state.view_type {
+            ViewType::List => vec!["name", "runtime"],
+            ViewType::Detail => vec!["version", "alias"],
+            ViewType::Custom(_) => Vec::new(),
+        }
+    }
+
+    fn matches_column(&self, item: &dyn ResourceItem, column: &str, value: &str) -> bool {
+        let value = value.to_lowercase();
+        if let Some(function) = item.as_any().downcast_ref::<LambdaFunction>() {
+            let field = match column {
+                "name" => Some(function.function_name.as_str()),
+                "runtime" => function.runtime.as_deref(),
+                _ => None,
+            };
+            field.is_some_and(|field| field.to_lowercase().contains(&value))
+        } else if let Some(version) = item.as_any().downcast_ref::<LambdaVersion>() {
+            match column {
+                "version" => version.version.to_lowercase().contains(&value),
+                "alias" => version
+                    .aliases
+                    .iter()
+                    .any(|alias| alias.to_lowercase().contains(&value)),
+                _ => false,
+            }
+        } else {
+            false
+        }
+    }
+
+    fn export_columns(&self, view_state: &ViewState) -> Vec<String> {
+        match view_state.view_type {
+            ViewType::List => FUNCTION_COLUMNS.iter().map(|c| c.to_string()).collect(),
+            ViewType::Detail => VERSION_COLUMNS.iter().map(|c| c.to_string()).collect(),
+            ViewType::Custom(_) => Vec::new(),
+        }
+    }
+
+    fn export_rows(&self, view_state: &ViewState, data: &ResourceData) -> Vec<Vec<String>> {
+        self.visible_items(data, view_state)
+            .iter()
+            .filter_map(|item| {
+                if let Some(function) = item.as_any().downcast_ref::<LambdaFunction>() {
+                    Some(function.cells())
+                } else {
+                    item.as_any()
+                        .downcast_ref::<LambdaVersion>()
+                        .map(LambdaVersion::cells)
+                }
+            })
+            .collect()
+    }
+
+    fn deep_link_path(&self, view_state: &ViewState) -> Option<String> {
+        match view_state.view_type {
+            ViewType::List => Some(String::new()),
+            ViewType::Detail => Some(format!("{}/versions", function_context(view_state)?)),
+            ViewType::Custom(_) => None,
+        }
+    }
+
+    fn view_from_deep_link(
+        &self,
+        service_id: &ServiceId,
+        path: &str,
+        cached: Option<&ResourceData>,
+    ) -> Option<ViewState> {
+        if path.is_empty() {
+            return Some(ViewState::new(service_id.clone(), ViewType::List));
+        }
+
+        let function_name = path.strip_suffix("/versions")?;
+        let function_arn = cached
+            .into_iter()
+            .flat_map(|data| &data.items)
+            .filter_map(|item| item.as_any().downcast_ref::<LambdaFunction>())
+            .find(|function| function.function_name == function_name)
+            .map(|function| function.function_arn.clone())
+            .unwrap_or_default();
+
+        let mut view_state = ViewState::new(service_id.clone(), ViewType::Detail);
+        view_state.context = Some(format!("{}|{}", function_name, function_arn));
+        Some(view_state)
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.rate_limiter.acquire().await;
+        self.client.list_functions().max_items(1).send().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_lambda_timestamps() {
+        let parsed = parse_last_modified(Some("2024-05-01T12:34:56.789+0000")).unwrap();
+        assert_eq!(parsed.to_rfc3339(), "2024-05-01T12:34:56.789+00:00");
+        assert!(parse_last_modified(Some("yesterday")).is_none());
+    }
+
+    #[test]
+    fn latest_sorts_before_newest_published_versions() {
+        let mut versions = vec!["1", "$LATEST", "10", "2"];
+        versions.sort_by_key(|version| version_order(version));
+        assert_eq!(versions, vec!["$LATEST", "10", "2", "1"]);
+    }
+}
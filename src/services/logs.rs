@@ -0,0 +1,562 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use aws_sdk_cloudwatchlogs::{
+    Client,
+    types::{LogGroup, LogStream, OrderBy},
+};
+use chrono::{DateTime, Utc};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    widgets::{Block, Borders, Cell, Row, Table},
+};
+use serde::{Deserialize, Serialize};
+use std::{any::Any, cmp::Reverse, sync::Arc};
+
+use super::traits::{
+    AwsService, ResourceData, ResourceItem, ServiceId, ServiceMetadata, ViewState, ViewType,
+    highlight_pattern,
+};
+use crate::app::App;
+use crate::ui::{
+    content::{header_row, list_title, render_empty, row_style, table_state},
+    skeleton::render_skeleton,
+};
+use crate::utils::{rate_limit::RateLimiter, text::highlight_match};
+
+const LOGS_SERVICE_ID: &str = "logs";
+
+/// Streams are listed most recently written first; older ones are rarely wanted
+const MAX_STREAMS: usize = 200;
+
+const GROUP_COLUMNS: [&str; 4] = ["NAME", "RETENTION", "STORED", "CREATED"];
+
+const GROUP_WIDTHS: [Constraint; 4] = [
+    Constraint::Min(40),    // Name
+    Constraint::Length(14), // Retention
+    Constraint::Length(12), // Stored bytes
+    Constraint::Length(20), // Created
+];
+
+const STREAM_COLUMNS: [&str; 3] = ["NAME", "LAST EVENT", "FIRST EVENT"];
+
+const STREAM_WIDTHS: [Constraint; 3] = [
+    Constraint::Min(40),    // Name
+    Constraint::Length(20), // Last event
+    Constraint::Length(20), // First event
+];
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LogGroupItem {
+    pub name: String,
+    pub retention_days: Option<i32>, // None when events never expire
+    pub stored_bytes: Option<i64>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LogStreamItem {
+    pub name: String,
+    pub last_event_at: Option<DateTime<Utc>>,
+    pub first_event_at: Option<DateTime<Utc>>,
+}
+
+impl ResourceItem for LogGroupItem {
+    fn id(&self) -> String {
+        self.name.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl ResourceItem for LogStreamItem {
+    fn id(&self) -> String {
+        self.name.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+/// CloudWatch Logs reports times as milliseconds since the epoch
+fn from_millis(millis: Option<i64>) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp_millis(millis?)
+}
+
+fn format_date(dt: Option<DateTime<Utc>>) -> String {
+    dt.map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn format_retention(days: Option<i32>) -> String {
+    match days {
+        Some(1) => "1 day".to_string(),
+        Some(days) => format!("{} days", days),
+        None => "Never expire".to_string(),
+    }
+}
+
+fn format_size(size: Option<i64>) -> String {
+    match size {
+        Some(size) if size >= 1_073_741_824 => {
+            format!("{:.1} GB", size as f64 / 1_073_741_824.0)
+        }
+        Some(size) if size >= 1_048_576 => format!("{:.1} MB", size as f64 / 1_048_576.0),
+        Some(size) if size >= 1024 => format!("{:.1} KB", size as f64 / 1024.0),
+        Some(size) => format!("{} B", size),
+        None => "-".to_string(),
+    }
+}
+
+impl From<&LogGroup> for LogGroupItem {
+    fn from(group: &LogGroup) -> Self {
+        Self {
+            name: group.log_group_name().unwrap_or_default().to_string(),
+            retention_days: group.retention_in_days(),
+            stored_bytes: group.stored_bytes(),
+            created_at: from_millis(group.creation_time()),
+        }
+    }
+}
+
+impl From<&LogStream> for LogStreamItem {
+    fn from(stream: &LogStream) -> Self {
+        Self {
+            name: stream.log_stream_name().unwrap_or_default().to_string(),
+            last_event_at: from_millis(stream.last_event_timestamp()),
+            first_event_at: from_millis(stream.first_event_timestamp()),
+        }
+    }
+}
+
+impl LogGroupItem {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            format_retention(self.retention_days),
+            format_size(self.stored_bytes),
+            format_date(self.created_at),
+        ]
+    }
+}
+
+impl LogStreamItem {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            format_date(self.last_event_at),
+            format_date(self.first_event_at),
+        ]
+    }
+}
+
+/// Most recently written streams first; streams without events last, by name
+fn stream_order(stream: &LogStreamItem) -> (Reverse<Option<DateTime<Utc>>>, String) {
+    (Reverse(stream.last_event_at), stream.name.clone())
+}
+
+pub struct CloudWatchLogsService {
+    client: Client,
+    rate_limiter: Arc<RateLimiter>,
+}
+
+impl CloudWatchLogsService {
+    pub fn new(client: Client, rate_limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            client,
+            rate_limiter,
+        }
+    }
+
+    pub async fn list_log_groups(&self) -> Result<Vec<LogGroupItem>> {
+        let mut groups = Vec::new();
+        let mut next_token = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .describe_log_groups()
+                .set_next_token(next_token)
+                .send()
+                .await?;
+            groups.extend(resp.log_groups().iter().map(LogGroupItem::from));
+
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        groups.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(groups)
+    }
+
+    pub async fn list_log_streams(&self, group_name: &str) -> Result<Vec<LogStreamItem>> {
+        let mut streams = Vec::new();
+        let mut next_token = None;
+        while streams.len() < MAX_STREAMS {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .describe_log_streams()
+                .log_group_name(group_name)
+                .order_by(OrderBy::LastEventTime)
+                .descending(true)
+                .set_next_token(next_token)
+                .send()
+                .await?;
+            streams.extend(resp.log_streams().iter().map(LogStreamItem::from));
+
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        streams.sort_by_key(stream_order);
+        streams.truncate(MAX_STREAMS);
+        Ok(streams)
+    }
+
+    fn render_groups(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        let filtered_items = self.visible_items(data, view_state);
+        let title = list_title("Log Groups", app, view_state, filtered_items.len(), data);
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(f, area, &app.theme, &title, &GROUP_COLUMNS, &GROUP_WIDTHS);
+            return;
+        }
+
+        if filtered_items.is_empty() {
+            let message = if !view_state.search_filter.is_empty() {
+                "No log groups match the current filter"
+            } else {
+                "No log groups found"
+            };
+            render_empty(f, area, &app.theme, &title, message);
+            return;
+        }
+
+        let rows: Vec<Row> = filtered_items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let group = item.as_any().downcast_ref::<LogGroupItem>()?;
+                let mut cells = group.cells().into_iter();
+                let name = cells.next().unwrap_or_default();
+                Some(
+                    Row::new(
+                        std::iter::once(Cell::from(highlight_match(
+                            &name,
+                            highlight_pattern(&view_state.search_filter, "name"),
+                        )))
+                        .chain(cells.map(Cell::from)),
+                    )
+                    .style(row_style(app, view_state, i)),
+                )
+            })
+            .collect();
+
+        let table = Table::new(rows, GROUP_WIDTHS)
+            .header(header_row(&app.theme, &GROUP_COLUMNS))
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_stateful_widget(
+            table,
+            area,
+            &mut table_state(view_state, view_state.selected_index),
+        );
+    }
+
+    fn render_streams(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        let filtered_items = self.visible_items(data, view_state);
+        let group = view_state.context.as_deref().unwrap_or("Unknown");
+        let title = list_title(
+            &format!("Log Groups: {} > Streams", group),
+            app,
+            view_state,
+            filtered_items.len(),
+            data,
+        );
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(f, area, &app.theme, &title, &STREAM_COLUMNS, &STREAM_WIDTHS);
+            return;
+        }
+
+        if filtered_items.is_empty() {
+            let message = if !view_state.search_filter.is_empty() {
+                "No log streams match the current filter"
+            } else {
+                "No log streams found"
+            };
+            render_empty(f, area, &app.theme, &title, message);
+            return;
+        }
+
+        let rows: Vec<Row> = filtered_items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let stream = item.as_any().downcast_ref::<LogStreamItem>()?;
+                let mut cells = stream.cells().into_iter();
+                let name = cells.next().unwrap_or_default();
+                Some(
+                    Row::new(
+                        std::iter::once(Cell::from(highlight_match(
+                            &name,
+                            highlight_pattern(&view_state.search_filter, "name"),
+                        )))
+                        .chain(cells.map(Cell::from)),
+                    )
+                    .style(row_style(app, view_state, i)),
+                )
+            })
+            .collect();
+
+        let table = Table::new(rows, STREAM_WIDTHS)
+            .header(header_row(&app.theme, &STREAM_COLUMNS))
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_stateful_widget(
+            table,
+            area,
+            &mut table_state(view_state, view_state.selected_index),
+        );
+    }
+
+    fn selected_item<'a, T: 'static>(
+        &self,
+        view_state: &ViewState,
+        data: &'a ResourceData,
+    ) -> Option<&'a T> {
+        self.visible_items(data, view_state)
+            .get(view_state.selected_index)?
+            .as_any()
+            .downcast_ref::<T>()
+    }
+}
+
+#[async_trait]
+impl AwsService for CloudWatchLogsService {
+    fn metadata(&self) -> ServiceMetadata {
+        ServiceMetadata {
+            id: LOGS_SERVICE_ID.to_string(),
+            name: "CloudWatch Logs".to_string(),
+            description: "Log groups and their streams".to_string(),
+            // `:logs` already shows ats' own message history
+            command: "cloudwatch".to_string(),
+            aliases: vec!["cw".to_string(), "loggroups".to_string()],
+            short_label: Some("Logs".to_string()),
+            icon: None,
+        }
+    }
+
+    async fn load_data(&self, view_state: &ViewState) -> Result<ResourceData> {
+        let items: Vec<Box<dyn ResourceItem>> = match view_state.view_type {
+            ViewType::List => self
+                .list_log_groups()
+                .await?
+                .into_iter()
+                .map(|group| Box::new(group) as Box<dyn ResourceItem>)
+                .collect(),
+            ViewType::Detail => match view_state.context.as_deref() {
+                Some(group_name) => self
+                    .list_log_streams(group_name)
+                    .await?
+                    .into_iter()
+                    .map(|stream| Box::new(stream) as Box<dyn ResourceItem>)
+                    .collect(),
+                None => Vec::new(),
+            },
+            ViewType::Custom(_) => Vec::new(),
+        };
+        Ok(ResourceData::new(items))
+    }
+
+    fn render(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        match view_state.view_type {
+            ViewType::List => self.render_groups(f, area, app, view_state, data),
+            ViewType::Detail => self.render_streams(f, area, app, view_state, data),
+            ViewType::Custom(_) => {}
+        }
+    }
+
+    fn handle_enter(&self, view_state: &mut ViewState, data: &ResourceData) -> Option<ViewState> {
+        if view_state.view_type != ViewType::List {
+            return None;
+        }
+        let group = self.selected_item::<LogGroupItem>(view_state, data)?;
+        let mut new_view = ViewState::new(view_state.service_id.clone(), ViewType::Detail);
+        new_view.context = Some(group.name.clone());
+        Some(new_view)
+    }
+
+    fn get_copy_content(
+        &self,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) -> Option<(String, String)> {
+        match view_state.view_type {
+            ViewType::List => {
+                let group = self.selected_item::<LogGroupItem>(view_state, data)?;
+                Some((group.name.clone(), group.name.clone()))
+            }
+            ViewType::Detail => {
+                let stream = self.selected_item::<LogStreamItem>(view_state, data)?;
+                Some((stream.name.clone(), stream.name.clone()))
+            }
+            ViewType::Custom(_) => None,
+        }
+    }
+
+    fn matches_filter(&self, item: &dyn ResourceItem, filter: &str) -> bool {
+        let filter = filter.to_lowercase();
+        if let Some(group) = item.as_any().downcast_ref::<LogGroupItem>() {
+            group.name.to_lowercase().contains(&filter)
+        } else if let Some(stream) = item.as_any().downcast_ref::<LogStreamItem>() {
+            stream.name.to_lowercase().contains(&filter)
+        } else {
+            false
+        }
+    }
+
+    fn filter_columns(&self, view_state: &ViewState) -> Vec<&'static str> {
+        match view_state.view_type {
+            ViewType::List | ViewType::Detail => vec!["name"],
+            ViewType::Custom(_) => Vec::new(),
+        }
+    }
+
+    fn matches_column(&self, item: &dyn ResourceItem, column: &str, value: &str) -> bool {
+        column == "name" && self.matches_filter(item, value)
+    }
+
+    fn export_columns(&self, view_state: &ViewState) -> Vec<String> {
+        match view_state.view_type {
+            ViewType::List => GROUP_COLUMNS.iter().map(|c| c.to_string()).collect(),
+            ViewType::Detail => STREAM_COLUMNS.iter().map(|c| c.to_string()).collect(),
+            ViewType::Custom(_) => Vec::new(),
+        }
+    }
+
+    fn export_rows(&self, view_state: &ViewState, data: &ResourceData) -> Vec<Vec<String>> {
+        self.visible_items(data, view_state)
+            .iter()
+            .filter_map(|item| {
+                if let Some(group) = item.as_any().downcast_ref::<LogGroupItem>() {
+                    Some(group.cells())
+                } else {
+                    item.as_any()
+                        .downcast_ref::<LogStreamItem>()
+                        .map(LogStreamItem::cells)
+                }
+            })
+            .collect()
+    }
+
+    fn deep_link_path(&self, view_state: &ViewState) -> Option<String> {
+        match view_state.view_type {
+            ViewType::List => Some(String::new()),
+            ViewType::Detail => Some(format!("{}/streams", view_state.context.as_deref()?)),
+            ViewType::Custom(_) => None,
+        }
+    }
+
+    fn view_from_deep_link(
+        &self,
+        service_id: &ServiceId,
+        path: &str,
+        _cached: Option<&ResourceData>,
+    ) -> Option<ViewState> {
+        if path.is_empty() {
+            return Some(ViewState::new(service_id.clone(), ViewType::List));
+        }
+
+        // Group names contain slashes themselves, e.g. /aws/lambda/my-function
+        let group_name = path.strip_suffix("/streams")?;
+        let mut view_state = ViewState::new(service_id.clone(), ViewType::Detail);
+        view_state.context = Some(group_name.to_string());
+        Some(view_state)
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.rate_limiter.acquire().await;
+        self.client.describe_log_groups().limit(1).send().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stream(name: &str, last_event_millis: Option<i64>) -> LogStreamItem {
+        LogStreamItem {
+            name: name.to_string(),
+            last_event_at: from_millis(last_event_millis),
+            first_event_at: None,
+        }
+    }
+
+    #[test]
+    fn streams_sort_by_most_recent_event() {
+        let mut streams = vec![
+            stream("idle", None),
+            stream("old", Some(1_700_000_000_000)),
+            stream("new", Some(1_700_000_500_000)),
+        ];
+        streams.sort_by_key(stream_order);
+        let names: Vec<&str> = streams.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["new", "old", "idle"]);
+    }
+
+    #[test]
+    fn formats_retention_and_stored_bytes() {
+        assert_eq!(format_retention(None), "Never expire");
+        assert_eq!(format_retention(Some(1)), "1 day");
+        assert_eq!(format_retention(Some(30)), "30 days");
+        assert_eq!(format_size(Some(512)), "512 B");
+        assert_eq!(format_size(Some(3 * 1_073_741_824)), "3.0 GB");
+    }
+}
@@ -3,10 +3,12 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use super::traits::{AwsService, ResourceData, ServiceId, ServiceMetadata, ViewState};
+use crate::utils::rate_limit::RateLimiter;
 
 pub struct ServiceManager {
     services: HashMap<ServiceId, Arc<dyn AwsService>>,
-    service_data: HashMap<ServiceId, ResourceData>,
+    service_data: HashMap<ServiceId, (ViewState, ResourceData)>, // Data with the view it was loaded for
+    rate_limiter: Arc<RateLimiter>,
 }
 
 impl ServiceManager {
@@ -14,15 +16,31 @@ impl ServiceManager {
         Self {
             services: HashMap::new(),
             service_data: HashMap::new(),
+            rate_limiter: Arc::new(RateLimiter::unlimited()),
         }
     }
 
+    /// Limiter that services acquire from before each AWS request
+    pub fn rate_limiter(&self) -> Arc<RateLimiter> {
+        self.rate_limiter.clone()
+    }
+
+    /// Replace the shared limiter; only affects services registered afterwards
+    pub fn set_rate_limiter(&mut self, rate_limiter: RateLimiter) {
+        self.rate_limiter = Arc::new(rate_limiter);
+    }
+
     pub fn register_service(&mut self, service: Arc<dyn AwsService>) {
         let metadata = service.metadata();
         let service_id = ServiceId::new(&metadata.id);
         self.services.insert(service_id, service);
     }
 
+    /// Forget all loaded data, e.g. after the services' clients were rebuilt
+    pub fn clear_data(&mut self) {
+        self.service_data.clear();
+    }
+
     pub fn get_service(&self, service_id: &ServiceId) -> Option<&Arc<dyn AwsService>> {
         self.services.get(service_id)
     }
@@ -34,13 +52,22 @@ impl ServiceManager {
             .collect()
     }
 
+    /// Display label for a service, falling back to its id if it isn't registered
+    pub fn service_label(&self, service_id: &ServiceId) -> String {
+        self.services
+            .get(service_id)
+            .map(|service| service.metadata().display_label())
+            .unwrap_or_else(|| service_id.to_string())
+    }
+
     pub fn get_service_by_command(
         &self,
         command: &str,
     ) -> Option<(&ServiceId, &Arc<dyn AwsService>)> {
-        self.services
-            .iter()
-            .find(|(_, service)| service.metadata().command == command)
+        self.services.iter().find(|(_, service)| {
+            let metadata = service.metadata();
+            metadata.command == command || metadata.aliases.iter().any(|alias| alias == command)
+        })
     }
 
     pub async fn load_service_data(
@@ -48,21 +75,47 @@ impl ServiceManager {
         service_id: &ServiceId,
         view_state: &ViewState,
     ) -> Result<()> {
-        if let Some(service) = self.services.get(service_id) {
-            let data = service.load_data(view_state).await?;
-            self.service_data.insert(service_id.clone(), data);
+        if let Some(data) = self.fetch_service_data(service_id, view_state).await? {
+            self.service_data
+                .insert(service_id.clone(), (view_state.clone(), data));
         }
         Ok(())
     }
 
+    /// Load `view_state`'s data without caching it. `None` for an unknown service.
+    pub async fn fetch_service_data(
+        &self,
+        service_id: &ServiceId,
+        view_state: &ViewState,
+    ) -> Result<Option<ResourceData>> {
+        let Some(service) = self.services.get(service_id) else {
+            return Ok(None);
+        };
+        service.load_data(view_state).await.map(Some)
+    }
+
+    pub fn set_service_data(&mut self, view_state: &ViewState, data: ResourceData) {
+        self.service_data
+            .insert(view_state.service_id.clone(), (view_state.clone(), data));
+    }
+
     pub fn get_service_data(&self, service_id: &ServiceId) -> Option<&ResourceData> {
-        self.service_data.get(service_id)
+        self.service_data.get(service_id).map(|(_, data)| data)
+    }
+
+    /// Every service's cached data, paired with the view it was loaded for
+    pub fn loaded_data(&self) -> impl Iterator<Item = (&ViewState, &ResourceData)> {
+        self.service_data
+            .values()
+            .map(|(view_state, data)| (view_state, data))
     }
 
+    #[allow(dead_code)]
     pub fn clear_service_data(&mut self, service_id: &ServiceId) {
         self.service_data.remove(service_id);
     }
 
+    #[allow(dead_code)]
     pub fn has_service(&self, service_id: &ServiceId) -> bool {
         self.services.contains_key(service_id)
     }
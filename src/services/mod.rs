@@ -1,3 +1,13 @@
+pub mod ec2;
 pub mod ecr;
+pub mod iam;
+pub mod lambda;
+pub mod logs;
 pub mod manager;
+pub mod rds;
+pub mod route53;
+pub mod s3;
+pub mod secrets;
+pub mod sfn;
 pub mod traits;
+pub mod vpc;
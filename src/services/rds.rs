@@ -0,0 +1,677 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use aws_sdk_rds::{
+    Client,
+    types::{DbInstance, DbSnapshot},
+};
+use chrono::{DateTime, Utc};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Cell, Row, Table},
+};
+use serde::{Deserialize, Serialize};
+use std::{any::Any, cmp::Reverse, sync::Arc};
+
+use super::traits::{
+    AwsService, ResourceData, ResourceItem, ServiceId, ServiceMetadata, ViewState, ViewType,
+    highlight_pattern,
+};
+use crate::app::App;
+use crate::ui::{
+    content::{header_row, list_title, render_empty, row_style, table_state},
+    skeleton::render_skeleton,
+    theme::Theme,
+};
+use crate::utils::{rate_limit::RateLimiter, text::highlight_match};
+
+const RDS_SERVICE_ID: &str = "rds";
+
+const INSTANCE_COLUMNS: [&str; 7] = [
+    "IDENTIFIER",
+    "ENGINE",
+    "VERSION",
+    "CLASS",
+    "STATUS",
+    "ENDPOINT",
+    "MULTI-AZ",
+];
+
+const INSTANCE_WIDTHS: [Constraint; 7] = [
+    Constraint::Min(24),    // Identifier
+    Constraint::Length(18), // Engine
+    Constraint::Length(12), // Version
+    Constraint::Length(16), // Class
+    Constraint::Length(14), // Status
+    Constraint::Min(30),    // Endpoint
+    Constraint::Length(9),  // Multi-AZ
+];
+
+const SNAPSHOT_COLUMNS: [&str; 5] = ["IDENTIFIER", "TYPE", "STATUS", "CREATED", "SIZE"];
+
+const SNAPSHOT_WIDTHS: [Constraint; 5] = [
+    Constraint::Min(40),    // Identifier
+    Constraint::Length(10), // Type
+    Constraint::Length(12), // Status
+    Constraint::Length(20), // Created
+    Constraint::Length(10), // Allocated storage
+];
+
+/// Index of the STATUS column in each view, which is colored by health
+const INSTANCE_STATUS_COLUMN: usize = 4;
+const SNAPSHOT_STATUS_COLUMN: usize = 2;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DBInstanceItem {
+    pub identifier: String,
+    pub engine: String,
+    pub engine_version: String,
+    pub instance_class: String,
+    pub status: String,
+    pub endpoint_address: Option<String>, // None until the instance is first available
+    pub endpoint_port: Option<i32>,
+    pub multi_az: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DBSnapshotItem {
+    pub identifier: String,
+    pub snapshot_type: String, // manual or automated
+    pub status: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub allocated_storage_gib: Option<i32>,
+}
+
+impl ResourceItem for DBInstanceItem {
+    fn id(&self) -> String {
+        self.identifier.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl ResourceItem for DBSnapshotItem {
+    fn id(&self) -> String {
+        self.identifier.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+fn to_utc(dt: &aws_sdk_rds::primitives::DateTime) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(dt.secs(), dt.subsec_nanos())
+}
+
+fn format_date(dt: Option<DateTime<Utc>>) -> String {
+    dt.map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Status color for instances and snapshots: green when usable, gray when stopped,
+/// red when broken, and yellow while RDS is working on it
+fn status_color(theme: &Theme, status: &str) -> Color {
+    match status {
+        "available" => theme.success,
+        "stopped" => theme.muted,
+        "failed" | "storage-full" | "inaccessible-encryption-credentials" => theme.error,
+        status if status.starts_with("incompatible-") => theme.error,
+        _ => theme.highlight,
+    }
+}
+
+impl From<&DbInstance> for DBInstanceItem {
+    fn from(instance: &DbInstance) -> Self {
+        Self {
+            identifier: instance
+                .db_instance_identifier()
+                .unwrap_or_default()
+                .to_string(),
+            engine: instance.engine().unwrap_or_default().to_string(),
+            engine_version: instance.engine_version().unwrap_or_default().to_string(),
+            instance_class: instance.db_instance_class().unwrap_or_default().to_string(),
+            status: instance
+                .db_instance_status()
+                .unwrap_or_default()
+                .to_string(),
+            endpoint_address: instance
+                .endpoint()
+                .and_then(|endpoint| endpoint.address())
+                .map(str::to_string),
+            endpoint_port: instance.endpoint().and_then(|endpoint| endpoint.port()),
+            multi_az: instance.multi_az().unwrap_or(false),
+        }
+    }
+}
+
+impl From<&DbSnapshot> for DBSnapshotItem {
+    fn from(snapshot: &DbSnapshot) -> Self {
+        Self {
+            identifier: snapshot
+                .db_snapshot_identifier()
+                .unwrap_or_default()
+                .to_string(),
+            snapshot_type: snapshot.snapshot_type().unwrap_or_default().to_string(),
+            status: snapshot.status().unwrap_or_default().to_string(),
+            created_at: snapshot.snapshot_create_time().and_then(to_utc),
+            allocated_storage_gib: snapshot.allocated_storage(),
+        }
+    }
+}
+
+impl DBInstanceItem {
+    /// `address:port` as clients connect to it, or `None` while there's no endpoint
+    pub fn endpoint(&self) -> Option<String> {
+        let address = self.endpoint_address.as_deref()?;
+        Some(match self.endpoint_port {
+            Some(port) => format!("{}:{}", address, port),
+            None => address.to_string(),
+        })
+    }
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.identifier.clone(),
+            self.engine.clone(),
+            self.engine_version.clone(),
+            self.instance_class.clone(),
+            self.status.clone(),
+            self.endpoint().unwrap_or_else(|| "-".to_string()),
+            if self.multi_az { "Yes" } else { "No" }.to_string(),
+        ]
+    }
+}
+
+impl DBSnapshotItem {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.identifier.clone(),
+            self.snapshot_type.clone(),
+            self.status.clone(),
+            format_date(self.created_at),
+            self.allocated_storage_gib
+                .map(|size| format!("{} GiB", size))
+                .unwrap_or_else(|| "-".to_string()),
+        ]
+    }
+}
+
+pub struct RDSService {
+    client: Client,
+    rate_limiter: Arc<RateLimiter>,
+}
+
+impl RDSService {
+    pub fn new(client: Client, rate_limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            client,
+            rate_limiter,
+        }
+    }
+
+    pub async fn list_instances(&self) -> Result<Vec<DBInstanceItem>> {
+        let mut instances = Vec::new();
+        let mut marker = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .describe_db_instances()
+                .set_marker(marker)
+                .send()
+                .await?;
+            instances.extend(resp.db_instances().iter().map(DBInstanceItem::from));
+
+            marker = resp.marker().map(str::to_string);
+            if marker.is_none() {
+                break;
+            }
+        }
+
+        instances.sort_by(|a, b| a.identifier.cmp(&b.identifier));
+        Ok(instances)
+    }
+
+    pub async fn list_snapshots(&self, instance_identifier: &str) -> Result<Vec<DBSnapshotItem>> {
+        let mut snapshots = Vec::new();
+        let mut marker = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .describe_db_snapshots()
+                .db_instance_identifier(instance_identifier)
+                .set_marker(marker)
+                .send()
+                .await?;
+            snapshots.extend(resp.db_snapshots().iter().map(DBSnapshotItem::from));
+
+            marker = resp.marker().map(str::to_string);
+            if marker.is_none() {
+                break;
+            }
+        }
+
+        // Newest first, as they're usually wanted for a restore
+        snapshots.sort_by_key(|snapshot| Reverse(snapshot.created_at));
+        Ok(snapshots)
+    }
+
+    fn render_instances(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        let filtered_items = self.visible_items(data, view_state);
+        let title = list_title("DB Instances", app, view_state, filtered_items.len(), data);
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(
+                f,
+                area,
+                &app.theme,
+                &title,
+                &INSTANCE_COLUMNS,
+                &INSTANCE_WIDTHS,
+            );
+            return;
+        }
+
+        if filtered_items.is_empty() {
+            let message = if !view_state.search_filter.is_empty() {
+                "No DB instances match the current filter"
+            } else {
+                "No DB instances found"
+            };
+            render_empty(f, area, &app.theme, &title, message);
+            return;
+        }
+
+        let rows: Vec<Row> = filtered_items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let instance = item.as_any().downcast_ref::<DBInstanceItem>()?;
+                let cells = instance.cells();
+                Some(
+                    status_row(
+                        &app.theme,
+                        view_state,
+                        i,
+                        cells,
+                        INSTANCE_STATUS_COLUMN,
+                        &instance.status,
+                    )
+                    .style(row_style(app, view_state, i)),
+                )
+            })
+            .collect();
+
+        let table = Table::new(rows, INSTANCE_WIDTHS)
+            .header(header_row(&app.theme, &INSTANCE_COLUMNS))
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_stateful_widget(
+            table,
+            area,
+            &mut table_state(view_state, view_state.selected_index),
+        );
+    }
+
+    fn render_snapshots(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        let filtered_items = self.visible_items(data, view_state);
+        let instance = view_state.context.as_deref().unwrap_or("Unknown");
+        let title = list_title(
+            &format!("DB Instances: {} > Snapshots", instance),
+            app,
+            view_state,
+            filtered_items.len(),
+            data,
+        );
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(
+                f,
+                area,
+                &app.theme,
+                &title,
+                &SNAPSHOT_COLUMNS,
+                &SNAPSHOT_WIDTHS,
+            );
+            return;
+        }
+
+        if filtered_items.is_empty() {
+            let message = if !view_state.search_filter.is_empty() {
+                "No snapshots match the current filter"
+            } else {
+                "No snapshots found"
+            };
+            render_empty(f, area, &app.theme, &title, message);
+            return;
+        }
+
+        let rows: Vec<Row> = filtered_items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let snapshot = item.as_any().downcast_ref::<DBSnapshotItem>()?;
+                let cells = snapshot.cells();
+                Some(
+                    status_row(
+                        &app.theme,
+                        view_state,
+                        i,
+                        cells,
+                        SNAPSHOT_STATUS_COLUMN,
+                        &snapshot.status,
+                    )
+                    .style(row_style(app, view_state, i)),
+                )
+            })
+            .collect();
+
+        let table = Table::new(rows, SNAPSHOT_WIDTHS)
+            .header(header_row(&app.theme, &SNAPSHOT_COLUMNS))
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_stateful_widget(
+            table,
+            area,
+            &mut table_state(view_state, view_state.selected_index),
+        );
+    }
+
+    fn selected_item<'a, T: 'static>(
+        &self,
+        view_state: &ViewState,
+        data: &'a ResourceData,
+    ) -> Option<&'a T> {
+        self.visible_items(data, view_state)
+            .get(view_state.selected_index)?
+            .as_any()
+            .downcast_ref::<T>()
+    }
+}
+
+/// Table row with the identifier highlighted for the search and the status colored
+fn status_row(
+    theme: &Theme,
+    view_state: &ViewState,
+    index: usize,
+    cells: Vec<String>,
+    status_column: usize,
+    status: &str,
+) -> Row<'static> {
+    // Keep the status readable on the selection highlight
+    let status_style = if index == view_state.selected_index {
+        Style::default()
+    } else {
+        Style::default().fg(status_color(theme, status))
+    };
+
+    Row::new(
+        cells
+            .into_iter()
+            .enumerate()
+            .map(|(column, cell)| match column {
+                0 => Cell::from(highlight_match(
+                    &cell,
+                    highlight_pattern(&view_state.search_filter, "identifier"),
+                )),
+                column if column == status_column => Cell::from(cell).style(status_style),
+                _ => Cell::from(cell),
+            }),
+    )
+}
+
+#[async_trait]
+impl AwsService for RDSService {
+    fn metadata(&self) -> ServiceMetadata {
+        ServiceMetadata {
+            id: RDS_SERVICE_ID.to_string(),
+            name: "RDS".to_string(),
+            description: "Relational database instances and their snapshots".to_string(),
+            command: "rds".to_string(),
+            aliases: vec!["databases".to_string()],
+            short_label: None,
+            icon: None,
+        }
+    }
+
+    async fn load_data(&self, view_state: &ViewState) -> Result<ResourceData> {
+        let items: Vec<Box<dyn ResourceItem>> = match view_state.view_type {
+            ViewType::List => self
+                .list_instances()
+                .await?
+                .into_iter()
+                .map(|instance| Box::new(instance) as Box<dyn ResourceItem>)
+                .collect(),
+            ViewType::Detail => match view_state.context.as_deref() {
+                Some(identifier) => self
+                    .list_snapshots(identifier)
+                    .await?
+                    .into_iter()
+                    .map(|snapshot| Box::new(snapshot) as Box<dyn ResourceItem>)
+                    .collect(),
+                None => Vec::new(),
+            },
+            ViewType::Custom(_) => Vec::new(),
+        };
+        Ok(ResourceData::new(items))
+    }
+
+    fn render(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        match view_state.view_type {
+            ViewType::List => self.render_instances(f, area, app, view_state, data),
+            ViewType::Detail => self.render_snapshots(f, area, app, view_state, data),
+            ViewType::Custom(_) => {}
+        }
+    }
+
+    fn handle_enter(&self, view_state: &mut ViewState, data: &ResourceData) -> Option<ViewState> {
+        if view_state.view_type != ViewType::List {
+            return None;
+        }
+        let instance = self.selected_item::<DBInstanceItem>(view_state, data)?;
+        let mut new_view = ViewState::new(view_state.service_id.clone(), ViewType::Detail);
+        new_view.context = Some(instance.identifier.clone());
+        Some(new_view)
+    }
+
+    fn get_copy_content(
+        &self,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) -> Option<(String, String)> {
+        match view_state.view_type {
+            ViewType::List => {
+                let instance = self.selected_item::<DBInstanceItem>(view_state, data)?;
+                // Instances still being created have no endpoint yet
+                let content = instance
+                    .endpoint()
+                    .unwrap_or_else(|| instance.identifier.clone());
+                Some((content, instance.identifier.clone()))
+            }
+            ViewType::Detail => {
+                let snapshot = self.selected_item::<DBSnapshotItem>(view_state, data)?;
+                Some((snapshot.identifier.clone(), snapshot.identifier.clone()))
+            }
+            ViewType::Custom(_) => None,
+        }
+    }
+
+    fn matches_filter(&self, item: &dyn ResourceItem, filter: &str) -> bool {
+        let filter = filter.to_lowercase();
+        if let Some(instance) = item.as_any().downcast_ref::<DBInstanceItem>() {
+            instance.identifier.to_lowercase().contains(&filter)
+                || instance.engine.to_lowercase().contains(&filter)
+        } else if let Some(snapshot) = item.as_any().downcast_ref::<DBSnapshotItem>() {
+            snapshot.identifier.to_lowercase().contains(&filter)
+        } else {
+            false
+        }
+    }
+
+    fn filter_columns(&self, view_state: &ViewState) -> Vec<&'static str> {
+        match view_state.view_type {
+            ViewType::List => vec!["identifier", "engine", "status"],
+            ViewType::Detail => vec!["identifier", "type", "status"],
+            ViewType::Custom(_) => Vec::new(),
+        }
+    }
+
+    fn matches_column(&self, item: &dyn ResourceItem, column: &str, value: &str) -> bool {
+        let value = value.to_lowercase();
+        let field = if let Some(instance) = item.as_any().downcast_ref::<DBInstanceItem>() {
+            match column {
+                "identifier" => &instance.identifier,
+                "engine" => &instance.engine,
+                "status" => &instance.status,
+                _ => return false,
+            }
+        } else if let Some(snapshot) = item.as_any().downcast_ref::<DBSnapshotItem>() {
+            match column {
+                "identifier" => &snapshot.identifier,
+                "type" => &snapshot.snapshot_type,
+                "status" => &snapshot.status,
+                _ => return false,
+            }
+        } else {
+            return false;
+        };
+        field.to_lowercase().contains(&value)
+    }
+
+    fn export_columns(&self, view_state: &ViewState) -> Vec<String> {
+        match view_state.view_type {
+            ViewType::List => INSTANCE_COLUMNS.iter().map(|c| c.to_string()).collect(),
+            ViewType::Detail => SNAPSHOT_COLUMNS.iter().map(|c| c.to_string()).collect(),
+            ViewType::Custom(_) => Vec::new(),
+        }
+    }
+
+    fn export_rows(&self, view_state: &ViewState, data: &ResourceData) -> Vec<Vec<String>> {
+        self.visible_items(data, view_state)
+            .iter()
+            .filter_map(|item| {
+                if let Some(instance) = item.as_any().downcast_ref::<DBInstanceItem>() {
+                    Some(instance.cells())
+                } else {
+                    item.as_any()
+                        .downcast_ref::<DBSnapshotItem>()
+                        .map(DBSnapshotItem::cells)
+                }
+            })
+            .collect()
+    }
+
+    fn deep_link_path(&self, view_state: &ViewState) -> Option<String> {
+        match view_state.view_type {
+            ViewType::List => Some(String::new()),
+            ViewType::Detail => Some(format!("{}/snapshots", view_state.context.as_deref()?)),
+            ViewType::Custom(_) => None,
+        }
+    }
+
+    fn view_from_deep_link(
+        &self,
+        service_id: &ServiceId,
+        path: &str,
+        _cached: Option<&ResourceData>,
+    ) -> Option<ViewState> {
+        if path.is_empty() {
+            return Some(ViewState::new(service_id.clone(), ViewType::List));
+        }
+
+        let identifier = path.strip_suffix("/snapshots")?;
+        let mut view_state = ViewState::new(service_id.clone(), ViewType::Detail);
+        view_state.context = Some(identifier.to_string());
+        Some(view_state)
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.rate_limiter.acquire().await;
+        self.client
+            .describe_db_instances()
+            .max_records(20) // The API's minimum page size
+            .send()
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instance(address: Option<&str>, port: Option<i32>) -> DBInstanceItem {
+        DBInstanceItem {
+            identifier: "orders-db".to_string(),
+            engine: "postgres".to_string(),
+            engine_version: "16.3".to_string(),
+            instance_class: "db.t4g.medium".to_string(),
+            status: "available".to_string(),
+            endpoint_address: address.map(str::to_string),
+            endpoint_port: port,
+            multi_az: true,
+        }
+    }
+
+    #[test]
+    fn endpoint_joins_address_and_port() {
+        let address = "orders-db.abc123.eu-west-1.rds.amazonaws.com";
+        assert_eq!(
+            instance(Some(address), Some(5432)).endpoint().as_deref(),
+            Some("orders-db.abc123.eu-west-1.rds.amazonaws.com:5432")
+        );
+        assert_eq!(instance(None, None).endpoint(), None);
+        assert_eq!(instance(None, None).cells()[5], "-");
+    }
+
+    #[test]
+    fn statuses_are_colored_by_health() {
+        let theme = Theme::default();
+        assert_eq!(status_color(&theme, "available"), theme.success);
+        assert_eq!(status_color(&theme, "stopped"), theme.muted);
+        assert_eq!(status_color(&theme, "modifying"), theme.highlight);
+        assert_eq!(status_color(&theme, "incompatible-network"), theme.error);
+    }
+}
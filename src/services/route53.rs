@@ -0,0 +1,586 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use aws_sdk_route53::{
+    Client,
+    types::{HostedZone, ResourceRecordSet},
+};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    widgets::{Block, Borders, Cell, Row, Table},
+};
+use serde::{Deserialize, Serialize};
+use std::{any::Any, sync::Arc};
+
+use super::traits::{
+    AwsService, ResourceData, ResourceItem, ServiceId, ServiceMetadata, ViewState, ViewType,
+    highlight_pattern,
+};
+use crate::app::App;
+use crate::ui::{
+    content::{header_row, list_title, render_empty, row_style, table_state},
+    skeleton::render_skeleton,
+};
+use crate::utils::{rate_limit::RateLimiter, text::highlight_match};
+
+const ROUTE53_SERVICE_ID: &str = "route53";
+
+/// Prefix the API puts on zone ids, e.g. `/hostedzone/Z0123456789`
+const HOSTED_ZONE_ID_PREFIX: &str = "/hostedzone/";
+
+const ZONE_COLUMNS: [&str; 5] = ["NAME", "ZONE ID", "RECORDS", "TYPE", "COMMENT"];
+
+const ZONE_WIDTHS: [Constraint; 5] = [
+    Constraint::Min(30),    // Name
+    Constraint::Length(24), // Zone id
+    Constraint::Length(8),  // Record count
+    Constraint::Length(8),  // Private or public
+    Constraint::Min(30),    // Comment
+];
+
+const RECORD_COLUMNS: [&str; 4] = ["NAME", "TYPE", "TTL", "VALUE"];
+
+const RECORD_WIDTHS: [Constraint; 4] = [
+    Constraint::Min(30),   // Name
+    Constraint::Length(7), // Type
+    Constraint::Length(7), // TTL
+    Constraint::Min(40),   // Value
+];
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Route53HostedZone {
+    pub zone_id: String, // Without the `/hostedzone/` prefix
+    pub name: String,
+    pub record_count: Option<i64>,
+    pub private: bool,
+    pub comment: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Route53Record {
+    pub name: String,
+    pub record_type: String,
+    pub ttl: Option<i64>, // None for alias records, which use the target's TTL
+    pub values: Vec<String>, // The alias target's DNS name for alias records
+    pub alias: bool,
+    pub set_identifier: Option<String>, // Tells weighted, latency, etc. records apart
+}
+
+impl ResourceItem for Route53HostedZone {
+    fn id(&self) -> String {
+        self.zone_id.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl ResourceItem for Route53Record {
+    fn id(&self) -> String {
+        match &self.set_identifier {
+            Some(set_identifier) => {
+                format!("{}|{}|{}", self.name, self.record_type, set_identifier)
+            }
+            None => format!("{}|{}", self.name, self.record_type),
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl From<&HostedZone> for Route53HostedZone {
+    fn from(zone: &HostedZone) -> Self {
+        Self {
+            zone_id: zone
+                .id()
+                .trim_start_matches(HOSTED_ZONE_ID_PREFIX)
+                .to_string(),
+            name: zone.name().to_string(),
+            record_count: zone.resource_record_set_count(),
+            private: zone.config().is_some_and(|config| config.private_zone()),
+            comment: zone
+                .config()
+                .and_then(|config| config.comment())
+                .filter(|comment| !comment.is_empty())
+                .map(str::to_string),
+        }
+    }
+}
+
+impl From<&ResourceRecordSet> for Route53Record {
+    fn from(record: &ResourceRecordSet) -> Self {
+        let (values, alias) = match record.alias_target() {
+            Some(target) => (vec![target.dns_name().to_string()], true),
+            None => (
+                record
+                    .resource_records()
+                    .iter()
+                    .map(|value| value.value().to_string())
+                    .collect(),
+                false,
+            ),
+        };
+        Self {
+            name: record.name().to_string(),
+            record_type: record.r#type().as_str().to_string(),
+            ttl: record.ttl(),
+            values,
+            alias,
+            set_identifier: record.set_identifier().map(str::to_string),
+        }
+    }
+}
+
+impl Route53HostedZone {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            self.zone_id.clone(),
+            self.record_count
+                .map(|count| count.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            if self.private { "Private" } else { "Public" }.to_string(),
+            self.comment.clone().unwrap_or_else(|| "-".to_string()),
+        ]
+    }
+}
+
+impl Route53Record {
+    /// The values on one line, with alias targets marked as such
+    fn value(&self) -> String {
+        let values = self.values.join(", ");
+        if self.alias {
+            format!("ALIAS {}", values)
+        } else {
+            values
+        }
+    }
+
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            self.record_type.clone(),
+            self.ttl
+                .map(|ttl| ttl.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            self.value(),
+        ]
+    }
+}
+
+/// Zone id and name from a record view's `id|name` context
+fn zone_context(view_state: &ViewState) -> Option<(&str, &str)> {
+    let context = view_state.context.as_deref()?;
+    Some(context.split_once('|').unwrap_or((context, context)))
+}
+
+pub struct Route53Service {
+    client: Client,
+    rate_limiter: Arc<RateLimiter>,
+}
+
+impl Route53Service {
+    pub fn new(client: Client, rate_limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            client,
+            rate_limiter,
+        }
+    }
+
+    pub async fn list_hosted_zones(&self) -> Result<Vec<Route53HostedZone>> {
+        let mut zones = Vec::new();
+        let mut marker = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .list_hosted_zones()
+                .set_marker(marker)
+                .send()
+                .await?;
+            zones.extend(resp.hosted_zones().iter().map(Route53HostedZone::from));
+
+            marker = resp
+                .next_marker()
+                .filter(|_| resp.is_truncated())
+                .map(str::to_string);
+            if marker.is_none() {
+                break;
+            }
+        }
+
+        zones.sort_by(|a, b| (&a.name, a.private).cmp(&(&b.name, b.private)));
+        Ok(zones)
+    }
+
+    /// Record sets in the zone, in the API's order (by name, then type)
+    pub async fn list_records(&self, zone_id: &str) -> Result<Vec<Route53Record>> {
+        let mut records = Vec::new();
+        let mut start = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let (name, record_type, identifier) = start.unwrap_or_default();
+            let resp = self
+                .client
+                .list_resource_record_sets()
+                .hosted_zone_id(zone_id)
+                .set_start_record_name(name)
+                .set_start_record_type(record_type)
+                .set_start_record_identifier(identifier)
+                .send()
+                .await?;
+            records.extend(resp.resource_record_sets().iter().map(Route53Record::from));
+
+            // Pages continue from a name, type, and identifier rather than a token
+            start = resp.is_truncated().then(|| {
+                (
+                    resp.next_record_name().map(str::to_string),
+                    resp.next_record_type().cloned(),
+                    resp.next_record_identifier().map(str::to_string),
+                )
+            });
+            if start.is_none() {
+                break;
+            }
+        }
+        Ok(records)
+    }
+
+    fn render_table(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        let filtered_items = self.visible_items(data, view_state);
+        // Route 53 is global, so the header's region has no bearing on these lists
+        let (name, columns, widths, noun) = match &view_state.view_type {
+            ViewType::Detail => {
+                let (_, zone) = zone_context(view_state).unwrap_or(("", "Unknown"));
+                (
+                    format!("Route 53: {} > Records - Global", zone),
+                    &RECORD_COLUMNS[..],
+                    &RECORD_WIDTHS[..],
+                    "records",
+                )
+            }
+            _ => (
+                "Route 53 Hosted Zones - Global".to_string(),
+                &ZONE_COLUMNS[..],
+                &ZONE_WIDTHS[..],
+                "hosted zones",
+            ),
+        };
+        let title = list_title(&name, app, view_state, filtered_items.len(), data);
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(f, area, &app.theme, &title, columns, widths);
+            return;
+        }
+
+        if filtered_items.is_empty() {
+            let message = if !view_state.search_filter.is_empty() {
+                format!("No {} match the current filter", noun)
+            } else {
+                format!("No {} found", noun)
+            };
+            render_empty(f, area, &app.theme, &title, &message);
+            return;
+        }
+
+        let rows: Vec<Row> = filtered_items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let cells = if let Some(zone) = item.as_any().downcast_ref::<Route53HostedZone>() {
+                    zone.cells()
+                } else {
+                    item.as_any().downcast_ref::<Route53Record>()?.cells()
+                };
+                let mut cells = cells.into_iter();
+                let name = cells.next().unwrap_or_default();
+                Some(
+                    Row::new(
+                        std::iter::once(Cell::from(highlight_match(
+                            &name,
+                            highlight_pattern(&view_state.search_filter, "name"),
+                        )))
+                        .chain(cells.map(Cell::from)),
+                    )
+                    .style(row_style(app, view_state, i)),
+                )
+            })
+            .collect();
+
+        let table = Table::new(rows, widths.to_vec())
+            .header(header_row(&app.theme, columns))
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_stateful_widget(
+            table,
+            area,
+            &mut table_state(view_state, view_state.selected_index),
+        );
+    }
+
+    fn selected_item<'a, T: 'static>(
+        &self,
+        view_state: &ViewState,
+        data: &'a ResourceData,
+    ) -> Option<&'a T> {
+        self.visible_items(data, view_state)
+            .get(view_state.selected_index)?
+            .as_any()
+            .downcast_ref::<T>()
+    }
+}
+
+#[async_trait]
+impl AwsService for Route53Service {
+    fn metadata(&self) -> ServiceMetadata {
+        ServiceMetadata {
+            id: ROUTE53_SERVICE_ID.to_string(),
+            name: "Route 53".to_string(),
+            description: "DNS hosted zones and their record sets".to_string(),
+            command: "route53".to_string(),
+            aliases: vec!["r53".to_string()],
+            short_label: Some("R53".to_string()),
+            icon: None,
+        }
+    }
+
+    async fn load_data(&self, view_state: &ViewState) -> Result<ResourceData> {
+        let items: Vec<Box<dyn ResourceItem>> = match view_state.view_type {
+            ViewType::List => self
+                .list_hosted_zones()
+                .await?
+                .into_iter()
+                .map(|zone| Box::new(zone) as Box<dyn ResourceItem>)
+                .collect(),
+            ViewType::Detail => match zone_context(view_state) {
+                Some((zone_id, _)) => self
+                    .list_records(zone_id)
+                    .await?
+                    .into_iter()
+                    .map(|record| Box::new(record) as Box<dyn ResourceItem>)
+                    .collect(),
+                None => Vec::new(),
+            },
+            ViewType::Custom(_) => Vec::new(),
+        };
+        Ok(ResourceData::new(items))
+    }
+
+    fn render(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        if !matches!(view_state.view_type, ViewType::Custom(_)) {
+            self.render_table(f, area, app, view_state, data);
+        }
+    }
+
+    fn handle_enter(&self, view_state: &mut ViewState, data: &ResourceData) -> Option<ViewState> {
+        if view_state.view_type != ViewType::List {
+            return None;
+        }
+        let zone = self.selected_item::<Route53HostedZone>(view_state, data)?;
+        let mut new_view = ViewState::new(view_state.service_id.clone(), ViewType::Detail);
+        new_view.context = Some(format!("{}|{}", zone.zone_id, zone.name));
+        Some(new_view)
+    }
+
+    fn get_copy_content(
+        &self,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) -> Option<(String, String)> {
+        match view_state.view_type {
+            ViewType::List => {
+                let zone = self.selected_item::<Route53HostedZone>(view_state, data)?;
+                Some((
+                    zone.zone_id.clone(),
+                    format!("{} ({})", zone.zone_id, zone.name),
+                ))
+            }
+            ViewType::Detail => {
+                let record = self.selected_item::<Route53Record>(view_state, data)?;
+                // One value per line, ready to paste into a zone file or a change batch
+                Some((
+                    record.values.join("\n"),
+                    format!("{} {}", record.name, record.record_type),
+                ))
+            }
+            ViewType::Custom(_) => None,
+        }
+    }
+
+    fn matches_filter(&self, item: &dyn ResourceItem, filter: &str) -> bool {
+        let filter = filter.to_lowercase();
+        if let Some(zone) = item.as_any().downcast_ref::<Route53HostedZone>() {
+            zone.name.to_lowercase().contains(&filter)
+                || zone.zone_id.to_lowercase().contains(&filter)
+        } else if let Some(record) = item.as_any().downcast_ref::<Route53Record>() {
+            record.name.to_lowercase().contains(&filter)
+                || record.value().to_lowercase().contains(&filter)
+        } else {
+            false
+        }
+    }
+
+    fn filter_columns(&self, view_state: &ViewState) -> Vec<&'static str> {
+        match view_state.view_type {
+            ViewType::List => vec!["name", "id", "type", "comment"],
+            ViewType::Detail => vec!["name", "type", "value"],
+            ViewType::Custom(_) => Vec::new(),
+        }
+    }
+
+    fn matches_column(&self, item: &dyn ResourceItem, column: &str, value: &str) -> bool {
+        let value = value.to_lowercase();
+        let field = if let Some(zone) = item.as_any().downcast_ref::<Route53HostedZone>() {
+            match column {
+                "name" => Some(zone.name.clone()),
+                "id" => Some(zone.zone_id.clone()),
+                "type" => zone.cells().into_iter().nth(3),
+                "comment" => zone.comment.clone(),
+                _ => None,
+            }
+        } else if let Some(record) = item.as_any().downcast_ref::<Route53Record>() {
+            match column {
+                "name" => Some(record.name.clone()),
+                "type" => Some(record.record_type.clone()),
+                "value" => Some(record.value()),
+                _ => None,
+            }
+        } else {
+            None
+        };
+        field.is_some_and(|field| field.to_lowercase().contains(&value))
+    }
+
+    fn export_columns(&self, view_state: &ViewState) -> Vec<String> {
+        let columns: &[&str] = match view_state.view_type {
+            ViewType::List => &ZONE_COLUMNS,
+            ViewType::Detail => &RECORD_COLUMNS,
+            ViewType::Custom(_) => &[],
+        };
+        columns.iter().map(|c| c.to_string()).collect()
+    }
+
+    fn export_rows(&self, view_state: &ViewState, data: &ResourceData) -> Vec<Vec<String>> {
+        self.visible_items(data, view_state)
+            .iter()
+            .filter_map(|item| {
+                if let Some(zone) = item.as_any().downcast_ref::<Route53HostedZone>() {
+                    Some(zone.cells())
+                } else {
+                    item.as_any()
+                        .downcast_ref::<Route53Record>()
+                        .map(Route53Record::cells)
+                }
+            })
+            .collect()
+    }
+
+    fn deep_link_path(&self, view_state: &ViewState) -> Option<String> {
+        match view_state.view_type {
+            ViewType::List => Some(String::new()),
+            ViewType::Detail => Some(format!("{}/records", zone_context(view_state)?.0)),
+            ViewType::Custom(_) => None,
+        }
+    }
+
+    fn view_from_deep_link(
+        &self,
+        service_id: &ServiceId,
+        path: &str,
+        cached: Option<&ResourceData>,
+    ) -> Option<ViewState> {
+        if path.is_empty() {
+            return Some(ViewState::new(service_id.clone(), ViewType::List));
+        }
+
+        let zone_id = path.strip_suffix("/records")?;
+        // The link only carries the id; recover the zone's name from the list if loaded
+        let zone_name = cached
+            .into_iter()
+            .flat_map(|data| &data.items)
+            .filter_map(|item| item.as_any().downcast_ref::<Route53HostedZone>())
+            .find(|zone| zone.zone_id == zone_id)
+            .map(|zone| zone.name.as_str())
+            .unwrap_or(zone_id);
+
+        let mut view_state = ViewState::new(service_id.clone(), ViewType::Detail);
+        view_state.context = Some(format!("{}|{}", zone_id, zone_name));
+        Some(view_state)
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.rate_limiter.acquire().await;
+        self.client.get_hosted_zone_count().send().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(values: &[&str], alias: bool) -> Route53Record {
+        Route53Record {
+            name: "api.example.com.".to_string(),
+            record_type: "A".to_string(),
+            ttl: (!alias).then_some(300),
+            values: values.iter().map(|value| value.to_string()).collect(),
+            alias,
+            set_identifier: None,
+        }
+    }
+
+    #[test]
+    fn record_values_show_on_one_line() {
+        assert_eq!(
+            record(&["10.0.0.1", "10.0.0.2"], false).cells(),
+            ["api.example.com.", "A", "300", "10.0.0.1, 10.0.0.2"]
+        );
+        assert_eq!(
+            record(&["lb-123.eu-west-1.elb.amazonaws.com."], true).cells()[2..],
+            ["-", "ALIAS lb-123.eu-west-1.elb.amazonaws.com."]
+        );
+    }
+
+    #[test]
+    fn zone_context_falls_back_to_the_id_for_the_name() {
+        let mut view_state = ViewState::new(ServiceId::new("route53"), ViewType::Detail);
+        view_state.context = Some("Z0123|example.com.".to_string());
+        assert_eq!(zone_context(&view_state), Some(("Z0123", "example.com.")));
+        view_state.context = Some("Z0123".to_string());
+        assert_eq!(zone_context(&view_state), Some(("Z0123", "Z0123")));
+    }
+}
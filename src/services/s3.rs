@@ -0,0 +1,567 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use aws_sdk_s3::{
+    Client,
+    config::Region,
+    types::{Bucket, Object},
+};
+use chrono::{DateTime, Utc};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    widgets::{Block, Borders, Cell, Row, Table},
+};
+use serde::{Deserialize, Serialize};
+use std::{any::Any, sync::Arc};
+
+use super::traits::{
+    AwsService, ResourceData, ResourceItem, ServiceId, ServiceMetadata, ViewState, ViewType,
+    highlight_pattern,
+};
+use crate::app::App;
+use crate::ui::{
+    content::{header_row, list_title, render_empty, row_style, table_state},
+    skeleton::render_skeleton,
+};
+use crate::utils::{rate_limit::RateLimiter, text::highlight_match};
+
+const S3_SERVICE_ID: &str = "s3";
+
+/// Objects are listed in key order; huge buckets are better browsed with the CLI
+const MAX_OBJECTS: usize = 1000;
+
+const BUCKET_COLUMNS: [&str; 3] = ["NAME", "REGION", "CREATED"];
+
+const BUCKET_WIDTHS: [Constraint; 3] = [
+    Constraint::Min(30),    // Name
+    Constraint::Length(16), // Region
+    Constraint::Length(20), // Created
+];
+
+const OBJECT_COLUMNS: [&str; 4] = ["KEY", "SIZE", "LAST MODIFIED", "STORAGE CLASS"];
+
+const OBJECT_WIDTHS: [Constraint; 4] = [
+    Constraint::Min(40),    // Key
+    Constraint::Length(12), // Size
+    Constraint::Length(20), // Last modified
+    Constraint::Length(20), // Storage class
+];
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct S3Bucket {
+    pub name: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub region: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct S3Object {
+    pub key: String,
+    pub size_in_bytes: Option<i64>,
+    pub last_modified: Option<DateTime<Utc>>,
+    pub storage_class: Option<String>,
+}
+
+impl ResourceItem for S3Bucket {
+    fn id(&self) -> String {
+        self.name.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl ResourceItem for S3Object {
+    fn id(&self) -> String {
+        self.key.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+fn to_utc(dt: &aws_sdk_s3::primitives::DateTime) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(dt.secs(), dt.subsec_nanos())
+}
+
+fn format_date(dt: Option<DateTime<Utc>>) -> String {
+    dt.map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn format_size(size: Option<i64>) -> String {
+    match size {
+        Some(size) if size >= 1_048_576 => format!("{:.1} MB", size as f64 / 1_048_576.0),
+        Some(size) if size >= 1024 => format!("{:.1} KB", size as f64 / 1024.0),
+        Some(size) => format!("{} B", size),
+        None => "-".to_string(),
+    }
+}
+
+impl From<&Bucket> for S3Bucket {
+    fn from(bucket: &Bucket) -> Self {
+        Self {
+            name: bucket.name().unwrap_or_default().to_string(),
+            created_at: bucket.creation_date().and_then(to_utc),
+            region: bucket.bucket_region().map(str::to_string),
+        }
+    }
+}
+
+impl From<&Object> for S3Object {
+    fn from(object: &Object) -> Self {
+        Self {
+            key: object.key().unwrap_or_default().to_string(),
+            size_in_bytes: object.size(),
+            last_modified: object.last_modified().and_then(to_utc),
+            storage_class: object
+                .storage_class()
+                .map(|class| class.as_str().to_string()),
+        }
+    }
+}
+
+/// Bucket name and region from a "name|region" view context
+fn bucket_context(view_state: &ViewState) -> Option<(&str, Option<&str>)> {
+    let mut parts = view_state.context.as_deref()?.split('|');
+    let name = parts.next()?;
+    let region = parts.next().filter(|region| !region.is_empty());
+    Some((name, region))
+}
+
+pub struct S3Service {
+    client: Client,
+    rate_limiter: Arc<RateLimiter>,
+}
+
+impl S3Service {
+    pub fn new(client: Client, rate_limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            client,
+            rate_limiter,
+        }
+    }
+
+    pub async fn list_buckets(&self) -> Result<Vec<S3Bucket>> {
+        let mut buckets = Vec::new();
+        let mut continuation_token = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .list_buckets()
+                .set_continuation_token(continuation_token)
+                .send()
+                .await?;
+            buckets.extend(resp.buckets().iter().map(S3Bucket::from));
+
+            continuation_token = resp.continuation_token().map(str::to_string);
+            if continuation_token.is_none() {
+                break;
+            }
+        }
+
+        buckets.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(buckets)
+    }
+
+    /// First objects of a bucket in key order. Buckets outside the configured region
+    /// only answer requests sent to their own region.
+    pub async fn list_objects(&self, bucket: &str, region: Option<&str>) -> Result<Vec<S3Object>> {
+        let current_region = self
+            .client
+            .config()
+            .region()
+            .map(|region| region.to_string());
+        let client = match region {
+            Some(region) if current_region.as_deref() != Some(region) => Client::from_conf(
+                self.client
+                    .config()
+                    .to_builder()
+                    .region(Region::new(region.to_string()))
+                    .build(),
+            ),
+            _ => self.client.clone(),
+        };
+
+        let mut objects = Vec::new();
+        let mut continuation_token = None;
+        while objects.len() < MAX_OBJECTS {
+            self.rate_limiter.acquire().await;
+            let resp = client
+                .list_objects_v2()
+                .bucket(bucket)
+                .set_continuation_token(continuation_token)
+                .send()
+                .await?;
+            objects.extend(resp.contents().iter().map(S3Object::from));
+
+            continuation_token = resp.next_continuation_token().map(str::to_string);
+            if continuation_token.is_none() {
+                break;
+            }
+        }
+
+        objects.truncate(MAX_OBJECTS);
+        Ok(objects)
+    }
+
+    fn render_buckets(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        let filtered_items = self.visible_items(data, view_state);
+        let title = list_title("S3 Buckets", app, view_state, filtered_items.len(), data);
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(f, area, &app.theme, &title, &BUCKET_COLUMNS, &BUCKET_WIDTHS);
+            return;
+        }
+
+        if filtered_items.is_empty() {
+            let message = if !view_state.search_filter.is_empty() {
+                "No buckets match the current filter"
+            } else {
+                "No S3 buckets found"
+            };
+            render_empty(f, area, &app.theme, &title, message);
+            return;
+        }
+
+        let rows: Vec<Row> = filtered_items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let bucket = item.as_any().downcast_ref::<S3Bucket>()?;
+                Some(
+                    Row::new(vec![
+                        Cell::from(highlight_match(
+                            &bucket.name,
+                            highlight_pattern(&view_state.search_filter, "name"),
+                        )),
+                        Cell::from(bucket.region.clone().unwrap_or_else(|| "-".to_string())),
+                        Cell::from(format_date(bucket.created_at)),
+                    ])
+                    .style(row_style(app, view_state, i)),
+                )
+            })
+            .collect();
+
+        let table = Table::new(rows, BUCKET_WIDTHS)
+            .header(header_row(&app.theme, &BUCKET_COLUMNS))
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_stateful_widget(
+            table,
+            area,
+            &mut table_state(view_state, view_state.selected_index),
+        );
+    }
+
+    fn render_objects(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        let filtered_items = self.visible_items(data, view_state);
+        let bucket = bucket_context(view_state)
+            .map(|(name, _)| name)
+            .unwrap_or("Unknown");
+        let title = list_title(
+            &format!("S3 Buckets: {} > Objects", bucket),
+            app,
+            view_state,
+            filtered_items.len(),
+            data,
+        );
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(f, area, &app.theme, &title, &OBJECT_COLUMNS, &OBJECT_WIDTHS);
+            return;
+        }
+
+        if filtered_items.is_empty() {
+            let message = if !view_state.search_filter.is_empty() {
+                "No objects match the current filter"
+            } else {
+                "No objects found"
+            };
+            render_empty(f, area, &app.theme, &title, message);
+            return;
+        }
+
+        let rows: Vec<Row> = filtered_items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let object = item.as_any().downcast_ref::<S3Object>()?;
+                Some(
+                    Row::new(vec![
+                        Cell::from(highlight_match(
+                            &object.key,
+                            highlight_pattern(&view_state.search_filter, "key"),
+                        )),
+                        Cell::from(format_size(object.size_in_bytes)),
+                        Cell::from(format_date(object.last_modified)),
+                        Cell::from(
+                            object
+                                .storage_class
+                                .clone()
+                                .unwrap_or_else(|| "-".to_string()),
+                        ),
+                    ])
+                    .style(row_style(app, view_state, i)),
+                )
+            })
+            .collect();
+
+        let table = Table::new(rows, OBJECT_WIDTHS)
+            .header(header_row(&app.theme, &OBJECT_COLUMNS))
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_stateful_widget(
+            table,
+            area,
+            &mut table_state(view_state, view_state.selected_index),
+        );
+    }
+
+    fn selected_item<'a, T: 'static>(
+        &self,
+        view_state: &ViewState,
+        data: &'a ResourceData,
+    ) -> Option<&'a T> {
+        self.visible_items(data, view_state)
+            .get(view_state.selected_index)?
+            .as_any()
+            .downcast_ref::<T>()
+    }
+}
+
+#[async_trait]
+impl AwsService for S3Service {
+    fn metadata(&self) -> ServiceMetadata {
+        ServiceMetadata {
+            id: S3_SERVICE_ID.to_string(),
+            name: "S3".to_string(),
+            description: "Buckets and the objects in them".to_string(),
+            command: "s3".to_string(),
+            aliases: Vec::new(),
+            short_label: Some("S3".to_string()),
+            icon: None,
+        }
+    }
+
+    async fn load_data(&self, view_state: &ViewState) -> Result<ResourceData> {
+        let items: Vec<Box<dyn ResourceItem>> = match view_state.view_type {
+            ViewType::List => self
+                .list_buckets()
+                .await?
+                .into_iter()
+                .map(|bucket| Box::new(bucket) as Box<dyn ResourceItem>)
+                .collect(),
+            ViewType::Detail => match bucket_context(view_state) {
+                Some((bucket, region)) => self
+                    .list_objects(bucket, region)
+                    .await?
+                    .into_iter()
+                    .map(|object| Box::new(object) as Box<dyn ResourceItem>)
+                    .collect(),
+                None => Vec::new(),
+            },
+            ViewType::Custom(_) => Vec::new(),
+        };
+        Ok(ResourceData::new(items))
+    }
+
+    fn render(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        match view_state.view_type {
+            ViewType::List => self.render_buckets(f, area, app, view_state, data),
+            ViewType::Detail => self.render_objects(f, area, app, view_state, data),
+            ViewType::Custom(_) => {}
+        }
+    }
+
+    fn handle_enter(&self, view_state: &mut ViewState, data: &ResourceData) -> Option<ViewState> {
+        if view_state.view_type != ViewType::List {
+            return None;
+        }
+        let bucket = self.selected_item::<S3Bucket>(view_state, data)?;
+        let mut new_view = ViewState::new(view_state.service_id.clone(), ViewType::Detail);
+        // Context is "name|region"; the region is empty when ListBuckets didn't report it
+        new_view.context = Some(format!(
+            "{}|{}",
+            bucket.name,
+            bucket.region.as_deref().unwrap_or_default()
+        ));
+        Some(new_view)
+    }
+
+    fn get_copy_content(
+        &self,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) -> Option<(String, String)> {
+        match view_state.view_type {
+            ViewType::List => {
+                let bucket = self.selected_item::<S3Bucket>(view_state, data)?;
+                Some((format!("s3://{}", bucket.name), bucket.name.clone()))
+            }
+            ViewType::Detail => {
+                let object = self.selected_item::<S3Object>(view_state, data)?;
+                let (bucket, _) = bucket_context(view_state)?;
+                let uri = format!("s3://{}/{}", bucket, object.key);
+                Some((uri.clone(), uri))
+            }
+            ViewType::Custom(_) => None,
+        }
+    }
+
+    fn matches_filter(&self, item: &dyn ResourceItem, filter: &str) -> bool {
+        let filter = filter.to_lowercase();
+        if let Some(bucket) = item.as_any().downcast_ref::<S3Bucket>() {
+            bucket.name.to_lowercase().contains(&filter)
+        } else if let Some(object) = item.as_any().downcast_ref::<S3Object>() {
+            object.key.to_lowercase().contains(&filter)
+        } else {
+            false
+        }
+    }
+
+    fn filter_columns(&self, view_state: &ViewState) -> Vec<&'static str> {
+        match view_state.view_type {
+            ViewType::List => vec!["name", "region"],
+            ViewType::Detail => vec!["key", "class"],
+            ViewType::Custom(_) => Vec::new(),
+        }
+    }
+
+    fn matches_column(&self, item: &dyn ResourceItem, column: &str, value: &str) -> bool {
+        let field = if let Some(bucket) = item.as_any().downcast_ref::<S3Bucket>() {
+            match column {
+                "name" => Some(bucket.name.as_str()),
+                "region" => bucket.region.as_deref(),
+                _ => None,
+            }
+        } else if let Some(object) = item.as_any().downcast_ref::<S3Object>() {
+            match column {
+                "key" => Some(object.key.as_str()),
+                "class" => object.storage_class.as_deref(),
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        field.is_some_and(|field| field.to_lowercase().contains(&value.to_lowercase()))
+    }
+
+    fn export_columns(&self, view_state: &ViewState) -> Vec<String> {
+        match view_state.view_type {
+            ViewType::List => BUCKET_COLUMNS.iter().map(|c| c.to_string()).collect(),
+            ViewType::Detail => OBJECT_COLUMNS.iter().map(|c| c.to_string()).collect(),
+            ViewType::Custom(_) => Vec::new(),
+        }
+    }
+
+    fn export_rows(&self, view_state: &ViewState, data: &ResourceData) -> Vec<Vec<String>> {
+        self.visible_items(data, view_state)
+            .iter()
+            .filter_map(|item| {
+                if let Some(bucket) = item.as_any().downcast_ref::<S3Bucket>() {
+                    Some(vec![
+                        bucket.name.clone(),
+                        bucket.region.clone().unwrap_or_else(|| "-".to_string()),
+                        format_date(bucket.created_at),
+                    ])
+                } else {
+                    item.as_any().downcast_ref::<S3Object>().map(|object| {
+                        vec![
+                            object.key.clone(),
+                            format_size(object.size_in_bytes),
+                            format_date(object.last_modified),
+                            object
+                                .storage_class
+                                .clone()
+                                .unwrap_or_else(|| "-".to_string()),
+                        ]
+                    })
+                }
+            })
+            .collect()
+    }
+
+    fn deep_link_path(&self, view_state: &ViewState) -> Option<String> {
+        match view_state.view_type {
+            ViewType::List => Some(String::new()),
+            ViewType::Detail => {
+                let (bucket, _) = bucket_context(view_state)?;
+                Some(format!("{}/objects", bucket))
+            }
+            ViewType::Custom(_) => None,
+        }
+    }
+
+    fn view_from_deep_link(
+        &self,
+        service_id: &ServiceId,
+        path: &str,
+        cached: Option<&ResourceData>,
+    ) -> Option<ViewState> {
+        if path.is_empty() {
+            return Some(ViewState::new(service_id.clone(), ViewType::List));
+        }
+
+        let bucket = path.strip_suffix("/objects")?;
+        // The link only carries the name; recover the region from the bucket list if loaded
+        let region = cached
+            .into_iter()
+            .flat_map(|data| &data.items)
+            .filter_map(|item| item.as_any().downcast_ref::<S3Bucket>())
+            .find(|cached_bucket| cached_bucket.name == bucket)
+            .and_then(|cached_bucket| cached_bucket.region.clone())
+            .unwrap_or_default();
+
+        let mut view_state = ViewState::new(service_id.clone(), ViewType::Detail);
+        view_state.context = Some(format!("{}|{}", bucket, region));
+        Some(view_state)
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.rate_limiter.acquire().await;
+        self.client.list_buckets().max_buckets(1).send().await?;
+        Ok(())
+    }
+}
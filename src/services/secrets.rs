@@ -0,0 +1,579 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use aws_sdk_secretsmanager::{Client, error::ProvideErrorMetadata, types::SecretListEntry};
+use chrono::{DateTime, Utc};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    widgets::{Block, Borders, Cell, Row, Table},
+};
+use serde::{Deserialize, Serialize};
+use std::{any::Any, sync::Arc};
+
+use super::traits::{
+    AwsService, ResourceData, ResourceItem, ServiceId, ServiceMetadata, ViewState, ViewType,
+    highlight_pattern,
+};
+use crate::app::App;
+use crate::ui::{
+    content::{header_row, list_title, render_empty, row_style, table_state},
+    skeleton::render_skeleton,
+};
+use crate::utils::{rate_limit::RateLimiter, text::highlight_match};
+
+const SECRETS_SERVICE_ID: &str = "secrets";
+
+/// Shown instead of a secret value until it is revealed with `x`
+const MASK: &str = "********";
+
+/// Field name for secrets stored as plain text rather than a JSON object
+const PLAINTEXT_FIELD: &str = "value";
+
+const SECRET_COLUMNS: [&str; 4] = ["NAME", "DESCRIPTION", "LAST CHANGED", "ROTATION"];
+
+const SECRET_WIDTHS: [Constraint; 4] = [
+    Constraint::Min(30),    // Name
+    Constraint::Min(30),    // Description
+    Constraint::Length(20), // Last changed
+    Constraint::Length(10), // Rotation
+];
+
+const FIELD_COLUMNS: [&str; 2] = ["KEY", "VALUE"];
+
+const FIELD_WIDTHS: [Constraint; 2] = [
+    Constraint::Length(30), // Key
+    Constraint::Min(30),    // Value
+];
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Secret {
+    pub name: String,
+    pub arn: String,
+    pub description: Option<String>,
+    pub last_changed: Option<DateTime<Utc>>,
+    pub rotation_enabled: bool,
+}
+
+/// One key of a JSON secret, or the whole value of a plain-text one
+#[derive(Clone, Debug)]
+pub struct SecretField {
+    pub key: String,
+    pub value: String,
+}
+
+impl ResourceItem for Secret {
+    fn id(&self) -> String {
+        self.name.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl ResourceItem for SecretField {
+    fn id(&self) -> String {
+        self.key.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    // Values stay out of JSON output, which is easy to paste somewhere by accident
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({ "key": self.key, "value": MASK })
+    }
+}
+
+impl From<&SecretListEntry> for Secret {
+    fn from(secret: &SecretListEntry) -> Self {
+        Self {
+            name: secret.name().unwrap_or_default().to_string(),
+            arn: secret.arn().unwrap_or_default().to_string(),
+            description: secret
+                .description()
+                .filter(|description| !description.is_empty())
+                .map(str::to_string),
+            last_changed: secret
+                .last_changed_date()
+                .and_then(|dt| DateTime::from_timestamp(dt.secs(), dt.subsec_nanos())),
+            rotation_enabled: secret.rotation_enabled().unwrap_or(false),
+        }
+    }
+}
+
+impl Secret {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.name.clone(),
+            self.description.clone().unwrap_or_else(|| "-".to_string()),
+            self.last_changed
+                .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            if self.rotation_enabled {
+                "Enabled".to_string()
+            } else {
+                "Disabled".to_string()
+            },
+        ]
+    }
+}
+
+impl SecretField {
+    fn cells(&self, revealed: bool) -> Vec<String> {
+        vec![
+            self.key.clone(),
+            if revealed {
+                self.value.clone()
+            } else {
+                MASK.to_string()
+            },
+        ]
+    }
+}
+
+/// Split a secret string into fields: one per key of a JSON object, else a single
+/// field holding the whole string
+fn parse_secret_fields(secret: &str) -> Vec<SecretField> {
+    match serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(secret) {
+        Ok(object) => object
+            .into_iter()
+            .map(|(key, value)| SecretField {
+                key,
+                value: match value {
+                    serde_json::Value::String(value) => value,
+                    value => value.to_string(),
+                },
+            })
+            .collect(),
+        Err(_) => vec![SecretField {
+            key: PLAINTEXT_FIELD.to_string(),
+            value: secret.to_string(),
+        }],
+    }
+}
+
+pub struct SecretsManagerService {
+    client: Client,
+    rate_limiter: Arc<RateLimiter>,
+}
+
+impl SecretsManagerService {
+    pub fn new(client: Client, rate_limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            client,
+            rate_limiter,
+        }
+    }
+
+    pub async fn list_secrets(&self) -> Result<Vec<Secret>> {
+        let mut secrets = Vec::new();
+        let mut next_token = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .list_secrets()
+                .set_next_token(next_token)
+                .send()
+                .await?;
+            secrets.extend(resp.secret_list().iter().map(Secret::from));
+
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        secrets.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(secrets)
+    }
+
+    pub async fn get_secret_fields(&self, secret_name: &str) -> Result<Vec<SecretField>> {
+        self.rate_limiter.acquire().await;
+        let resp = self
+            .client
+            .get_secret_value()
+            .secret_id(secret_name)
+            .send()
+            .await
+            .map_err(|e| {
+                let code = e.code().map(str::to_string);
+                match code.as_deref() {
+                    // Listing secrets doesn't need the same permissions as reading them
+                    Some("AccessDeniedException") => anyhow!(
+                        "Access denied reading {}: secretsmanager:GetSecretValue (and \
+                         kms:Decrypt on its key) is required",
+                        secret_name
+                    ),
+                    Some("DecryptionFailure") => anyhow!(
+                        "Could not decrypt {}: check access to its KMS key",
+                        secret_name
+                    ),
+                    _ => anyhow!(e),
+                }
+            })?;
+
+        if let Some(secret) = resp.secret_string() {
+            Ok(parse_secret_fields(secret))
+        } else if let Some(binary) = resp.secret_binary() {
+            Ok(vec![SecretField {
+                key: PLAINTEXT_FIELD.to_string(),
+                value: format!("<{} bytes of binary data>", binary.as_ref().len()),
+            }])
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    fn render_secrets(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        let filtered_items = self.visible_items(data, view_state);
+        let title = list_title("Secrets", app, view_state, filtered_items.len(), data);
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(f, area, &app.theme, &title, &SECRET_COLUMNS, &SECRET_WIDTHS);
+            return;
+        }
+
+        if filtered_items.is_empty() {
+            let message = if !view_state.search_filter.is_empty() {
+                "No secrets match the current filter"
+            } else {
+                "No secrets found"
+            };
+            render_empty(f, area, &app.theme, &title, message);
+            return;
+        }
+
+        let rows: Vec<Row> = filtered_items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let secret = item.as_any().downcast_ref::<Secret>()?;
+                let mut cells = secret.cells().into_iter();
+                let name = cells.next().unwrap_or_default();
+                Some(
+                    Row::new(
+                        std::iter::once(Cell::from(highlight_match(
+                            &name,
+                            highlight_pattern(&view_state.search_filter, "name"),
+                        )))
+                        .chain(cells.map(Cell::from)),
+                    )
+                    .style(row_style(app, view_state, i)),
+                )
+            })
+            .collect();
+
+        let table = Table::new(rows, SECRET_WIDTHS)
+            .header(header_row(&app.theme, &SECRET_COLUMNS))
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_stateful_widget(
+            table,
+            area,
+            &mut table_state(view_state, view_state.selected_index),
+        );
+    }
+
+    fn render_fields(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        let filtered_items = self.visible_items(data, view_state);
+        let secret = view_state.context.as_deref().unwrap_or("Unknown");
+        let mut title = list_title(
+            &format!("Secrets: {}", secret),
+            app,
+            view_state,
+            filtered_items.len(),
+            data,
+        );
+        if !view_state.reveal_values {
+            title.push_str(" - Masked, x to reveal");
+        }
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(f, area, &app.theme, &title, &FIELD_COLUMNS, &FIELD_WIDTHS);
+            return;
+        }
+
+        if filtered_items.is_empty() {
+            let message = if !view_state.search_filter.is_empty() {
+                "No keys match the current filter"
+            } else {
+                "This secret has no value"
+            };
+            render_empty(f, area, &app.theme, &title, message);
+            return;
+        }
+
+        let rows: Vec<Row> = filtered_items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let field = item.as_any().downcast_ref::<SecretField>()?;
+                let mut cells = field.cells(view_state.reveal_values).into_iter();
+                let key = cells.next().unwrap_or_default();
+                Some(
+                    Row::new(
+                        std::iter::once(Cell::from(highlight_match(
+                            &key,
+                            highlight_pattern(&view_state.search_filter, "key"),
+                        )))
+                        .chain(cells.map(Cell::from)),
+                    )
+                    .style(row_style(app, view_state, i)),
+                )
+            })
+            .collect();
+
+        let table = Table::new(rows, FIELD_WIDTHS)
+            .header(header_row(&app.theme, &FIELD_COLUMNS))
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_stateful_widget(
+            table,
+            area,
+            &mut table_state(view_state, view_state.selected_index),
+        );
+    }
+
+    fn selected_item<'a, T: 'static>(
+        &self,
+        view_state: &ViewState,
+        data: &'a ResourceData,
+    ) -> Option<&'a T> {
+        self.visible_items(data, view_state)
+            .get(view_state.selected_index)?
+            .as_any()
+            .downcast_ref::<T>()
+    }
+}
+
+#[async_trait]
+impl AwsService for SecretsManagerService {
+    fn metadata(&self) -> ServiceMetadata {
+        ServiceMetadata {
+            id: SECRETS_SERVICE_ID.to_string(),
+            name: "Secrets Manager".to_string(),
+            description: "Secrets, with values masked until revealed".to_string(),
+            command: "secrets".to_string(),
+            aliases: vec!["secretsmanager".to_string()],
+            short_label: Some("Secrets".to_string()),
+            icon: None,
+        }
+    }
+
+    async fn load_data(&self, view_state: &ViewState) -> Result<ResourceData> {
+        let items: Vec<Box<dyn ResourceItem>> = match view_state.view_type {
+            ViewType::List => self
+                .list_secrets()
+                .await?
+                .into_iter()
+                .map(|secret| Box::new(secret) as Box<dyn ResourceItem>)
+                .collect(),
+            ViewType::Detail => match view_state.context.as_deref() {
+                Some(secret_name) => self
+                    .get_secret_fields(secret_name)
+                    .await?
+                    .into_iter()
+                    .map(|field| Box::new(field) as Box<dyn ResourceItem>)
+                    .collect(),
+                None => Vec::new(),
+            },
+            ViewType::Custom(_) => Vec::new(),
+        };
+        Ok(ResourceData::new(items))
+    }
+
+    fn render(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        match view_state.view_type {
+            ViewType::List => self.render_secrets(f, area, app, view_state, data),
+            ViewType::Detail => self.render_fields(f, area, app, view_state, data),
+            ViewType::Custom(_) => {}
+        }
+    }
+
+    fn handle_enter(&self, view_state: &mut ViewState, data: &ResourceData) -> Option<ViewState> {
+        if view_state.view_type != ViewType::List {
+            return None;
+        }
+        let secret = self.selected_item::<Secret>(view_state, data)?;
+        let mut new_view = ViewState::new(view_state.service_id.clone(), ViewType::Detail);
+        new_view.context = Some(secret.name.clone());
+        Some(new_view)
+    }
+
+    fn get_copy_content(
+        &self,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) -> Option<(String, String)> {
+        match view_state.view_type {
+            ViewType::List => {
+                let secret = self.selected_item::<Secret>(view_state, data)?;
+                Some((secret.arn.clone(), secret.name.clone()))
+            }
+            // The real value, even while it's masked on screen
+            ViewType::Detail => {
+                let field = self.selected_item::<SecretField>(view_state, data)?;
+                let secret = view_state.context.as_deref()?;
+                Some((field.value.clone(), format!("{} {}", secret, field.key)))
+            }
+            ViewType::Custom(_) => None,
+        }
+    }
+
+    fn matches_filter(&self, item: &dyn ResourceItem, filter: &str) -> bool {
+        let filter = filter.to_lowercase();
+        if let Some(secret) = item.as_any().downcast_ref::<Secret>() {
+            secret.name.to_lowercase().contains(&filter)
+        } else if let Some(field) = item.as_any().downcast_ref::<SecretField>() {
+            field.key.to_lowercase().contains(&filter)
+        } else {
+            false
+        }
+    }
+
+    fn filter_columns(&self, view_state: &ViewState) -> Vec<&'static str> {
+        match view_state.view_type {
+            ViewType::List => vec!["name", "description"],
+            ViewType::Detail => vec!["key"],
+            ViewType::Custom(_) => Vec::new(),
+        }
+    }
+
+    fn matches_column(&self, item: &dyn ResourceItem, column: &str, value: &str) -> bool {
+        let value = value.to_lowercase();
+        if let Some(secret) = item.as_any().downcast_ref::<Secret>() {
+            let field = match column {
+                "name" => Some(secret.name.as_str()),
+                "description" => secret.description.as_deref(),
+                _ => None,
+            };
+            field.is_some_and(|field| field.to_lowercase().contains(&value))
+        } else if let Some(field) = item.as_any().downcast_ref::<SecretField>() {
+            column == "key" && field.key.to_lowercase().contains(&value)
+        } else {
+            false
+        }
+    }
+
+    fn export_columns(&self, view_state: &ViewState) -> Vec<String> {
+        match view_state.view_type {
+            ViewType::List => SECRET_COLUMNS.iter().map(|c| c.to_string()).collect(),
+            ViewType::Detail => FIELD_COLUMNS.iter().map(|c| c.to_string()).collect(),
+            ViewType::Custom(_) => Vec::new(),
+        }
+    }
+
+    // Masked like the table, so exports and `i` only show values once revealed
+    fn export_rows(&self, view_state: &ViewState, data: &ResourceData) -> Vec<Vec<String>> {
+        self.visible_items(data, view_state)
+            .iter()
+            .filter_map(|item| {
+                if let Some(secret) = item.as_any().downcast_ref::<Secret>() {
+                    Some(secret.cells())
+                } else {
+                    item.as_any()
+                        .downcast_ref::<SecretField>()
+                        .map(|field| field.cells(view_state.reveal_values))
+                }
+            })
+            .collect()
+    }
+
+    fn deep_link_path(&self, view_state: &ViewState) -> Option<String> {
+        match view_state.view_type {
+            ViewType::List => Some(String::new()),
+            ViewType::Detail => Some(format!("{}/value", view_state.context.as_deref()?)),
+            ViewType::Custom(_) => None,
+        }
+    }
+
+    fn view_from_deep_link(
+        &self,
+        service_id: &ServiceId,
+        path: &str,
+        _cached: Option<&ResourceData>,
+    ) -> Option<ViewState> {
+        if path.is_empty() {
+            return Some(ViewState::new(service_id.clone(), ViewType::List));
+        }
+
+        // Secret names may contain slashes themselves, e.g. prod/db/password
+        let secret_name = path.strip_suffix("/value")?;
+        let mut view_state = ViewState::new(service_id.clone(), ViewType::Detail);
+        view_state.context = Some(secret_name.to_string());
+        Some(view_state)
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.rate_limiter.acquire().await;
+        self.client.list_secrets().max_results(1).send().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_secrets_split_into_fields() {
+        let fields = parse_secret_fields(r#"{"username":"admin","port":5432}"#);
+        let pairs: Vec<(&str, &str)> = fields
+            .iter()
+            .map(|field| (field.key.as_str(), field.value.as_str()))
+            .collect();
+        assert_eq!(pairs, vec![("port", "5432"), ("username", "admin")]);
+
+        let fields = parse_secret_fields("hunter2");
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].key, PLAINTEXT_FIELD);
+        assert_eq!(fields[0].value, "hunter2");
+    }
+
+    #[test]
+    fn values_stay_masked_until_revealed() {
+        let field = SecretField {
+            key: "password".to_string(),
+            value: "hunter2".to_string(),
+        };
+        assert_eq!(field.cells(false), vec!["password", MASK]);
+        assert_eq!(field.cells(true), vec!["password", "hunter2"]);
+        assert_eq!(field.to_json()["value"], MASK);
+    }
+}
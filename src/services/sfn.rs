@@ -0,0 +1,564 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use aws_sdk_sfn::{
+    Client,
+    types::{ExecutionListItem, StateMachineListItem},
+};
+use chrono::{DateTime, Utc};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Cell, Row, Table},
+};
+use serde::{Deserialize, Serialize};
+use std::{any::Any, sync::Arc};
+
+use super::traits::{
+    AwsService, ResourceData, ResourceItem, ServiceId, ServiceMetadata, ViewState, ViewType,
+    highlight_pattern,
+};
+use crate::app::App;
+use crate::ui::{
+    content::{header_row, list_title, render_empty, row_style, table_state},
+    skeleton::render_skeleton,
+    theme::Theme,
+};
+use crate::utils::{rate_limit::RateLimiter, text::highlight_match};
+
+const SFN_SERVICE_ID: &str = "sfn";
+
+/// Executions are listed newest first; older ones are rarely useful in a terminal
+const MAX_EXECUTIONS: usize = 200;
+
+const STATE_MACHINE_COLUMNS: [&str; 3] = ["NAME", "TYPE", "CREATED"];
+
+const STATE_MACHINE_WIDTHS: [Constraint; 3] = [
+    Constraint::Min(30),    // Name
+    Constraint::Length(10), // Type
+    Constraint::Length(20), // Created
+];
+
+const EXECUTION_COLUMNS: [&str; 4] = ["NAME", "STATUS", "STARTED", "STOPPED"];
+
+const EXECUTION_WIDTHS: [Constraint; 4] = [
+    Constraint::Min(36),    // Name
+    Constraint::Length(16), // Status
+    Constraint::Length(20), // Started
+    Constraint::Length(20), // Stopped
+];
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StateMachine {
+    pub name: String,
+    pub state_machine_arn: String,
+    pub machine_type: String,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Execution {
+    pub name: String,
+    pub execution_arn: String,
+    pub status: String,
+    pub started_at: Option<DateTime<Utc>>,
+    pub stopped_at: Option<DateTime<Utc>>,
+}
+
+impl ResourceItem for StateMachine {
+    fn id(&self) -> String {
+        self.state_machine_arn.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl ResourceItem for Execution {
+    fn id(&self) -> String {
+        self.execution_arn.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+fn to_utc(dt: &aws_sdk_sfn::primitives::DateTime) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(dt.secs(), dt.subsec_nanos())
+}
+
+fn format_date(dt: Option<DateTime<Utc>>) -> String {
+    dt.map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+impl From<&StateMachineListItem> for StateMachine {
+    fn from(item: &StateMachineListItem) -> Self {
+        Self {
+            name: item.name().to_string(),
+            state_machine_arn: item.state_machine_arn().to_string(),
+            machine_type: item.r#type().as_str().to_string(),
+            created_at: to_utc(item.creation_date()),
+        }
+    }
+}
+
+impl From<&ExecutionListItem> for Execution {
+    fn from(item: &ExecutionListItem) -> Self {
+        Self {
+            name: item.name().to_string(),
+            execution_arn: item.execution_arn().to_string(),
+            status: item.status().as_str().to_string(),
+            started_at: to_utc(item.start_date()),
+            stopped_at: item.stop_date().and_then(to_utc),
+        }
+    }
+}
+
+fn status_color(theme: &Theme, status: &str) -> Color {
+    match status {
+        "SUCCEEDED" => theme.success,
+        "RUNNING" | "PENDING_REDRIVE" => theme.highlight,
+        "FAILED" | "TIMED_OUT" | "ABORTED" => theme.error,
+        _ => theme.muted,
+    }
+}
+
+pub struct StepFunctionsService {
+    client: Client,
+    rate_limiter: Arc<RateLimiter>,
+}
+
+impl StepFunctionsService {
+    pub fn new(client: Client, rate_limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            client,
+            rate_limiter,
+        }
+    }
+
+    pub async fn list_state_machines(&self) -> Result<Vec<StateMachine>> {
+        let mut state_machines = Vec::new();
+        let mut next_token = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .list_state_machines()
+                .set_next_token(next_token)
+                .send()
+                .await?;
+            state_machines.extend(resp.state_machines().iter().map(StateMachine::from));
+
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        state_machines.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(state_machines)
+    }
+
+    /// Most recent executions of a state machine, newest first
+    pub async fn list_executions(&self, state_machine_arn: &str) -> Result<Vec<Execution>> {
+        let mut executions = Vec::new();
+        let mut next_token = None;
+        while executions.len() < MAX_EXECUTIONS {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .list_executions()
+                .state_machine_arn(state_machine_arn)
+                .set_next_token(next_token)
+                .send()
+                .await?;
+            executions.extend(resp.executions().iter().map(Execution::from));
+
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        executions.truncate(MAX_EXECUTIONS);
+        Ok(executions)
+    }
+
+    fn render_state_machines(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        let filtered_items = self.visible_items(data, view_state);
+        let title = list_title(
+            "Step Functions",
+            app,
+            view_state,
+            filtered_items.len(),
+            data,
+        );
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(
+                f,
+                area,
+                &app.theme,
+                &title,
+                &STATE_MACHINE_COLUMNS,
+                &STATE_MACHINE_WIDTHS,
+            );
+            return;
+        }
+
+        if filtered_items.is_empty() {
+            let message = if !view_state.search_filter.is_empty() {
+                "No state machines match the current filter"
+            } else {
+                "No state machines found"
+            };
+            render_empty(f, area, &app.theme, &title, message);
+            return;
+        }
+
+        let rows: Vec<Row> = filtered_items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let machine = item.as_any().downcast_ref::<StateMachine>()?;
+                Some(
+                    Row::new(vec![
+                        Cell::from(highlight_match(
+                            &machine.name,
+                            highlight_pattern(&view_state.search_filter, "name"),
+                        )),
+                        Cell::from(machine.machine_type.clone()),
+                        Cell::from(format_date(machine.created_at)),
+                    ])
+                    .style(row_style(app, view_state, i)),
+                )
+            })
+            .collect();
+
+        let table = Table::new(rows, STATE_MACHINE_WIDTHS)
+            .header(header_row(&app.theme, &STATE_MACHINE_COLUMNS))
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_stateful_widget(
+            table,
+            area,
+            &mut table_state(view_state, view_state.selected_index),
+        );
+    }
+
+    fn render_executions(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        let filtered_items = self.visible_items(data, view_state);
+        let machine_name = view_state
+            .context
+            .as_deref()
+            .and_then(|context| context.split('|').next())
+            .unwrap_or("Unknown");
+        let title = list_title(
+            &format!("Step Functions: {} > Executions", machine_name),
+            app,
+            view_state,
+            filtered_items.len(),
+            data,
+        );
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(
+                f,
+                area,
+                &app.theme,
+                &title,
+                &EXECUTION_COLUMNS,
+                &EXECUTION_WIDTHS,
+            );
+            return;
+        }
+
+        if filtered_items.is_empty() {
+            let message = if !view_state.search_filter.is_empty() {
+                "No executions match the current filter"
+            } else {
+                "No executions found"
+            };
+            render_empty(f, area, &app.theme, &title, message);
+            return;
+        }
+
+        let rows: Vec<Row> = filtered_items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let execution = item.as_any().downcast_ref::<Execution>()?;
+                let selected = i == view_state.selected_index;
+                // Keep the status readable on the selection highlight
+                let status_style = if selected {
+                    Style::default()
+                } else {
+                    Style::default().fg(status_color(&app.theme, &execution.status))
+                };
+                Some(
+                    Row::new(vec![
+                        Cell::from(highlight_match(
+                            &execution.name,
+                            highlight_pattern(&view_state.search_filter, "name"),
+                        )),
+                        Cell::from(execution.status.clone()).style(status_style),
+                        Cell::from(format_date(execution.started_at)),
+                        Cell::from(format_date(execution.stopped_at)),
+                    ])
+                    .style(row_style(app, view_state, i)),
+                )
+            })
+            .collect();
+
+        let table = Table::new(rows, EXECUTION_WIDTHS)
+            .header(header_row(&app.theme, &EXECUTION_COLUMNS))
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_stateful_widget(
+            table,
+            area,
+            &mut table_state(view_state, view_state.selected_index),
+        );
+    }
+
+    fn selected_item<'a, T: 'static>(
+        &self,
+        view_state: &ViewState,
+        data: &'a ResourceData,
+    ) -> Option<&'a T> {
+        self.visible_items(data, view_state)
+            .get(view_state.selected_index)?
+            .as_any()
+            .downcast_ref::<T>()
+    }
+}
+
+#[async_trait]
+impl AwsService for StepFunctionsService {
+    fn metadata(&self) -> ServiceMetadata {
+        ServiceMetadata {
+            id: SFN_SERVICE_ID.to_string(),
+            name: "Step Functions".to_string(),
+            description: "State machines and their executions".to_string(),
+            command: "sfn".to_string(),
+            aliases: vec!["stepfunctions".to_string()],
+            short_label: Some("SFN".to_string()),
+            icon: None,
+        }
+    }
+
+    async fn load_data(&self, view_state: &ViewState) -> Result<ResourceData> {
+        let items: Vec<Box<dyn ResourceItem>> = match view_state.view_type {
+            ViewType::List => self
+                .list_state_machines()
+                .await?
+                .into_iter()
+                .map(|machine| Box::new(machine) as Box<dyn ResourceItem>)
+                .collect(),
+            ViewType::Detail => match &view_state.context {
+                // Context is "name|arn"
+                Some(context) => {
+                    let arn = context.split('|').nth(1).unwrap_or(context);
+                    self.list_executions(arn)
+                        .await?
+                        .into_iter()
+                        .map(|execution| Box::new(execution) as Box<dyn ResourceItem>)
+                        .collect()
+                }
+                None => Vec::new(),
+            },
+            ViewType::Custom(_) => Vec::new(),
+        };
+        Ok(ResourceData::new(items))
+    }
+
+    fn render(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        match view_state.view_type {
+            ViewType::List => self.render_state_machines(f, area, app, view_state, data),
+            ViewType::Detail => self.render_executions(f, area, app, view_state, data),
+            ViewType::Custom(_) => {}
+        }
+    }
+
+    fn handle_enter(&self, view_state: &mut ViewState, data: &ResourceData) -> Option<ViewState> {
+        if view_state.view_type != ViewType::List {
+            return None;
+        }
+        let machine = self.selected_item::<StateMachine>(view_state, data)?;
+        let mut new_view = ViewState::new(view_state.service_id.clone(), ViewType::Detail);
+        new_view.context = Some(format!("{}|{}", machine.name, machine.state_machine_arn));
+        Some(new_view)
+    }
+
+    fn get_copy_content(
+        &self,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) -> Option<(String, String)> {
+        match view_state.view_type {
+            ViewType::List => {
+                let machine = self.selected_item::<StateMachine>(view_state, data)?;
+                Some((machine.state_machine_arn.clone(), machine.name.clone()))
+            }
+            ViewType::Detail => {
+                let execution = self.selected_item::<Execution>(view_state, data)?;
+                Some((execution.execution_arn.clone(), execution.name.clone()))
+            }
+            ViewType::Custom(_) => None,
+        }
+    }
+
+    fn matches_filter(&self, item: &dyn ResourceItem, filter: &str) -> bool {
+        let filter = filter.to_lowercase();
+        if let Some(machine) = item.as_any().downcast_ref::<StateMachine>() {
+            machine.name.to_lowercase().contains(&filter)
+        } else if let Some(execution) = item.as_any().downcast_ref::<Execution>() {
+            execution.name.to_lowercase().contains(&filter)
+                || execution.status.to_lowercase().contains(&filter)
+        } else {
+            false
+        }
+    }
+
+    fn filter_columns(&self, view_state: &ViewState) -> Vec<&'static str> {
+        match view_state.view_type {
+            ViewType::List => vec!["name", "type"],
+            ViewType::Detail => vec!["name", "status"],
+            ViewType::Custom(_) => Vec::new(),
+        }
+    }
+
+    fn matches_column(&self, item: &dyn ResourceItem, column: &str, value: &str) -> bool {
+        let field = if let Some(machine) = item.as_any().downcast_ref::<StateMachine>() {
+            match column {
+                "name" => Some(&machine.name),
+                "type" => Some(&machine.machine_type),
+                _ => None,
+            }
+        } else if let Some(execution) = item.as_any().downcast_ref::<Execution>() {
+            match column {
+                "name" => Some(&execution.name),
+                "status" => Some(&execution.status),
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        field.is_some_and(|field| field.to_lowercase().contains(&value.to_lowercase()))
+    }
+
+    fn export_columns(&self, view_state: &ViewState) -> Vec<String> {
+        match view_state.view_type {
+            ViewType::List => STATE_MACHINE_COLUMNS
+                .iter()
+                .map(|c| c.to_string())
+                .collect(),
+            ViewType::Detail => EXECUTION_COLUMNS.iter().map(|c| c.to_string()).collect(),
+            ViewType::Custom(_) => Vec::new(),
+        }
+    }
+
+    fn export_rows(&self, view_state: &ViewState, data: &ResourceData) -> Vec<Vec<String>> {
+        self.visible_items(data, view_state)
+            .iter()
+            .filter_map(|item| {
+                if let Some(machine) = item.as_any().downcast_ref::<StateMachine>() {
+                    Some(vec![
+                        machine.name.clone(),
+                        machine.machine_type.clone(),
+                        format_date(machine.created_at),
+                    ])
+                } else {
+                    item.as_any().downcast_ref::<Execution>().map(|execution| {
+                        vec![
+                            execution.name.clone(),
+                            execution.status.clone(),
+                            format_date(execution.started_at),
+                            format_date(execution.stopped_at),
+                        ]
+                    })
+                }
+            })
+            .collect()
+    }
+
+    fn deep_link_path(&self, view_state: &ViewState) -> Option<String> {
+        match view_state.view_type {
+            ViewType::List => Some(String::new()),
+            // Executions are listed by ARN, so the link carries it in full
+            ViewType::Detail => {
+                let arn = view_state.context.as_ref()?.split('|').nth(1)?;
+                Some(format!("{}/executions", arn))
+            }
+            ViewType::Custom(_) => None,
+        }
+    }
+
+    fn view_from_deep_link(
+        &self,
+        service_id: &ServiceId,
+        path: &str,
+        _cached: Option<&ResourceData>,
+    ) -> Option<ViewState> {
+        if path.is_empty() {
+            return Some(ViewState::new(service_id.clone(), ViewType::List));
+        }
+
+        let arn = path.strip_suffix("/executions")?;
+        let name = arn.rsplit(':').next()?;
+        let mut view_state = ViewState::new(service_id.clone(), ViewType::Detail);
+        view_state.context = Some(format!("{}|{}", name, arn));
+        Some(view_state)
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.rate_limiter.acquire().await;
+        self.client
+            .list_state_machines()
+            .max_results(1)
+            .send()
+            .await?;
+        Ok(())
+    }
+}
@@ -2,11 +2,82 @@ use anyhow::Result;
 use async_trait::async_trait;
 use ratatui::{Frame, layout::Rect};
 use serde::{Deserialize, Serialize};
-use std::any::Any;
+use std::{
+    any::Any,
+    collections::HashSet,
+    sync::{
+        Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+};
 
 use crate::app::App;
+use crate::utils::fuzzy::fuzzy_score;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// How a column search matches the column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterOp {
+    Contains, // column:value
+    Greater,  // column>value
+    Less,     // column<value
+}
+
+impl FilterOp {
+    /// Whether a column whose `compare_key` is `key` passes a `>` or `<` search for
+    /// `threshold`
+    pub fn accepts(self, key: f64, threshold: f64) -> bool {
+        match self {
+            FilterOp::Contains => false,
+            FilterOp::Greater => key > threshold,
+            FilterOp::Less => key < threshold,
+        }
+    }
+}
+
+/// A search targeting one column, e.g. `tag:prod` or `size>500`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnFilter<'a> {
+    pub column: &'a str,
+    pub op: FilterOp,
+    pub value: &'a str,
+}
+
+/// Split a `column:value`, `column>value`, or `column<value` search into its parts.
+/// Plain searches return `None`.
+pub fn parse_column_filter(filter: &str) -> Option<ColumnFilter<'_>> {
+    let (column, rest) = filter.split_at(filter.find([':', '>', '<'])?);
+    let op = match rest.as_bytes()[0] {
+        b':' => FilterOp::Contains,
+        b'>' => FilterOp::Greater,
+        _ => FilterOp::Less,
+    };
+    let is_column_name = !column.is_empty()
+        && column
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    is_column_name.then_some(ColumnFilter {
+        column,
+        op,
+        value: &rest[1..],
+    })
+}
+
+/// The part of a search to highlight in `column`: the value when the search looks for
+/// it in that column, nothing when it targets another column or compares, and the
+/// whole search otherwise
+pub fn highlight_pattern<'a>(filter: &'a str, column: &str) -> &'a str {
+    match parse_column_filter(filter) {
+        Some(target)
+            if target.op == FilterOp::Contains && target.column.eq_ignore_ascii_case(column) =>
+        {
+            target.value
+        }
+        Some(_) => "",
+        None => filter,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ServiceId(pub String);
 
 impl ServiceId {
@@ -27,12 +98,28 @@ pub struct ServiceMetadata {
     pub name: String,
     pub description: String,
     pub command: String,
+    pub aliases: Vec<String>,        // Other commands that open the service
+    pub short_label: Option<String>, // Compact name for the header and breadcrumb
+    pub icon: Option<String>,        // Glyph shown before the short label
+}
+
+impl ServiceMetadata {
+    /// Icon and short label for orientation, falling back to the service id
+    pub fn display_label(&self) -> String {
+        let label = self.short_label.as_deref().unwrap_or(&self.id);
+        match &self.icon {
+            Some(icon) => format!("{} {}", icon, label),
+            None => label.to_string(),
+        }
+    }
 }
 
 pub trait ResourceItem: Send + Sync + std::fmt::Debug {
     fn id(&self) -> String;
     fn as_any(&self) -> &dyn Any;
     fn clone_box(&self) -> Box<dyn ResourceItem>;
+    /// JSON representation used by the non-interactive `--output json` mode
+    fn to_json(&self) -> serde_json::Value;
 }
 
 impl Clone for Box<dyn ResourceItem> {
@@ -44,15 +131,105 @@ impl Clone for Box<dyn ResourceItem> {
 #[derive(Debug, Clone)]
 pub struct ResourceData {
     pub items: Vec<Box<dyn ResourceItem>>,
+    generation: u64, // Tells loads apart, so cached filter results go stale with their data
 }
 
-#[derive(Debug, Clone, PartialEq)]
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+impl ResourceData {
+    pub fn new(items: Vec<Box<dyn ResourceItem>>) -> Self {
+        Self {
+            items,
+            generation: NEXT_GENERATION.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::Value::Array(self.items.iter().map(|item| item.to_json()).collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ViewType {
     List,
     Detail,
     Custom(String),
 }
 
+/// A service-specific operation on the current view, bound to a key
+#[derive(Debug, Clone)]
+pub struct ServiceAction {
+    pub id: String,
+    pub key: char,
+    pub description: String,
+    pub prompt: Option<String>, // Ask for a text argument before running
+}
+
+/// What a destructive action is about to affect, used to pick how hard to confirm it
+#[derive(Debug, Clone)]
+pub struct Confirmation {
+    pub item_count: usize,
+    pub target: String, // Name typed to confirm large batches, e.g. the repository
+}
+
+/// What a table column sorts by: sizes and timestamps as numbers, the rest as text
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum SortValue {
+    Number(f64),
+    Text(String),
+}
+
+/// What a cached `visible_items` result was computed from
+#[derive(Debug, Clone, PartialEq)]
+struct VisibleKey {
+    generation: u64,
+    filter: String,
+    fuzzy: bool,
+    grouped: bool,
+    sort: Option<(usize, bool)>,
+}
+
+impl VisibleKey {
+    fn new(data: &ResourceData, view_state: &ViewState) -> Self {
+        Self {
+            generation: data.generation,
+            filter: view_state.search_filter.clone(),
+            fuzzy: view_state.fuzzy_search,
+            grouped: view_state.grouped,
+            sort: view_state
+                .sort_key
+                .map(|column| (column, view_state.sort_ascending)),
+        }
+    }
+}
+
+/// Positions of the items the last `visible_items` call returned, so redraws and
+/// navigation don't filter a large list again until the search or the data change
+#[derive(Debug, Default)]
+pub struct VisibleCache(Mutex<Option<(VisibleKey, Vec<usize>)>>);
+
+impl VisibleCache {
+    fn get(&self, key: &VisibleKey) -> Option<Vec<usize>> {
+        let cached = self.0.lock().ok()?;
+        let (cached_key, positions) = cached.as_ref()?;
+        (cached_key == key).then(|| positions.clone())
+    }
+
+    fn store(&self, key: VisibleKey, positions: Vec<usize>) {
+        if let Ok(mut cached) = self.0.lock() {
+            *cached = Some((key, positions));
+        }
+    }
+}
+
+impl Clone for VisibleCache {
+    fn clone(&self) -> Self {
+        Self(Mutex::new(
+            self.0.lock().ok().and_then(|cached| cached.clone()),
+        ))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ViewState {
     pub service_id: ServiceId,
@@ -60,6 +237,16 @@ pub struct ViewState {
     pub selected_index: usize,
     pub search_filter: String,
     pub context: Option<String>, // For drill-down context (e.g., repository name)
+    pub grouped: bool,           // Cluster items by the service's group key
+    pub wrap_cells: bool,        // Wrap long cell values onto extra lines instead of cutting them
+    pub column_cursor: usize,    // Display position of the cell copied with `y`
+    pub scroll_offset: usize,    // First table row shown, kept so the selection stays visible
+    pub selected_items: HashSet<usize>, // Visible rows marked with Space for bulk copy
+    pub fuzzy_search: bool,      // Match searches as subsequences, best matches first
+    pub reveal_values: bool,     // Show values a service masks by default, e.g. secrets
+    pub sort_key: Option<usize>, // `export_columns` index the table is sorted by, picked with 1-9
+    pub sort_ascending: bool,
+    pub visible_cache: VisibleCache, // Last `visible_items` result, reused while nothing changed
 }
 
 impl ViewState {
@@ -70,6 +257,16 @@ impl ViewState {
             selected_index: 0,
             search_filter: String::new(),
             context: None,
+            grouped: false,
+            wrap_cells: false,
+            column_cursor: 0,
+            scroll_offset: 0,
+            selected_items: HashSet::new(),
+            fuzzy_search: false,
+            reveal_values: false,
+            sort_key: None,
+            sort_ascending: true,
+            visible_cache: VisibleCache::default(),
         }
     }
 }
@@ -97,23 +294,275 @@ pub trait AwsService: Send + Sync {
         data: &ResourceData,
     ) -> Option<(String, String)>;
 
+    /// Items matching `filter`, each with its position in `data.items`
     #[allow(clippy::borrowed_box)]
     fn filter_data<'a>(
         &self,
         data: &'a ResourceData,
         filter: &str,
-    ) -> Vec<&'a Box<dyn ResourceItem>> {
+    ) -> Vec<(usize, &'a Box<dyn ResourceItem>)> {
         if filter.is_empty() {
-            data.items.iter().collect()
-        } else {
-            data.items
+            return data.items.iter().enumerate().collect();
+        }
+
+        match parse_column_filter(filter) {
+            Some(ColumnFilter {
+                column,
+                op: FilterOp::Contains,
+                value,
+            }) => data
+                .items
                 .iter()
-                .filter(|item| self.matches_filter(item.as_ref(), filter))
-                .collect()
+                .enumerate()
+                .filter(|(_, item)| {
+                    self.matches_column(item.as_ref(), &column.to_lowercase(), value)
+                })
+                .collect(),
+            Some(ColumnFilter { column, op, value }) => {
+                let column = column.to_lowercase();
+                // A malformed comparison matches nothing; the input bar says why
+                let Ok(threshold) = self.parse_compare_value(&column, value) else {
+                    return Vec::new();
+                };
+                data.items
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, item)| {
+                        self.compare_key(item.as_ref(), &column)
+                            .is_some_and(|key| op.accepts(key, threshold))
+                    })
+                    .collect()
+            }
+            None => data
+                .items
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| self.matches_filter(item.as_ref(), filter))
+                .collect(),
         }
     }
 
     fn matches_filter(&self, _item: &dyn ResourceItem, _filter: &str) -> bool {
         true // Default implementation - override in service implementations
     }
+
+    /// Column names accepted by `column:value` searches in this view
+    fn filter_columns(&self, _view_state: &ViewState) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    /// Whether `item`'s `column` (lowercased) contains `value`. Unknown columns match nothing.
+    fn matches_column(&self, _item: &dyn ResourceItem, _column: &str, _value: &str) -> bool {
+        false
+    }
+
+    /// Number `column>value` and `column<value` searches compare `item`'s `column` by,
+    /// e.g. a size in bytes or a timestamp. `None` leaves the item out.
+    fn compare_key(&self, _item: &dyn ResourceItem, _column: &str) -> Option<f64> {
+        None
+    }
+
+    /// The value of a `column>value` or `column<value` search in `compare_key`'s units,
+    /// or why it can't be read. Shown in the input bar while typing.
+    fn parse_compare_value(&self, column: &str, _value: &str) -> Result<f64, String> {
+        Err(format!("{} can't be compared with > or <", column))
+    }
+
+    /// Text fuzzy searches score an item by, usually its name. Items without one
+    /// fall back to `matches_filter`.
+    fn fuzzy_key(&self, _item: &dyn ResourceItem) -> Option<String> {
+        None
+    }
+
+    /// Items whose `fuzzy_key` contains `filter` as a subsequence, best matches first.
+    /// Column searches keep matching by substring.
+    #[allow(clippy::borrowed_box)]
+    fn fuzzy_filter_data<'a>(
+        &self,
+        data: &'a ResourceData,
+        filter: &str,
+    ) -> Vec<(usize, &'a Box<dyn ResourceItem>)> {
+        if filter.is_empty() || parse_column_filter(filter).is_some() {
+            return self.filter_data(data, filter);
+        }
+
+        let mut scored: Vec<(i64, (usize, &Box<dyn ResourceItem>))> = data
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let score = match self.fuzzy_key(item.as_ref()) {
+                    Some(key) => fuzzy_score(&key, filter)?,
+                    None => self.matches_filter(item.as_ref(), filter).then_some(0)?,
+                };
+                Some((score, (i, item)))
+            })
+            .collect();
+        // Stable, so equally good matches keep the service's order
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        scored.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    /// Items shown in the current view, in display order. Selection indices, copy,
+    /// and drill-down all refer to positions in this list.
+    #[allow(clippy::borrowed_box)]
+    fn visible_items<'a>(
+        &self,
+        data: &'a ResourceData,
+        view_state: &ViewState,
+    ) -> Vec<&'a Box<dyn ResourceItem>> {
+        let key = VisibleKey::new(data, view_state);
+        if let Some(positions) = view_state.visible_cache.get(&key) {
+            return positions
+                .into_iter()
+                .filter_map(|i| data.items.get(i))
+                .collect();
+        }
+
+        let mut items = if view_state.fuzzy_search {
+            self.fuzzy_filter_data(data, &view_state.search_filter)
+        } else {
+            self.filter_data(data, &view_state.search_filter)
+        };
+        if let Some(column) = view_state.sort_key {
+            let mut keyed: Vec<_> = items
+                .into_iter()
+                .map(|(i, item)| (self.sort_value(item.as_ref(), column), (i, item)))
+                .collect();
+            // Stable, so ties keep the service's order. Items without a value go last.
+            keyed.sort_by(|(a, _), (b, _)| match (a, b) {
+                (Some(a), Some(b)) => {
+                    let ordering = a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal);
+                    if view_state.sort_ascending {
+                        ordering
+                    } else {
+                        ordering.reverse()
+                    }
+                }
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            });
+            items = keyed.into_iter().map(|(_, entry)| entry).collect();
+        }
+        if view_state.grouped {
+            // Stable, so items keep their order within a group
+            items.sort_by_cached_key(|(_, item)| self.group_key(item.as_ref()));
+        }
+        view_state
+            .visible_cache
+            .store(key, items.iter().map(|(i, _)| *i).collect());
+        items.into_iter().map(|(_, item)| item).collect()
+    }
+
+    /// Column names for tabular export of the current view
+    fn export_columns(&self, _view_state: &ViewState) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Plain-text cell values for each visible item, matching `export_columns`
+    fn export_rows(&self, _view_state: &ViewState, _data: &ResourceData) -> Vec<Vec<String>> {
+        Vec::new()
+    }
+
+    /// What `item` sorts by in `column`, an index into `export_columns`, when the
+    /// table is sorted with the number keys. Columns without values can't be sorted by.
+    fn sort_value(&self, _item: &dyn ResourceItem, _column: usize) -> Option<SortValue> {
+        None
+    }
+
+    /// Key used to cluster items when grouping is enabled with `:group`
+    fn group_key(&self, _item: &dyn ResourceItem) -> Option<String> {
+        None
+    }
+
+    /// Alternate copy formats for the selected item (e.g. an ARN or CLI command),
+    /// resolved through `get_copy_content_for`
+    fn copy_actions(&self, _view_state: &ViewState) -> Vec<ServiceAction> {
+        Vec::new()
+    }
+
+    fn get_copy_content_for(
+        &self,
+        _action_id: &str,
+        _view_state: &ViewState,
+        _data: &ResourceData,
+    ) -> Option<(String, String)> {
+        None
+    }
+
+    /// Placeholder values of the selected item for the `:copyfmt` template, e.g.
+    /// `("uri", ...)`. Services without any keep copying `get_copy_content`.
+    fn copy_fields(&self, _view_state: &ViewState, _data: &ResourceData) -> Vec<(&str, String)> {
+        Vec::new()
+    }
+
+    fn actions(&self, _view_state: &ViewState) -> Vec<ServiceAction> {
+        Vec::new() // No service-specific actions by default
+    }
+
+    /// Destructive actions return what they would affect so the app can ask for confirmation
+    fn confirmation(
+        &self,
+        _action_id: &str,
+        _view_state: &ViewState,
+        _data: &ResourceData,
+    ) -> Option<Confirmation> {
+        None
+    }
+
+    /// Run the action with the given id against the current view, returning a
+    /// short status message on success
+    async fn run_action(
+        &self,
+        action_id: &str,
+        _view_state: &ViewState,
+        _data: &ResourceData,
+        _input: Option<&str>,
+    ) -> Result<String> {
+        Err(anyhow::anyhow!("Action '{}' is not supported", action_id))
+    }
+
+    /// Every field of the selected item as key/value pairs for the `i` popup. Empty
+    /// falls back to the selected row's table cells.
+    fn detail_lines(&self, _view_state: &ViewState, _data: &ResourceData) -> Vec<(String, String)> {
+        Vec::new()
+    }
+
+    /// The selected item as pretty-printed JSON, printed to stdout on exit with Ctrl-y
+    fn selected_json(&self, view_state: &ViewState, data: &ResourceData) -> Option<String> {
+        let item = self
+            .visible_items(data, view_state)
+            .into_iter()
+            .nth(view_state.selected_index)?;
+        serde_json::to_string_pretty(&item.to_json()).ok()
+    }
+
+    /// AWS console page for the selected item, opened in a browser with `o`
+    fn console_url(&self, _view_state: &ViewState, _data: &ResourceData) -> Option<String> {
+        None
+    }
+
+    /// Path identifying `view_state` in an `ats://<service>/<path>` link, or `None` if
+    /// the view can't be linked
+    fn deep_link_path(&self, view_state: &ViewState) -> Option<String> {
+        (view_state.view_type == ViewType::List).then(String::new)
+    }
+
+    /// View for the path of an `ats://` link. `cached` is the service's last loaded
+    /// data, which may help fill in context the path leaves out.
+    fn view_from_deep_link(
+        &self,
+        service_id: &ServiceId,
+        path: &str,
+        _cached: Option<&ResourceData>,
+    ) -> Option<ViewState> {
+        path.is_empty()
+            .then(|| ViewState::new(service_id.clone(), ViewType::List))
+    }
+
+    /// Issue a lightweight request to check the service's API is reachable
+    async fn health_check(&self) -> Result<()> {
+        Err(anyhow::anyhow!("Health check is not supported"))
+    }
 }
@@ -0,0 +1,847 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use aws_sdk_ec2::{
+    Client,
+    types::{Filter, RouteTable, SecurityGroup, Subnet, Tag, Vpc},
+};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    widgets::{Block, Borders, Cell, Row, Table},
+};
+use serde::{Deserialize, Serialize};
+use std::{any::Any, sync::Arc};
+
+use super::traits::{
+    AwsService, ResourceData, ResourceItem, ServiceId, ServiceMetadata, ViewState, ViewType,
+    highlight_pattern,
+};
+use crate::app::App;
+use crate::ui::{
+    content::{header_row, list_title, render_empty, row_style, table_state},
+    skeleton::render_skeleton,
+};
+use crate::utils::{rate_limit::RateLimiter, text::highlight_match};
+
+const VPC_SERVICE_ID: &str = "vpc";
+
+/// Custom views, opened with `:vpc subnets` or, for one VPC, `:vpc <vpc-id>/subnets`
+const SUBNETS_VIEW: &str = "subnets";
+const ROUTE_TABLES_VIEW: &str = "route-tables";
+const SECURITY_GROUPS_VIEW: &str = "security-groups";
+const NETWORK_VIEWS: [&str; 3] = [SUBNETS_VIEW, ROUTE_TABLES_VIEW, SECURITY_GROUPS_VIEW];
+
+const VPC_COLUMNS: [&str; 5] = ["VPC ID", "NAME", "CIDR", "DEFAULT", "STATE"];
+
+const VPC_WIDTHS: [Constraint; 5] = [
+    Constraint::Length(22), // VPC id
+    Constraint::Min(24),    // Name
+    Constraint::Length(18), // CIDR
+    Constraint::Length(8),  // Default
+    Constraint::Length(10), // State
+];
+
+const SUBNET_COLUMNS: [&str; 6] = ["SUBNET ID", "NAME", "VPC ID", "CIDR", "ZONE", "FREE IPS"];
+
+const SUBNET_WIDTHS: [Constraint; 6] = [
+    Constraint::Length(25), // Subnet id
+    Constraint::Min(24),    // Name
+    Constraint::Length(22), // VPC id
+    Constraint::Length(18), // CIDR
+    Constraint::Length(14), // Availability zone
+    Constraint::Length(9),  // Available IPs
+];
+
+const ROUTE_TABLE_COLUMNS: [&str; 6] = [
+    "ROUTE TABLE ID",
+    "NAME",
+    "VPC ID",
+    "MAIN",
+    "ROUTES",
+    "SUBNETS",
+];
+
+const ROUTE_TABLE_WIDTHS: [Constraint; 6] = [
+    Constraint::Length(22), // Route table id
+    Constraint::Min(24),    // Name
+    Constraint::Length(22), // VPC id
+    Constraint::Length(5),  // Main
+    Constraint::Length(7),  // Routes
+    Constraint::Length(8),  // Associated subnets
+];
+
+const SECURITY_GROUP_COLUMNS: [&str; 6] = [
+    "GROUP ID",
+    "NAME",
+    "VPC ID",
+    "INBOUND",
+    "OUTBOUND",
+    "DESCRIPTION",
+];
+
+const SECURITY_GROUP_WIDTHS: [Constraint; 6] = [
+    Constraint::Length(21), // Group id
+    Constraint::Min(24),    // Name
+    Constraint::Length(22), // VPC id
+    Constraint::Length(8),  // Inbound rules
+    Constraint::Length(9),  // Outbound rules
+    Constraint::Min(30),    // Description
+];
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct VPCItem {
+    pub vpc_id: String,
+    pub name: Option<String>, // Value of the `Name` tag
+    pub cidr_block: String,
+    pub is_default: bool,
+    pub state: String, // pending or available
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SubnetItem {
+    pub subnet_id: String,
+    pub name: Option<String>,
+    pub vpc_id: String,
+    pub cidr_block: String,
+    pub availability_zone: String,
+    pub available_ips: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RouteTableItem {
+    pub route_table_id: String,
+    pub name: Option<String>,
+    pub vpc_id: String,
+    pub main: bool, // Used by subnets without an explicit association
+    pub route_count: usize,
+    pub subnet_count: usize,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SecurityGroupItem {
+    pub group_id: String,
+    pub group_name: String,
+    pub vpc_id: String,
+    pub inbound_rules: usize,
+    pub outbound_rules: usize,
+    pub description: String,
+}
+
+impl ResourceItem for VPCItem {
+    fn id(&self) -> String {
+        self.vpc_id.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl ResourceItem for SubnetItem {
+    fn id(&self) -> String {
+        self.subnet_id.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl ResourceItem for RouteTableItem {
+    fn id(&self) -> String {
+        self.route_table_id.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl ResourceItem for SecurityGroupItem {
+    fn id(&self) -> String {
+        self.group_id.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn ResourceItem> {
+        Box::new(self.clone())
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+fn name_tag(tags: &[Tag]) -> Option<String> {
+    tags.iter()
+        .find(|tag| tag.key() == Some("Name"))
+        .and_then(|tag| tag.value())
+        .map(str::to_string)
+}
+
+fn or_dash(value: &Option<String>) -> String {
+    value.clone().unwrap_or_else(|| "-".to_string())
+}
+
+impl From<&Vpc> for VPCItem {
+    fn from(vpc: &Vpc) -> Self {
+        Self {
+            vpc_id: vpc.vpc_id().unwrap_or_default().to_string(),
+            name: name_tag(vpc.tags()),
+            cidr_block: vpc.cidr_block().unwrap_or_default().to_string(),
+            is_default: vpc.is_default().unwrap_or(false),
+            state: vpc
+                .state()
+                .map(|state| state.as_str().to_string())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl From<&Subnet> for SubnetItem {
+    fn from(subnet: &Subnet) -> Self {
+        Self {
+            subnet_id: subnet.subnet_id().unwrap_or_default().to_string(),
+            name: name_tag(subnet.tags()),
+            vpc_id: subnet.vpc_id().unwrap_or_default().to_string(),
+            cidr_block: subnet.cidr_block().unwrap_or_default().to_string(),
+            availability_zone: subnet.availability_zone().unwrap_or_default().to_string(),
+            available_ips: subnet.available_ip_address_count(),
+        }
+    }
+}
+
+impl From<&RouteTable> for RouteTableItem {
+    fn from(table: &RouteTable) -> Self {
+        let associations = table.associations();
+        Self {
+            route_table_id: table.route_table_id().unwrap_or_default().to_string(),
+            name: name_tag(table.tags()),
+            vpc_id: table.vpc_id().unwrap_or_default().to_string(),
+            main: associations
+                .iter()
+                .any(|association| association.main().unwrap_or(false)),
+            route_count: table.routes().len(),
+            subnet_count: associations
+                .iter()
+                .filter(|association| association.subnet_id().is_some())
+                .count(),
+        }
+    }
+}
+
+impl From<&SecurityGroup> for SecurityGroupItem {
+    fn from(group: &SecurityGroup) -> Self {
+        Self {
+            group_id: group.group_id().unwrap_or_default().to_string(),
+            group_name: group.group_name().unwrap_or_default().to_string(),
+            vpc_id: group.vpc_id().unwrap_or_default().to_string(),
+            inbound_rules: group.ip_permissions().len(),
+            outbound_rules: group.ip_permissions_egress().len(),
+            description: group.description().unwrap_or_default().to_string(),
+        }
+    }
+}
+
+/// What every networking row offers the shared table, search, and export code
+trait NetworkItem {
+    fn cells(&self) -> Vec<String>;
+
+    /// Value of a `column:value` search column, or `None` for unknown columns
+    fn field(&self, column: &str) -> Option<&str>;
+}
+
+impl NetworkItem for VPCItem {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.vpc_id.clone(),
+            or_dash(&self.name),
+            self.cidr_block.clone(),
+            if self.is_default { "Yes" } else { "No" }.to_string(),
+            self.state.clone(),
+        ]
+    }
+
+    fn field(&self, column: &str) -> Option<&str> {
+        match column {
+            "id" => Some(&self.vpc_id),
+            "name" => self.name.as_deref(),
+            "cidr" => Some(&self.cidr_block),
+            "state" => Some(&self.state),
+            _ => None,
+        }
+    }
+}
+
+impl NetworkItem for SubnetItem {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.subnet_id.clone(),
+            or_dash(&self.name),
+            self.vpc_id.clone(),
+            self.cidr_block.clone(),
+            self.availability_zone.clone(),
+            self.available_ips
+                .map(|count| count.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+        ]
+    }
+
+    fn field(&self, column: &str) -> Option<&str> {
+        match column {
+            "id" => Some(&self.subnet_id),
+            "name" => self.name.as_deref(),
+            "vpc" => Some(&self.vpc_id),
+            "cidr" => Some(&self.cidr_block),
+            "zone" => Some(&self.availability_zone),
+            _ => None,
+        }
+    }
+}
+
+impl NetworkItem for RouteTableItem {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.route_table_id.clone(),
+            or_dash(&self.name),
+            self.vpc_id.clone(),
+            if self.main { "Yes" } else { "No" }.to_string(),
+            self.route_count.to_string(),
+            self.subnet_count.to_string(),
+        ]
+    }
+
+    fn field(&self, column: &str) -> Option<&str> {
+        match column {
+            "id" => Some(&self.route_table_id),
+            "name" => self.name.as_deref(),
+            "vpc" => Some(&self.vpc_id),
+            _ => None,
+        }
+    }
+}
+
+impl NetworkItem for SecurityGroupItem {
+    fn cells(&self) -> Vec<String> {
+        vec![
+            self.group_id.clone(),
+            self.group_name.clone(),
+            self.vpc_id.clone(),
+            self.inbound_rules.to_string(),
+            self.outbound_rules.to_string(),
+            self.description.clone(),
+        ]
+    }
+
+    fn field(&self, column: &str) -> Option<&str> {
+        match column {
+            "id" => Some(&self.group_id),
+            "name" => Some(&self.group_name),
+            "vpc" => Some(&self.vpc_id),
+            "description" => Some(&self.description),
+            _ => None,
+        }
+    }
+}
+
+fn network_item(item: &dyn ResourceItem) -> Option<&dyn NetworkItem> {
+    let item = item.as_any();
+    if let Some(vpc) = item.downcast_ref::<VPCItem>() {
+        Some(vpc)
+    } else if let Some(subnet) = item.downcast_ref::<SubnetItem>() {
+        Some(subnet)
+    } else if let Some(table) = item.downcast_ref::<RouteTableItem>() {
+        Some(table)
+    } else {
+        item.downcast_ref::<SecurityGroupItem>()
+            .map(|group| group as &dyn NetworkItem)
+    }
+}
+
+/// Limits a describe call to one VPC when a view is opened for it
+fn vpc_filter(vpc_id: Option<&str>) -> Option<Vec<Filter>> {
+    vpc_id.map(|vpc_id| vec![Filter::builder().name("vpc-id").values(vpc_id).build()])
+}
+
+/// View named by an `ats://vpc/...` path: empty for the VPC list, `<view>` for
+/// every VPC's subnets, route tables, or security groups, `<vpc-id>/<view>` for one
+fn parse_view_path(path: &str) -> Option<(Option<&str>, ViewType)> {
+    if path.is_empty() {
+        return Some((None, ViewType::List));
+    }
+    let (vpc_id, view) = match path.rsplit_once('/') {
+        Some((vpc_id, view)) => (Some(vpc_id), view),
+        None => (None, path),
+    };
+    NETWORK_VIEWS
+        .contains(&view)
+        .then(|| (vpc_id, ViewType::Custom(view.to_string())))
+}
+
+pub struct VPCService {
+    client: Client,
+    rate_limiter: Arc<RateLimiter>,
+}
+
+impl VPCService {
+    pub fn new(client: Client, rate_limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            client,
+            rate_limiter,
+        }
+    }
+
+    pub async fn list_vpcs(&self) -> Result<Vec<VPCItem>> {
+        let mut vpcs = Vec::new();
+        let mut next_token = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .describe_vpcs()
+                .set_next_token(next_token)
+                .send()
+                .await?;
+            vpcs.extend(resp.vpcs().iter().map(VPCItem::from));
+
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        // Named VPCs first, by name; unnamed ones after, by id
+        vpcs.sort_by(|a, b| {
+            (a.name.is_none(), &a.name, &a.vpc_id).cmp(&(b.name.is_none(), &b.name, &b.vpc_id))
+        });
+        Ok(vpcs)
+    }
+
+    pub async fn list_subnets(&self, vpc_id: Option<&str>) -> Result<Vec<SubnetItem>> {
+        let mut subnets = Vec::new();
+        let mut next_token = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .describe_subnets()
+                .set_filters(vpc_filter(vpc_id))
+                .set_next_token(next_token)
+                .send()
+                .await?;
+            subnets.extend(resp.subnets().iter().map(SubnetItem::from));
+
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        // Grouped by VPC, then laid out by zone as the console shows them
+        subnets.sort_by(|a, b| {
+            (&a.vpc_id, &a.availability_zone, &a.cidr_block).cmp(&(
+                &b.vpc_id,
+                &b.availability_zone,
+                &b.cidr_block,
+            ))
+        });
+        Ok(subnets)
+    }
+
+    pub async fn list_route_tables(&self, vpc_id: Option<&str>) -> Result<Vec<RouteTableItem>> {
+        let mut tables = Vec::new();
+        let mut next_token = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .describe_route_tables()
+                .set_filters(vpc_filter(vpc_id))
+                .set_next_token(next_token)
+                .send()
+                .await?;
+            tables.extend(resp.route_tables().iter().map(RouteTableItem::from));
+
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        // Each VPC's main table first
+        tables.sort_by(|a, b| {
+            (&a.vpc_id, !a.main, &a.route_table_id).cmp(&(&b.vpc_id, !b.main, &b.route_table_id))
+        });
+        Ok(tables)
+    }
+
+    pub async fn list_security_groups(
+        &self,
+        vpc_id: Option<&str>,
+    ) -> Result<Vec<SecurityGroupItem>> {
+        let mut groups = Vec::new();
+        let mut next_token = None;
+        loop {
+            self.rate_limiter.acquire().await;
+            let resp = self
+                .client
+                .describe_security_groups()
+                .set_filters(vpc_filter(vpc_id))
+                .set_next_token(next_token)
+                .send()
+                .await?;
+            groups.extend(resp.security_groups().iter().map(SecurityGroupItem::from));
+
+            next_token = resp.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        groups.sort_by(|a, b| (&a.vpc_id, &a.group_name).cmp(&(&b.vpc_id, &b.group_name)));
+        Ok(groups)
+    }
+
+    fn render_table(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        let filtered_items = self.visible_items(data, view_state);
+        let (name, columns, widths, noun) = match &view_state.view_type {
+            ViewType::Custom(view) if view == SUBNETS_VIEW => (
+                "Subnets",
+                &SUBNET_COLUMNS[..],
+                &SUBNET_WIDTHS[..],
+                "subnets",
+            ),
+            ViewType::Custom(view) if view == ROUTE_TABLES_VIEW => (
+                "Route Tables",
+                &ROUTE_TABLE_COLUMNS[..],
+                &ROUTE_TABLE_WIDTHS[..],
+                "route tables",
+            ),
+            ViewType::Custom(_) => (
+                "Security Groups",
+                &SECURITY_GROUP_COLUMNS[..],
+                &SECURITY_GROUP_WIDTHS[..],
+                "security groups",
+            ),
+            _ => ("VPCs", &VPC_COLUMNS[..], &VPC_WIDTHS[..], "VPCs"),
+        };
+        let name = match view_state.context.as_deref() {
+            Some(vpc_id) => format!("VPCs: {} > {}", vpc_id, name),
+            None => name.to_string(),
+        };
+        let title = list_title(&name, app, view_state, filtered_items.len(), data);
+
+        if filtered_items.is_empty() && app.loading {
+            render_skeleton(f, area, &app.theme, &title, columns, widths);
+            return;
+        }
+
+        if filtered_items.is_empty() {
+            let message = if !view_state.search_filter.is_empty() {
+                format!("No {} match the current filter", noun)
+            } else {
+                format!("No {} found", noun)
+            };
+            render_empty(f, area, &app.theme, &title, &message);
+            return;
+        }
+
+        let rows: Vec<Row> = filtered_items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let mut cells = network_item(item.as_ref())?.cells().into_iter();
+                let id = cells.next().unwrap_or_default();
+                let name = cells.next().unwrap_or_default();
+                Some(
+                    Row::new(
+                        [
+                            Cell::from(highlight_match(
+                                &id,
+                                highlight_pattern(&view_state.search_filter, "id"),
+                            )),
+                            Cell::from(highlight_match(
+                                &name,
+                                highlight_pattern(&view_state.search_filter, "name"),
+                            )),
+                        ]
+                        .into_iter()
+                        .chain(cells.map(Cell::from)),
+                    )
+                    .style(row_style(app, view_state, i)),
+                )
+            })
+            .collect();
+
+        let table = Table::new(rows, widths.to_vec())
+            .header(header_row(&app.theme, columns))
+            .block(Block::default().borders(Borders::ALL).title(title));
+
+        f.render_stateful_widget(
+            table,
+            area,
+            &mut table_state(view_state, view_state.selected_index),
+        );
+    }
+}
+
+#[async_trait]
+impl AwsService for VPCService {
+    fn metadata(&self) -> ServiceMetadata {
+        ServiceMetadata {
+            id: VPC_SERVICE_ID.to_string(),
+            name: "VPC".to_string(),
+            description: "VPCs, subnets, route tables, and security groups".to_string(),
+            command: "vpc".to_string(),
+            aliases: vec!["network".to_string()],
+            short_label: Some("VPC".to_string()),
+            icon: None,
+        }
+    }
+
+    async fn load_data(&self, view_state: &ViewState) -> Result<ResourceData> {
+        let vpc_id = view_state.context.as_deref();
+        let items: Vec<Box<dyn ResourceItem>> = match &view_state.view_type {
+            ViewType::List => self
+                .list_vpcs()
+                .await?
+                .into_iter()
+                .map(|vpc| Box::new(vpc) as Box<dyn ResourceItem>)
+                .collect(),
+            ViewType::Custom(view) if view == SUBNETS_VIEW => self
+                .list_subnets(vpc_id)
+                .await?
+                .into_iter()
+                .map(|subnet| Box::new(subnet) as Box<dyn ResourceItem>)
+                .collect(),
+            ViewType::Custom(view) if view == ROUTE_TABLES_VIEW => self
+                .list_route_tables(vpc_id)
+                .await?
+                .into_iter()
+                .map(|table| Box::new(table) as Box<dyn ResourceItem>)
+                .collect(),
+            ViewType::Custom(view) if view == SECURITY_GROUPS_VIEW => self
+                .list_security_groups(vpc_id)
+                .await?
+                .into_iter()
+                .map(|group| Box::new(group) as Box<dyn ResourceItem>)
+                .collect(),
+            _ => Vec::new(),
+        };
+        Ok(ResourceData::new(items))
+    }
+
+    fn render(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        app: &App,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) {
+        match &view_state.view_type {
+            ViewType::Detail => {}
+            ViewType::Custom(view) if !NETWORK_VIEWS.contains(&view.as_str()) => {}
+            _ => self.render_table(f, area, app, view_state, data),
+        }
+    }
+
+    /// A VPC opens its subnets; `:vpc <vpc-id>/route-tables` and
+    /// `:vpc <vpc-id>/security-groups` reach the rest
+    fn handle_enter(&self, view_state: &mut ViewState, data: &ResourceData) -> Option<ViewState> {
+        if view_state.view_type != ViewType::List {
+            return None;
+        }
+        let vpc = self
+            .visible_items(data, view_state)
+            .get(view_state.selected_index)?
+            .as_any()
+            .downcast_ref::<VPCItem>()?
+            .vpc_id
+            .clone();
+        let mut new_view = ViewState::new(
+            view_state.service_id.clone(),
+            ViewType::Custom(SUBNETS_VIEW.to_string()),
+        );
+        new_view.context = Some(vpc);
+        Some(new_view)
+    }
+
+    fn get_copy_content(
+        &self,
+        view_state: &ViewState,
+        data: &ResourceData,
+    ) -> Option<(String, String)> {
+        let item = self
+            .visible_items(data, view_state)
+            .get(view_state.selected_index)?
+            .as_any();
+        if let Some(vpc) = item.downcast_ref::<VPCItem>() {
+            let display_name = match &vpc.name {
+                Some(name) => format!("{} ({})", vpc.vpc_id, name),
+                None => vpc.vpc_id.clone(),
+            };
+            Some((vpc.vpc_id.clone(), display_name))
+        } else if let Some(subnet) = item.downcast_ref::<SubnetItem>() {
+            Some((subnet.subnet_id.clone(), subnet.subnet_id.clone()))
+        } else if let Some(table) = item.downcast_ref::<RouteTableItem>() {
+            Some((table.route_table_id.clone(), table.route_table_id.clone()))
+        } else {
+            let group = item.downcast_ref::<SecurityGroupItem>()?;
+            Some((
+                group.group_id.clone(),
+                format!("{} ({})", group.group_id, group.group_name),
+            ))
+        }
+    }
+
+    fn matches_filter(&self, item: &dyn ResourceItem, filter: &str) -> bool {
+        let filter = filter.to_lowercase();
+        network_item(item).is_some_and(|item| {
+            ["id", "name", "cidr"].iter().any(|column| {
+                item.field(column)
+                    .is_some_and(|field| field.to_lowercase().contains(&filter))
+            })
+        })
+    }
+
+    fn filter_columns(&self, view_state: &ViewState) -> Vec<&'static str> {
+        match &view_state.view_type {
+            ViewType::List => vec!["id", "name", "cidr", "state"],
+            ViewType::Custom(view) if view == SUBNETS_VIEW => {
+                vec!["id", "name", "vpc", "cidr", "zone"]
+            }
+            ViewType::Custom(view) if view == ROUTE_TABLES_VIEW => vec!["id", "name", "vpc"],
+            ViewType::Custom(view) if view == SECURITY_GROUPS_VIEW => {
+                vec!["id", "name", "vpc", "description"]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn matches_column(&self, item: &dyn ResourceItem, column: &str, value: &str) -> bool {
+        network_item(item)
+            .and_then(|item| item.field(column))
+            .is_some_and(|field| field.to_lowercase().contains(&value.to_lowercase()))
+    }
+
+    fn export_columns(&self, view_state: &ViewState) -> Vec<String> {
+        let columns: &[&str] = match &view_state.view_type {
+            ViewType::List => &VPC_COLUMNS,
+            ViewType::Custom(view) if view == SUBNETS_VIEW => &SUBNET_COLUMNS,
+            ViewType::Custom(view) if view == ROUTE_TABLES_VIEW => &ROUTE_TABLE_COLUMNS,
+            ViewType::Custom(view) if view == SECURITY_GROUPS_VIEW => &SECURITY_GROUP_COLUMNS,
+            _ => &[],
+        };
+        columns.iter().map(|c| c.to_string()).collect()
+    }
+
+    fn export_rows(&self, view_state: &ViewState, data: &ResourceData) -> Vec<Vec<String>> {
+        self.visible_items(data, view_state)
+            .iter()
+            .filter_map(|item| network_item(item.as_ref()))
+            .map(|item| item.cells())
+            .collect()
+    }
+
+    fn deep_link_path(&self, view_state: &ViewState) -> Option<String> {
+        match &view_state.view_type {
+            ViewType::List => Some(String::new()),
+            ViewType::Custom(view) => Some(match view_state.context.as_deref() {
+                Some(vpc_id) => format!("{}/{}", vpc_id, view),
+                None => view.clone(),
+            }),
+            ViewType::Detail => None,
+        }
+    }
+
+    fn view_from_deep_link(
+        &self,
+        service_id: &ServiceId,
+        path: &str,
+        _cached: Option<&ResourceData>,
+    ) -> Option<ViewState> {
+        let (vpc_id, view_type) = parse_view_path(path)?;
+        let mut view_state = ViewState::new(service_id.clone(), view_type);
+        view_state.context = vpc_id.map(str::to_string);
+        Some(view_state)
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.rate_limiter.acquire().await;
+        self.client.describe_vpcs().max_results(5).send().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn view_paths_name_a_view_and_optionally_a_vpc() {
+        assert_eq!(parse_view_path(""), Some((None, ViewType::List)));
+        assert_eq!(
+            parse_view_path("subnets"),
+            Some((None, ViewType::Custom(SUBNETS_VIEW.to_string())))
+        );
+        assert_eq!(
+            parse_view_path("vpc-0abc/security-groups"),
+            Some((
+                Some("vpc-0abc"),
+                ViewType::Custom(SECURITY_GROUPS_VIEW.to_string())
+            ))
+        );
+        assert_eq!(parse_view_path("vpc-0abc/instances"), None);
+    }
+
+    #[test]
+    fn unnamed_rows_show_a_dash_and_search_skips_them() {
+        let subnet = SubnetItem {
+            subnet_id: "subnet-1".to_string(),
+            name: None,
+            vpc_id: "vpc-0abc".to_string(),
+            cidr_block: "10.0.1.0/24".to_string(),
+            availability_zone: "eu-west-1a".to_string(),
+            available_ips: Some(250),
+        };
+        assert_eq!(subnet.cells()[1], "-");
+        assert_eq!(subnet.field("name"), None);
+        assert_eq!(subnet.field("zone"), Some("eu-west-1a"));
+        assert_eq!(subnet.field("state"), None);
+    }
+}
@@ -0,0 +1,90 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::{fs, path::PathBuf};
+
+use crate::config::config_dir;
+use crate::services::traits::{ServiceId, ViewState, ViewType};
+
+const MAX_RECENT_VIEWS: usize = 10;
+
+/// Session state persisted between runs as `state.json` next to the config file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct State {
+    /// Most recently visited drill-down views, newest first
+    pub recent_views: Vec<RecentView>,
+    /// Hide the header and footer to give the content the full screen
+    pub zen_mode: bool,
+    /// Service switched to most recently, opened on the next start
+    pub last_service: Option<ServiceId>,
+}
+
+/// The parts of a `ViewState` needed to navigate back to it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecentView {
+    pub service_id: ServiceId,
+    pub view_type: ViewType,
+    pub context: Option<String>,
+}
+
+impl RecentView {
+    pub fn to_view_state(&self) -> ViewState {
+        let mut view_state = ViewState::new(self.service_id.clone(), self.view_type.clone());
+        view_state.context = self.context.clone();
+        view_state
+    }
+
+    pub fn label(&self) -> String {
+        match &self.context {
+            // Contexts may carry extra "|"-separated data after the display name
+            Some(context) => format!(
+                "{} > {}",
+                self.service_id,
+                context.split('|').next().unwrap_or(context)
+            ),
+            None => self.service_id.to_string(),
+        }
+    }
+}
+
+impl From<&ViewState> for RecentView {
+    fn from(view_state: &ViewState) -> Self {
+        Self {
+            service_id: view_state.service_id.clone(),
+            view_type: view_state.view_type.clone(),
+            context: view_state.context.clone(),
+        }
+    }
+}
+
+impl State {
+    pub fn path() -> Option<PathBuf> {
+        config_dir().map(|dir| dir.join("state.json"))
+    }
+
+    /// Load the state file, falling back to an empty state if it is missing or invalid
+    pub fn load() -> Self {
+        Self::path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(path) = Self::path() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, serde_json::to_string_pretty(self)?)?;
+        }
+        Ok(())
+    }
+
+    /// Move `view_state` to the front of the recents list
+    pub fn record_view(&mut self, view_state: &ViewState) {
+        let recent = RecentView::from(view_state);
+        self.recent_views.retain(|existing| existing != &recent);
+        self.recent_views.insert(0, recent);
+        self.recent_views.truncate(MAX_RECENT_VIEWS);
+    }
+}
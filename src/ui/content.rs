@@ -1,13 +1,103 @@
 use ratatui::{
     Frame,
-    layout::Rect,
-    style::{Color, Style},
-    widgets::{Block, Borders},
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState, Wrap},
 };
+use std::panic::{self, AssertUnwindSafe};
 
 use crate::app::App;
+use crate::services::traits::{ResourceData, ViewState};
+use crate::ui::{skeleton::render_skeleton, theme::Theme};
+use crate::utils::text::highlight_match;
+
+thread_local! {
+    static IN_SERVICE_RENDER: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Whether this thread is inside a service's `render`, whose panics `render_content`
+/// catches and shows as an error panel, so the panic hook should stay quiet
+pub fn in_service_render() -> bool {
+    IN_SERVICE_RENDER.get()
+}
+
+/// Table state scrolled to the view's offset with `selected_row` marked, so ratatui
+/// moves the window further if the selection would otherwise fall outside it
+pub fn table_state(view_state: &ViewState, selected_row: usize) -> TableState {
+    TableState::default()
+        .with_offset(view_state.scroll_offset)
+        .with_selected(Some(selected_row))
+}
+
+/// List table title with the shown count, or the shown/total counts and the filter
+/// while one is active
+pub fn list_title(
+    name: &str,
+    app: &App,
+    view_state: &ViewState,
+    shown: usize,
+    data: &ResourceData,
+) -> String {
+    if app.loading {
+        format!("{} (Loading...)", name)
+    } else if view_state.search_filter.is_empty() {
+        format!("{} ({})", name, shown)
+    } else {
+        format!(
+            "{} ({}/{}) - Filter: {}",
+            name,
+            shown,
+            data.items.len(),
+            view_state.search_filter
+        )
+    }
+}
+
+/// Bordered placeholder shown in place of a table that has no rows
+pub fn render_empty(f: &mut Frame, area: Rect, theme: &Theme, title: &str, message: &str) {
+    let paragraph = Paragraph::new(message.to_string())
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title.to_string()),
+        )
+        .style(Style::default().fg(theme.muted));
+    f.render_widget(paragraph, area);
+}
+
+pub fn header_row(theme: &Theme, columns: &[&'static str]) -> Row<'static> {
+    Row::new(
+        columns
+            .iter()
+            .map(|h| Cell::from(*h).style(theme.header_style())),
+    )
+    .height(1)
+}
+
+/// Style for the item row at `index`: the selection highlight, or a stripe on
+/// alternate rows when zebra striping is enabled. Rows marked with Space are shown
+/// in the theme's bold marked color.
+pub fn row_style(app: &App, view_state: &ViewState, index: usize) -> Style {
+    let style = if index == view_state.selected_index {
+        app.theme.selected_style()
+    } else if view_state.selected_items.contains(&index) {
+        Style::default().fg(app.theme.marked)
+    } else if app.experimental.zebra_rows && index % 2 == 1 {
+        Style::default().bg(app.theme.stripe)
+    } else {
+        Style::default()
+    };
+
+    if view_state.selected_items.contains(&index) {
+        style.add_modifier(Modifier::BOLD)
+    } else {
+        style
+    }
+}
 
 pub fn render_content(f: &mut Frame, area: Rect, app: &App) {
+    app.content_area.set(area);
+
     // First check if there's an error to display
     if let Some(ref error_message) = app.error_message {
         let title = if let Some(view_state) = &app.current_view {
@@ -16,51 +106,187 @@ pub fn render_content(f: &mut Frame, area: Rect, app: &App) {
             "Error".to_string()
         };
 
+        // AWS throttling and validation errors are often longer than the terminal is wide
         let error_paragraph = ratatui::widgets::Paragraph::new(error_message.as_str())
             .block(Block::default().borders(Borders::ALL).title(title))
-            .style(Style::default().fg(Color::Red));
+            .style(Style::default().fg(app.theme.error))
+            .wrap(Wrap { trim: true });
 
         f.render_widget(error_paragraph, area);
         return;
     }
 
     // Try to render service content
-    if let Some(view_state) = &app.current_view {
-        if let Some(service) = app.service_manager.get_service(&view_state.service_id) {
-            if let Some(data) = app.service_manager.get_service_data(&view_state.service_id) {
-                service.render(f, area, app, view_state, data);
-                return;
-            }
+    if let Some(view_state) = &app.current_view
+        && let Some(service) = app.service_manager.get_service(&view_state.service_id)
+    {
+        if let Some(data) = app.service_manager.get_service_data(&view_state.service_id) {
+            // A panicking service shouldn't take the whole app down. The panic hook
+            // stays quiet meanwhile since its stderr output would garble the alternate
+            // screen.
+            IN_SERVICE_RENDER.set(true);
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                service.render(f, area, app, view_state, data)
+            }));
+            IN_SERVICE_RENDER.set(false);
 
-            // Service exists but no data - show loading or empty state
-            let message = if app.loading {
-                "Loading..."
-            } else {
-                "No data available"
-            };
+            if let Err(payload) = result {
+                let detail = payload
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "unknown panic".to_string());
 
-            let loading_paragraph = ratatui::widgets::Paragraph::new(message)
+                let error_paragraph = ratatui::widgets::Paragraph::new(format!(
+                    "Failed to render {}: {}",
+                    view_state.service_id, detail
+                ))
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .title(format!("{}", view_state.service_id)),
+                        .title(format!("{} - Render Error", view_state.service_id)),
                 )
-                .style(Style::default().fg(Color::Yellow));
+                .style(Style::default().fg(app.theme.error))
+                .wrap(Wrap { trim: true });
+
+                // Discard whatever the service drew before panicking
+                f.render_widget(Clear, area);
+                f.render_widget(error_paragraph, area);
+            }
+            return;
+        }
 
-            f.render_widget(loading_paragraph, area);
+        // Service exists but no data - show a skeleton of the table while loading
+        let columns = service.export_columns(view_state);
+        if app.loading && !columns.is_empty() {
+            let title = format!("{} (Loading...)", view_state.service_id);
+            render_skeleton(f, area, &app.theme, &title, &columns, &[]);
             return;
         }
+
+        let message = if app.loading {
+            "Loading..."
+        } else {
+            "No data available"
+        };
+
+        let loading_paragraph = ratatui::widgets::Paragraph::new(message)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("{}", view_state.service_id)),
+            )
+            .style(Style::default().fg(app.theme.highlight))
+            .wrap(Wrap { trim: true });
+
+        f.render_widget(loading_paragraph, area);
+        return;
     }
 
-    // Show default message when no service is active
-    let message = "No service selected. Use :ecr to start.";
-    let empty_paragraph = ratatui::widgets::Paragraph::new(message)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("ATS - AWS Terminal Service"),
+    // No service is active - show the services landing screen
+    render_landing(f, area, app);
+}
+
+fn render_landing(f: &mut Frame, area: Rect, app: &App) {
+    let services = app.landing_services();
+    let total = app.service_manager.get_service_metadata().len();
+
+    let title = if app.landing_filter.is_empty() {
+        format!("Services ({})", services.len())
+    } else {
+        format!(
+            "Services ({}/{}) - Filter: {}",
+            services.len(),
+            total,
+            app.landing_filter
         )
-        .style(Style::default().fg(Color::Gray));
+    };
+
+    if services.is_empty() {
+        let message = if app.landing_filter.is_empty() {
+            "No services registered"
+        } else {
+            "No services match the current filter"
+        };
+
+        let empty_paragraph = ratatui::widgets::Paragraph::new(message)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .style(Style::default().fg(app.theme.muted))
+            .wrap(Wrap { trim: true });
+
+        f.render_widget(empty_paragraph, area);
+        return;
+    }
 
-    f.render_widget(empty_paragraph, area);
+    let header_cells = ["SERVICE", "COMMAND", "DESCRIPTION"]
+        .iter()
+        .map(|h| Cell::from(*h).style(app.theme.header_style()));
+    let header = Row::new(header_cells).height(1).bottom_margin(0);
+
+    let rows: Vec<Row> = services
+        .iter()
+        .enumerate()
+        .map(|(i, metadata)| {
+            let style = if i == app.landing_selected {
+                app.theme.selected_style()
+            } else {
+                Style::default()
+            };
+
+            Row::new(vec![
+                Cell::from(highlight_match(&metadata.name, &app.landing_filter)),
+                Cell::from(format!(":{}", metadata.command)),
+                Cell::from(highlight_match(&metadata.description, &app.landing_filter)),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(30), // Service name
+            Constraint::Length(12), // Command
+            Constraint::Min(0),     // Description
+        ],
+    )
+    .header(header)
+    .block(Block::default().borders(Borders::ALL).title(title));
+
+    f.render_widget(table, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::traits::{ServiceId, ViewType};
+
+    fn app_and_view() -> (App, ViewState) {
+        let app = App::new("test".to_string(), "us-east-1".to_string());
+        let view_state = ViewState::new(ServiceId::new("ec2"), ViewType::List);
+        (app, view_state)
+    }
+
+    #[test]
+    fn marked_rows_are_bold_in_the_marked_color() {
+        let (app, mut view_state) = app_and_view();
+        view_state.selected_items.insert(1);
+
+        let marked = row_style(&app, &view_state, 1);
+        assert_eq!(marked.fg, Some(app.theme.marked));
+        assert!(marked.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(row_style(&app, &view_state, 2), Style::default());
+    }
+
+    #[test]
+    fn zebra_rows_stripe_odd_rows_below_the_selection() {
+        let (mut app, mut view_state) = app_and_view();
+        assert_eq!(row_style(&app, &view_state, 1), Style::default());
+
+        app.experimental.zebra_rows = true;
+        view_state.selected_index = 3;
+        assert_eq!(row_style(&app, &view_state, 1).bg, Some(app.theme.stripe));
+        assert_eq!(row_style(&app, &view_state, 2), Style::default());
+        assert_eq!(row_style(&app, &view_state, 3), app.theme.selected_style());
+    }
 }
@@ -1,36 +1,109 @@
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
 use std::time::Duration;
 
 use crate::app::{App, InputMode};
+use crate::utils::text::truncate_with_ellipsis;
+
+/// Shown after the status while `p` holds off auto-refresh
+const PAUSED_LABEL: &str = " | Auto-refresh paused";
+
+/// Key hints as (key, label, rank) in display order. On narrow terminals the
+/// highest rank is dropped first, so rank 0 is the last hint to go.
+type Hint = (&'static str, &'static str, u8);
+
+const NORMAL_HINTS: [Hint; 5] = [
+    ("q", "Quit", 1),
+    (":", "Command", 2),
+    ("/", "Search", 3),
+    ("c", "Copy", 4),
+    ("?", "Help", 0),
+];
+
+const COMMAND_HINTS: [Hint; 2] = [("Enter", "Execute", 0), ("Esc", "Cancel", 1)];
+
+const SEARCH_HINTS: [Hint; 2] = [("Enter", "Apply", 0), ("Esc", "Cancel", 1)];
+
+const HINT_SEPARATOR: &str = " | ";
+
+fn hints_width(hints: &[&Hint]) -> usize {
+    let text: usize = hints
+        .iter()
+        .map(|(key, label, _)| key.chars().count() + 1 + label.chars().count())
+        .sum();
+    text + HINT_SEPARATOR.len() * hints.len().saturating_sub(1)
+}
+
+/// The hints that fit in `width` columns, dropping the least important first
+fn fit_hints(hints: &[Hint], width: usize) -> Vec<&Hint> {
+    let mut kept: Vec<&Hint> = hints.iter().collect();
+    while hints_width(&kept) > width {
+        let Some(least_important) = (0..kept.len()).max_by_key(|&i| kept[i].2) else {
+            break;
+        };
+        kept.remove(least_important);
+    }
+    kept
+}
 
 pub fn render_footer(f: &mut Frame, area: Rect, app: &App) {
+    // Main status
+    let status_text = match &app.progress_status {
+        Some(progress_status) => progress_status.as_str(),
+        None if app.loading => "Loading...",
+        None => "Ready",
+    };
+
+    // Hotkeys get whatever the status leaves, keeping the most important ones
+    let hints = match app.input_mode {
+        InputMode::Normal => &NORMAL_HINTS[..],
+        InputMode::Command => &COMMAND_HINTS[..],
+        InputMode::Search | InputMode::Prompt => &SEARCH_HINTS[..],
+    };
+    let paused_label = if app.paused { PAUSED_LABEL } else { "" };
+    let hints = fit_hints(
+        hints,
+        (area.width as usize)
+            .saturating_sub(status_text.chars().count() + paused_label.chars().count() + 1),
+    );
+
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Min(0),     // Status message
-            Constraint::Length(50), // Hotkeys
+            Constraint::Min(0),                             // Status message
+            Constraint::Length(hints_width(&hints) as u16), // Hotkeys
         ])
         .split(area);
 
     // Left side - Status
     let mut status_spans = Vec::new();
-
-    // Main status
-    let status_text = if app.loading { "Loading..." } else { "Ready" };
-    status_spans.push(Span::styled(status_text, Style::default().fg(Color::Green)));
+    status_spans.push(Span::styled(
+        status_text,
+        Style::default().fg(app.theme.success),
+    ));
+    if app.paused {
+        status_spans.push(Span::styled(
+            paused_label,
+            Style::default().fg(app.theme.highlight),
+        ));
+    }
 
     // Copy status (if present and not expired)
-    if let Some((copy_msg, copy_time)) = &app.copy_status {
-        if copy_time.elapsed() < Duration::from_secs(2) {
-            status_spans.push(Span::raw(" | "));
-            status_spans.push(Span::styled(copy_msg, Style::default().fg(Color::Green)));
-        }
+    if let Some((copy_msg, copy_time)) = &app.copy_status
+        && copy_time.elapsed() < Duration::from_secs(2)
+    {
+        let available = (chunks[0].width as usize)
+            .saturating_sub(status_text.chars().count() + paused_label.chars().count() + 3);
+        status_spans.push(Span::raw(" | "));
+        status_spans.push(Span::styled(
+            truncate_with_ellipsis(copy_msg, available),
+            Style::default().fg(app.theme.success),
+        ));
     }
 
     let status =
@@ -38,32 +111,42 @@ pub fn render_footer(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(status, chunks[0]);
 
     // Right side - Hotkeys
-    let hotkeys = match app.input_mode {
-        InputMode::Normal => vec![
-            Span::styled("q ", Style::default().fg(Color::Yellow)),
-            Span::raw("Quit | "),
-            Span::styled(": ", Style::default().fg(Color::Yellow)),
-            Span::raw("Command | "),
-            Span::styled("/ ", Style::default().fg(Color::Yellow)),
-            Span::raw("Search | "),
-            Span::styled("c ", Style::default().fg(Color::Yellow)),
-            Span::raw("Copy"),
-        ],
-        InputMode::Command => vec![
-            Span::styled("Enter ", Style::default().fg(Color::Yellow)),
-            Span::raw("Execute | "),
-            Span::styled("Esc ", Style::default().fg(Color::Yellow)),
-            Span::raw("Cancel"),
-        ],
-        InputMode::Search => vec![
-            Span::styled("Enter ", Style::default().fg(Color::Yellow)),
-            Span::raw("Apply | "),
-            Span::styled("Esc ", Style::default().fg(Color::Yellow)),
-            Span::raw("Cancel"),
-        ],
-    };
+    let mut hotkeys = Vec::new();
+    for (i, (key, label, _)) in hints.iter().enumerate() {
+        if i > 0 {
+            hotkeys.push(Span::raw(HINT_SEPARATOR));
+        }
+        hotkeys.push(Span::styled(
+            format!("{} ", key),
+            Style::default().fg(app.theme.highlight),
+        ));
+        hotkeys.push(Span::raw(*label));
+    }
 
     let hotkeys_paragraph =
         Paragraph::new(Line::from(hotkeys)).block(Block::default().borders(Borders::NONE));
     f.render_widget(hotkeys_paragraph, chunks[1]);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(hints: &[&Hint]) -> Vec<&'static str> {
+        hints.iter().map(|(key, _, _)| *key).collect()
+    }
+
+    #[test]
+    fn all_hints_fit_on_an_80_column_terminal() {
+        let hints = fit_hints(&NORMAL_HINTS, 80 - "Loading...".len() - 1);
+        assert_eq!(keys(&hints), ["q", ":", "/", "c", "?"]);
+    }
+
+    #[test]
+    fn narrow_terminals_drop_the_least_important_hints_first() {
+        let hints = fit_hints(&NORMAL_HINTS, 20);
+        assert_eq!(keys(&hints), ["q", "?"]);
+        assert!(hints_width(&hints) <= 20);
+        assert!(fit_hints(&NORMAL_HINTS, 3).is_empty());
+    }
+}
@@ -1,20 +1,31 @@
-use chrono::Local;
+use chrono::{Local, Utc};
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
 
 use crate::app::App;
+use std::time::Duration;
+
+/// Age of the loaded data, e.g. "12s ago" or "3m ago"
+fn refreshed_ago(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs < 60 {
+        format!("{}s ago", secs)
+    } else {
+        format!("{}m ago", secs / 60)
+    }
+}
 
 pub fn render_header(f: &mut Frame, area: Rect, app: &App) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Length(20), // ATS info
-            Constraint::Min(0),     // Spacer
+            Constraint::Length(24), // ATS info and current service
+            Constraint::Min(0),     // Service tabs
             Constraint::Length(40), // AWS info
         ])
         .split(area);
@@ -22,26 +33,74 @@ pub fn render_header(f: &mut Frame, area: Rect, app: &App) {
     // Get AWS info from app state (includes CLI flags)
     let profile = &app.aws_profile;
     let region = &app.aws_region;
-    let current_time = Local::now().format("%H:%M:%S").to_string();
+    let current_time = if app.utc_clock {
+        Utc::now().format("%H:%M:%SZ").to_string()
+    } else {
+        Local::now().format("%H:%M:%S").to_string()
+    };
 
     // Left side - Application info
     let version = env!("CARGO_PKG_VERSION");
-    let app_info = Paragraph::new(Line::from(vec![
-        Span::styled("ATS", Style::default().fg(Color::Yellow)),
+    let mut app_info_spans = vec![
+        Span::styled("ATS", Style::default().fg(app.theme.highlight)),
         Span::raw(format!(" v{}", version)),
-    ]))
-    .block(Block::default().borders(Borders::NONE));
+    ];
+    if let Some(view_state) = &app.current_view {
+        app_info_spans.push(Span::raw(" | "));
+        app_info_spans.push(Span::styled(
+            app.service_manager.service_label(&view_state.service_id),
+            Style::default().fg(app.theme.accent),
+        ));
+    }
+    let app_info =
+        Paragraph::new(Line::from(app_info_spans)).block(Block::default().borders(Borders::NONE));
     f.render_widget(app_info, chunks[0]);
 
+    // Middle - Service tabs (Tab/Shift-Tab) and background activity
+    let current_service = app
+        .current_view
+        .as_ref()
+        .map(|view_state| view_state.service_id.0.as_str());
+    let mut middle_spans = Vec::new();
+    for metadata in app.tab_services() {
+        let style = if current_service == Some(metadata.id.as_str()) {
+            app.theme.filled(app.theme.accent)
+        } else {
+            Style::default().fg(app.theme.muted)
+        };
+        middle_spans.push(Span::styled(
+            format!(" {} ", metadata.display_label()),
+            style,
+        ));
+        middle_spans.push(Span::raw(" "));
+    }
+    // Age of the current data, so stale views between auto-refreshes are obvious
+    if app.current_view.is_some() && !app.loading {
+        middle_spans.push(Span::styled(
+            format!("Refreshed {}", refreshed_ago(app.last_refresh.elapsed())),
+            Style::default().fg(app.theme.muted),
+        ));
+        middle_spans.push(Span::raw(" "));
+    }
+    if app.is_prefetching() {
+        middle_spans.push(Span::styled(
+            "Prefetching…",
+            Style::default().fg(app.theme.highlight),
+        ));
+    }
+    let middle =
+        Paragraph::new(Line::from(middle_spans)).block(Block::default().borders(Borders::NONE));
+    f.render_widget(middle, chunks[1]);
+
     // Right side - AWS info and time
     let aws_info = Paragraph::new(Line::from(vec![
-        Span::styled("Profile: ", Style::default().fg(Color::Gray)),
-        Span::styled(profile, Style::default().fg(Color::Green)),
+        Span::styled("Profile: ", Style::default().fg(app.theme.muted)),
+        Span::styled(profile, Style::default().fg(app.theme.success)),
         Span::raw(" | "),
-        Span::styled("Region: ", Style::default().fg(Color::Gray)),
-        Span::styled(region, Style::default().fg(Color::Green)),
+        Span::styled("Region: ", Style::default().fg(app.theme.muted)),
+        Span::styled(region, Style::default().fg(app.theme.success)),
         Span::raw(" | "),
-        Span::styled(&current_time, Style::default().fg(Color::Yellow)),
+        Span::styled(&current_time, Style::default().fg(app.theme.highlight)),
     ]))
     .block(Block::default().borders(Borders::NONE));
     f.render_widget(aws_info, chunks[2]);
@@ -1,64 +1,91 @@
 use ratatui::{
     Frame,
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
 
 use crate::app::{App, InputMode};
+use crate::services::traits::{FilterOp, parse_column_filter};
 
 pub fn render_input(f: &mut Frame, area: Rect, app: &App) {
     let (prompt, content, mode_indicator) = match app.input_mode {
         InputMode::Normal => {
-            let current_service = if let Some(view_state) = &app.current_view {
-                match view_state.view_type {
-                    crate::services::traits::ViewType::List => view_state.service_id.to_string(),
-                    crate::services::traits::ViewType::Detail => {
-                        if let Some(context) = &view_state.context {
-                            format!("{}/{}", view_state.service_id, context)
-                        } else {
-                            format!("{}/detail", view_state.service_id)
-                        }
-                    }
-                    crate::services::traits::ViewType::Custom(ref name) => {
-                        format!("{}/{}", view_state.service_id, name)
-                    }
-                }
-            } else {
-                "no service".to_string()
+            let breadcrumb = app.breadcrumb().unwrap_or_else(|| "services".to_string());
+            ("> ".to_string(), breadcrumb, "".to_string())
+        }
+        InputMode::Command => (":".to_string(), app.input_buffer.clone(), "[:]".to_string()),
+        InputMode::Search => {
+            let indicator = match column_filter_status(app) {
+                Some((column, Ok(()))) => format!("[/ {}]", column),
+                Some((_, Err(problem))) => format!("[/ {}]", problem),
+                None => "[/]".to_string(),
+            };
+            // The filter applies as it's typed, so the count shows how narrow it is
+            let count = match &app.current_view {
+                Some(view_state) => app.get_filtered_data_count(view_state),
+                None => app.landing_services().len(),
             };
-            ("> ", current_service, "".to_string())
+            let noun = if count == 1 { "match" } else { "matches" };
+            let indicator = format!("({} {}) {}", count, noun, indicator);
+            ("/".to_string(), app.input_buffer.clone(), indicator)
         }
-        InputMode::Command => (":", app.input_buffer.clone(), "[:]".to_string()),
-        InputMode::Search => ("/", app.input_buffer.clone(), "[/]".to_string()),
+        InputMode::Prompt => match &app.active_prompt {
+            Some(action) => (
+                format!("{}: ", action.prompt.as_deref().unwrap_or("Input")),
+                app.input_buffer.clone(),
+                format!("[{}]", action.description),
+            ),
+            None => (
+                "? ".to_string(),
+                app.input_buffer.clone(),
+                "[?]".to_string(),
+            ),
+        },
     };
 
     let input_style = match app.input_mode {
-        InputMode::Normal => Style::default().fg(Color::White),
-        InputMode::Command => Style::default().fg(Color::Cyan),
-        InputMode::Search => Style::default().fg(Color::Yellow),
+        InputMode::Normal => Style::default().fg(app.theme.text),
+        InputMode::Command => Style::default().fg(app.theme.accent),
+        InputMode::Search => Style::default().fg(app.theme.highlight),
+        InputMode::Prompt => Style::default().fg(app.theme.marked),
     };
 
-    let content_len = content.len();
-    let mut spans = vec![
-        Span::styled(prompt, input_style),
-        Span::styled(content, input_style),
-    ];
+    let prompt_width = display_width(&prompt);
+    let content_width = display_width(&content);
+    let mut spans = vec![Span::styled(prompt, input_style)];
+    match column_filter_status(app) {
+        // Color the column name to show live whether the query is usable
+        Some((column, status)) => {
+            let column_color = if status.is_ok() {
+                app.theme.success
+            } else {
+                app.theme.error
+            };
+            spans.push(Span::styled(
+                column.to_string(),
+                Style::default().fg(column_color),
+            ));
+            spans.push(Span::styled(
+                content[column.len()..].to_string(),
+                input_style,
+            ));
+        }
+        _ => spans.push(Span::styled(content, input_style)),
+    }
 
     if !mode_indicator.is_empty() {
         spans.push(Span::raw(
             " ".repeat(
                 area.width
-                    .saturating_sub(
-                        prompt.len() as u16 + content_len as u16 + mode_indicator.len() as u16,
-                    )
+                    .saturating_sub(prompt_width + content_width + display_width(&mode_indicator))
                     .saturating_sub(2) as usize,
             ),
         ));
         spans.push(Span::styled(
             mode_indicator,
-            Style::default().fg(Color::Gray),
+            Style::default().fg(app.theme.muted),
         ));
     }
 
@@ -68,10 +95,42 @@ pub fn render_input(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(input_paragraph, area);
 
     // Set cursor position for input modes
-    if matches!(app.input_mode, InputMode::Command | InputMode::Search) {
-        f.set_cursor_position((
-            area.x + prompt.len() as u16 + content_len as u16 + 1,
-            area.y + 1,
-        ));
+    if matches!(
+        app.input_mode,
+        InputMode::Command | InputMode::Search | InputMode::Prompt
+    ) {
+        f.set_cursor_position((area.x + prompt_width + content_width + 1, area.y + 1));
+    }
+}
+
+/// Terminal columns `text` takes up. Byte length overcounts non-ASCII text and char
+/// count undercounts wide characters such as Hangul or CJK, which take two columns.
+fn display_width(text: &str) -> u16 {
+    Span::raw(text).width() as u16
+}
+
+/// Column targeted by a column search being typed, and what's wrong with the query
+/// if the current view can't run it: an unknown column or an unreadable comparison
+fn column_filter_status(app: &App) -> Option<(&str, Result<(), String>)> {
+    if app.input_mode != InputMode::Search {
+        return None;
     }
+    let view_state = app.current_view.as_ref()?;
+    let service = app.service_manager.get_service(&view_state.service_id)?;
+    let filter = parse_column_filter(&app.input_buffer)?;
+    let known = service
+        .filter_columns(view_state)
+        .iter()
+        .any(|known| known.eq_ignore_ascii_case(filter.column));
+
+    let status = if !known {
+        Err(format!("unknown column: {}", filter.column))
+    } else if filter.op == FilterOp::Contains {
+        Ok(())
+    } else {
+        service
+            .parse_compare_value(&filter.column.to_lowercase(), filter.value)
+            .map(|_| ())
+    };
+    Some((filter.column, status))
 }
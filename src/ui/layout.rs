@@ -8,19 +8,35 @@ use crate::app::App;
 pub fn render_layout(f: &mut Frame, app: &App) {
     let size = f.area();
 
+    // Zen mode collapses the header and footer; the input bar stays for commands
+    let chrome_height = if app.state.zen_mode { 0 } else { 1 };
+
+    // The view status line only takes space while a filter or view option is active
+    let status_height = if super::status::view_status_line(app).is_some() {
+        1
+    } else {
+        0
+    };
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(1), // Header
-            Constraint::Length(3), // Input bar
-            Constraint::Min(0),    // Main content
-            Constraint::Length(1), // Footer
+            Constraint::Length(chrome_height), // Header
+            Constraint::Length(3),             // Input bar
+            Constraint::Length(status_height), // Active filter and view options
+            Constraint::Min(0),                // Main content
+            Constraint::Length(chrome_height), // Footer
         ])
         .split(size);
 
     // Render each section
     super::header::render_header(f, chunks[0], app);
     super::input::render_input(f, chunks[1], app);
-    super::content::render_content(f, chunks[2], app);
-    super::footer::render_footer(f, chunks[3], app);
+    super::status::render_view_status(f, chunks[2], app);
+    super::content::render_content(f, chunks[3], app);
+    super::footer::render_footer(f, chunks[4], app);
+
+    // Overlays are drawn last so they sit on top of the content
+    super::picker::render_picker(f, chunks[3], app);
+    super::popup::render_popup(f, chunks[3], app);
 }
@@ -3,3 +3,8 @@ pub mod footer;
 pub mod header;
 pub mod input;
 pub mod layout;
+pub mod picker;
+pub mod popup;
+pub mod skeleton;
+pub mod status;
+pub mod theme;
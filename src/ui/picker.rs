@@ -0,0 +1,55 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use super::popup::centered_rect;
+use crate::app::App;
+
+pub fn render_picker(f: &mut Frame, area: Rect, app: &App) {
+    let Some(picker) = &app.picker else {
+        return;
+    };
+
+    let visible_items = picker.visible_items();
+    let width = area.width.saturating_sub(4).min(80);
+    let height = (visible_items.len().max(1) as u16 + 2).min(area.height);
+    let picker_area = centered_rect(area, width, height);
+    let title = match &picker.filter {
+        Some(filter) => format!(
+            "{} > {}▏ (Enter to open, Esc to close)",
+            picker.title, filter
+        ),
+        None => format!("{} (Enter to open, Esc to close)", picker.title),
+    };
+    let block = Block::default().borders(Borders::ALL).title(title);
+
+    f.render_widget(Clear, picker_area);
+
+    if visible_items.is_empty() {
+        let message = if picker.items.is_empty() {
+            "Nothing to show"
+        } else {
+            "No matches"
+        };
+        let empty_paragraph = Paragraph::new(message)
+            .block(block)
+            .style(Style::default().fg(app.theme.muted));
+        f.render_widget(empty_paragraph, picker_area);
+        return;
+    }
+
+    let items: Vec<ListItem> = visible_items
+        .iter()
+        .map(|item| ListItem::new(item.label.clone()))
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(app.theme.selected_style());
+
+    let mut state = ListState::default().with_selected(Some(picker.selected_index));
+    f.render_stateful_widget(list, picker_area, &mut state);
+}
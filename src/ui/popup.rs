@@ -0,0 +1,71 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+use crate::app::App;
+
+/// Compute a rect of the given size centered within `area`
+pub fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let [area] = Layout::horizontal([Constraint::Length(width)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [area] = Layout::vertical([Constraint::Length(height)])
+        .flex(Flex::Center)
+        .areas(area);
+    area
+}
+
+pub fn render_popup(f: &mut Frame, area: Rect, app: &App) {
+    let Some(popup) = &app.popup else {
+        return;
+    };
+
+    let key_width = popup
+        .lines
+        .iter()
+        .map(|(key, _)| key.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let lines: Vec<Line> = popup
+        .lines
+        .iter()
+        .map(|(key, value)| {
+            // A line without a key is a section heading
+            if key.is_empty() {
+                return Line::styled(
+                    value.clone(),
+                    Style::default()
+                        .fg(app.theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                );
+            }
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<width$}  ", key, width = key_width),
+                    Style::default().fg(app.theme.highlight),
+                ),
+                Span::raw(value.clone()),
+            ])
+        })
+        .collect();
+
+    let width = area.width.saturating_sub(4).min(100);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let popup_area = centered_rect(area, width, height);
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("{} (Esc to close)", popup.title)),
+        )
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(Clear, popup_area);
+    f.render_widget(paragraph, popup_area);
+}
@@ -0,0 +1,50 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Cell, Row, Table},
+};
+
+use super::theme::Theme;
+
+/// Placeholder rows drawn under the headers while a table is loading
+const SKELETON_ROWS: usize = 5;
+
+/// Draw a table with the real headers and greyed placeholder rows, so a view has
+/// its shape before the data arrives. Columns share the width evenly when `widths`
+/// is empty.
+pub fn render_skeleton<S: AsRef<str>>(
+    f: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    title: &str,
+    columns: &[S],
+    widths: &[Constraint],
+) {
+    let header = Row::new(
+        columns
+            .iter()
+            .map(|column| Cell::from(column.as_ref().to_string()).style(theme.header_style())),
+    )
+    .height(1);
+
+    let widths = if widths.is_empty() {
+        vec![Constraint::Fill(1); columns.len()]
+    } else {
+        widths.to_vec()
+    };
+
+    // Vary the bar lengths a little so the placeholder reads as rows of text
+    let rows = (0..SKELETON_ROWS).map(|i| {
+        Row::new((0..columns.len()).map(|j| Cell::from("░".repeat(6 + (i + j * 3) % 5 * 2))))
+            .style(Style::default().fg(Color::DarkGray))
+    });
+
+    let table = Table::new(rows, widths).header(header).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(title.to_string()),
+    );
+
+    f.render_widget(table, area);
+}
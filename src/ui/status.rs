@@ -0,0 +1,82 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::app::App;
+use crate::services::traits::{ColumnFilter, FilterOp, parse_column_filter};
+
+/// Summary of what is shaping the current list (filter, grouping, wrapping, marks), or
+/// `None` when the view is shown as loaded
+pub fn view_status_line(app: &App) -> Option<Line<'static>> {
+    let label_style = Style::default().fg(app.theme.muted);
+    let value_style = Style::default().fg(app.theme.highlight);
+    let mut parts: Vec<Vec<Span<'static>>> = Vec::new();
+
+    let filter = match &app.current_view {
+        Some(view_state) => view_state.search_filter.as_str(),
+        None => app.landing_filter.as_str(),
+    };
+    if !filter.is_empty() {
+        let mut spans = vec![Span::styled("Filter: ", label_style)];
+        match parse_column_filter(filter) {
+            Some(ColumnFilter {
+                column,
+                op: FilterOp::Contains,
+                value,
+            }) => {
+                spans.push(Span::styled(value.to_string(), value_style));
+                spans.push(Span::styled(format!(" in {}", column), label_style));
+            }
+            Some(ColumnFilter { column, op, value }) => {
+                let symbol = if op == FilterOp::Greater { ">" } else { "<" };
+                spans.push(Span::styled(
+                    format!("{} {} {}", column, symbol, value),
+                    value_style,
+                ));
+            }
+            None => spans.push(Span::styled(filter.to_string(), value_style)),
+        }
+        parts.push(spans);
+    }
+
+    if let Some(view_state) = &app.current_view {
+        if view_state.fuzzy_search {
+            parts.push(vec![Span::styled("Fuzzy", value_style)]);
+        }
+        if view_state.grouped {
+            parts.push(vec![Span::styled("Grouped", value_style)]);
+        }
+        if view_state.wrap_cells {
+            parts.push(vec![Span::styled("Wrapping cells", value_style)]);
+        }
+        if !view_state.selected_items.is_empty() {
+            parts.push(vec![Span::styled(
+                format!("{} marked", view_state.selected_items.len()),
+                value_style,
+            )]);
+        }
+    }
+
+    if parts.is_empty() {
+        return None;
+    }
+
+    let mut spans = vec![Span::raw(" ")];
+    for (i, part) in parts.into_iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(" | ", label_style));
+        }
+        spans.extend(part);
+    }
+    Some(Line::from(spans))
+}
+
+pub fn render_view_status(f: &mut Frame, area: Rect, app: &App) {
+    if let Some(line) = view_status_line(app) {
+        f.render_widget(Paragraph::new(line), area);
+    }
+}
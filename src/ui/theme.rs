@@ -0,0 +1,130 @@
+use ratatui::style::{Color, Modifier, Style};
+
+/// Names accepted by `--theme` and `:theme`
+pub const THEME_NAMES: [&str; 3] = ["default", "monochrome", "solarized"];
+
+/// Colors the UI and services draw with, so one setting can make ats readable on
+/// light-background terminals where yellow on white disappears
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub header: Color,       // Table column headers
+    pub selection_bg: Color, // Selected row
+    pub selection_fg: Color,
+    pub accent: Color,    // Current service, popup headings, command input
+    pub highlight: Color, // Key hints, filter values, the clock
+    pub muted: Color,     // Labels, placeholders, and empty-table messages
+    pub success: Color,   // Healthy states and confirmations
+    pub error: Color,     // Failed states and error messages
+    pub marked: Color,    // Rows marked with Space and action prompts
+    pub text: Color,      // Plain input and body text
+    pub stripe: Color,    // Alternate row background with zebra striping
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            header: Color::Yellow,
+            selection_bg: Color::Yellow,
+            selection_fg: Color::Black,
+            accent: Color::Cyan,
+            highlight: Color::Yellow,
+            muted: Color::Gray,
+            success: Color::Green,
+            error: Color::Red,
+            marked: Color::Magenta,
+            text: Color::White,
+            stripe: Color::Indexed(236),
+        }
+    }
+}
+
+impl Theme {
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Self::default()),
+            // The terminal's own colors, with the selection shown in reverse video
+            "monochrome" => Some(Self {
+                header: Color::Reset,
+                selection_bg: Color::Reset,
+                selection_fg: Color::Reset,
+                accent: Color::Reset,
+                highlight: Color::Reset,
+                muted: Color::Reset,
+                success: Color::Reset,
+                error: Color::Reset,
+                marked: Color::Reset,
+                text: Color::Reset,
+                stripe: Color::Reset,
+            }),
+            // Solarized accents, which read on both its light and dark backgrounds
+            "solarized" => Some(Self {
+                header: Color::Rgb(38, 139, 210),
+                selection_bg: Color::Rgb(38, 139, 210),
+                selection_fg: Color::Rgb(253, 246, 227),
+                accent: Color::Rgb(42, 161, 152),
+                highlight: Color::Rgb(203, 75, 22),
+                muted: Color::Rgb(147, 161, 161),
+                success: Color::Rgb(133, 153, 0),
+                error: Color::Rgb(220, 50, 47),
+                marked: Color::Rgb(211, 54, 130),
+                text: Color::Rgb(101, 123, 131),
+                stripe: Color::Rgb(238, 232, 213),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Bold column header text
+    pub fn header_style(&self) -> Style {
+        Style::default()
+            .fg(self.header)
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// The selected row of a table or list
+    pub fn selected_style(&self) -> Style {
+        self.filled(self.selection_bg)
+    }
+
+    /// Selection-colored text on `bg`, falling back to reverse video when the theme
+    /// leaves the background to the terminal
+    pub fn filled(&self, bg: Color) -> Style {
+        let style = Style::default().bg(bg).fg(self.selection_fg);
+        if bg == Color::Reset {
+            style.add_modifier(Modifier::REVERSED)
+        } else {
+            style
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_listed_theme_resolves() {
+        for name in THEME_NAMES {
+            assert!(Theme::by_name(name).is_some(), "{}", name);
+        }
+        assert_eq!(Theme::by_name("default"), Some(Theme::default()));
+        assert!(Theme::by_name("neon").is_none());
+    }
+
+    #[test]
+    fn monochrome_selection_uses_reverse_video() {
+        let theme = Theme::by_name("monochrome").unwrap();
+        assert!(
+            theme
+                .selected_style()
+                .add_modifier
+                .contains(Modifier::REVERSED)
+        );
+        assert!(
+            !Theme::default()
+                .selected_style()
+                .add_modifier
+                .contains(Modifier::REVERSED)
+        );
+    }
+}
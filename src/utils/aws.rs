@@ -1,13 +1,128 @@
 use anyhow::Result;
-use aws_config::BehaviorVersion;
-use aws_sdk_ecr::Client as ECRClient;
+use aws_config::{BehaviorVersion, SdkConfig, retry::RetryConfig};
+use aws_credential_types::provider::{ProvideCredentials, error::CredentialsError};
+use aws_sdk_cloudwatchlogs::Client as LogsClient;
+use aws_sdk_ec2::Client as EC2Client;
+use aws_sdk_ecr::{
+    Client as ECRClient,
+    error::{ConnectorError, ErrorMetadata, ProvideErrorMetadata},
+};
+use aws_sdk_iam::Client as IAMClient;
+use aws_sdk_lambda::Client as LambdaClient;
+use aws_sdk_rds::Client as RDSClient;
+use aws_sdk_route53::Client as Route53Client;
+use aws_sdk_s3::Client as S3Client;
+use aws_sdk_secretsmanager::Client as SecretsClient;
+use aws_sdk_sfn::Client as SFNClient;
+use aws_sdk_sts::{
+    Client as STSClient, error::SdkError as StsSdkError,
+    operation::assume_role_with_web_identity::AssumeRoleWithWebIdentityError,
+};
 use aws_types::region::Region;
+use std::{env, fs, path::PathBuf, time::Duration};
 
-pub async fn create_ecr_client(
+/// Regions accepted by `:region`, to catch typos before any request is sent
+const AWS_REGIONS: &[&str] = &[
+    "af-south-1",
+    "ap-east-1",
+    "ap-northeast-1",
+    "ap-northeast-2",
+    "ap-northeast-3",
+    "ap-south-1",
+    "ap-south-2",
+    "ap-southeast-1",
+    "ap-southeast-2",
+    "ap-southeast-3",
+    "ap-southeast-4",
+    "ap-southeast-5",
+    "ap-southeast-7",
+    "ca-central-1",
+    "ca-west-1",
+    "cn-north-1",
+    "cn-northwest-1",
+    "eu-central-1",
+    "eu-central-2",
+    "eu-north-1",
+    "eu-south-1",
+    "eu-south-2",
+    "eu-west-1",
+    "eu-west-2",
+    "eu-west-3",
+    "il-central-1",
+    "me-central-1",
+    "me-south-1",
+    "mx-central-1",
+    "sa-east-1",
+    "us-east-1",
+    "us-east-2",
+    "us-gov-east-1",
+    "us-gov-west-1",
+    "us-west-1",
+    "us-west-2",
+];
+
+pub fn is_known_region(region: &str) -> bool {
+    AWS_REGIONS.contains(&region)
+}
+
+/// Profile names defined in the shared credentials and config files, honoring
+/// `AWS_SHARED_CREDENTIALS_FILE` and `AWS_CONFIG_FILE`
+pub fn profile_names() -> Vec<String> {
+    let aws_dir = dirs::home_dir().map(|home| home.join(".aws"));
+    let file = |var: &str, default: &str| {
+        env::var(var)
+            .ok()
+            .map(PathBuf::from)
+            .or_else(|| aws_dir.as_ref().map(|dir| dir.join(default)))
+            .and_then(|path| fs::read_to_string(path).ok())
+            .unwrap_or_default()
+    };
+
+    let mut names = parse_profile_names(&file("AWS_SHARED_CREDENTIALS_FILE", "credentials"), false);
+    names.extend(parse_profile_names(
+        &file("AWS_CONFIG_FILE", "config"),
+        true,
+    ));
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Section names of an AWS profile file. The config file prefixes every profile but
+/// `default` with `profile `, and also holds non-profile sections (e.g. `sso-session`).
+fn parse_profile_names(content: &str, is_config_file: bool) -> Vec<String> {
+    content
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix('[')?.strip_suffix(']'))
+        .map(str::trim)
+        .filter_map(|section| {
+            if !is_config_file || section == "default" {
+                Some(section)
+            } else {
+                section.strip_prefix("profile ").map(str::trim)
+            }
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+/// Attempts the SDK makes per request: the first plus three retries. Only throttling,
+/// 5xx, and connection failures are retried, with exponential backoff and jitter.
+const MAX_REQUEST_ATTEMPTS: u32 = 4;
+
+/// Backoff before the first retry of a request, doubled for each later one
+const RETRY_INITIAL_BACKOFF: Duration = Duration::from_millis(200);
+
+pub async fn load_aws_config(
     profile: Option<String>,
     region: Option<String>,
-) -> Result<ECRClient> {
-    let mut config_loader = aws_config::defaults(BehaviorVersion::latest());
+    endpoint_url: Option<String>,
+) -> SdkConfig {
+    let mut config_loader = aws_config::defaults(BehaviorVersion::latest()).retry_config(
+        RetryConfig::standard()
+            .with_max_attempts(MAX_REQUEST_ATTEMPTS)
+            .with_initial_backoff(RETRY_INITIAL_BACKOFF),
+    );
 
     // CLI flags have highest priority
     if let Some(profile) = profile {
@@ -18,7 +133,339 @@ pub async fn create_ecr_client(
         config_loader = config_loader.region(Region::new(region));
     }
 
-    let config = config_loader.load().await;
-    let client = ECRClient::new(&config);
-    Ok(client)
+    // Custom endpoints such as LocalStack serve every service from one URL
+    if let Some(endpoint_url) = endpoint_url {
+        config_loader = config_loader.endpoint_url(endpoint_url);
+    }
+
+    config_loader.load().await
+}
+
+/// Error codes AWS returns when a region isn't enabled for the account. An
+/// unrecognized client can also mean invalid credentials, so messages built from
+/// this should say so.
+const REGION_UNAVAILABLE_CODES: &[&str] = &["OptInRequired", "UnrecognizedClientException"];
+
+pub fn is_region_unavailable(err: &impl ProvideErrorMetadata) -> bool {
+    err.code()
+        .is_some_and(|code| REGION_UNAVAILABLE_CODES.contains(&code))
+}
+
+/// Error codes AWS returns when the request's credentials are expired or rejected
+const CREDENTIAL_ERROR_CODES: &[&str] = &[
+    "ExpiredToken",
+    "ExpiredTokenException",
+    "InvalidClientTokenId",
+    "UnauthorizedException",
+];
+
+/// AWS error code of a failed request, e.g. "ExpiredTokenException". Only errors the
+/// SDK doesn't model carry their metadata in the source chain, which is where
+/// credential and throttling errors end up.
+fn error_code(error: &anyhow::Error) -> Option<&str> {
+    error
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<ErrorMetadata>()?.code())
+}
+
+/// Whether a failed request points at missing or expired credentials rather than a
+/// problem with the request, e.g. after an SSO session ends
+fn is_credential_error(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| cause.is::<CredentialsError>())
+        || error_code(error).is_some_and(|code| CREDENTIAL_ERROR_CODES.contains(&code))
+}
+
+/// Problem with IRSA-style web identity settings (`AWS_WEB_IDENTITY_TOKEN_FILE` and
+/// `AWS_ROLE_ARN`) that would make credential loading fail. `None` when web identity
+/// isn't configured or looks usable.
+pub fn web_identity_problem(token_file: Option<&str>, role_arn: Option<&str>) -> Option<String> {
+    let token_file = token_file.filter(|path| !path.is_empty())?;
+    if role_arn.is_none_or(str::is_empty) {
+        return Some("AWS_WEB_IDENTITY_TOKEN_FILE is set but AWS_ROLE_ARN is not".to_string());
+    }
+
+    match fs::read_to_string(token_file) {
+        Err(e) => Some(format!(
+            "Cannot read web identity token file {}: {}",
+            token_file, e
+        )),
+        Ok(token) if token.trim().is_empty() => {
+            Some(format!("Web identity token file {} is empty", token_file))
+        }
+        Ok(_) => None,
+    }
+}
+
+/// Whether a failed request never got an answer because the connection failed or
+/// timed out, e.g. on a flaky VPN. ats sets no operation timeouts, so timeouts only
+/// come from the connector. Credentials that can't be loaded also fail the dispatch,
+/// but not as I/O or timeout errors, so they fail fast with the login hint.
+pub fn is_connection_error(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        cause
+            .downcast_ref::<ConnectorError>()
+            .is_some_and(|e| e.is_io() || e.is_timeout())
+    })
+}
+
+/// Whether STS rejected the web identity token as expired or invalid while loading
+/// credentials, e.g. when the projected service account token wasn't refreshed
+fn is_web_identity_rejected(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        cause
+            .downcast_ref::<StsSdkError<AssumeRoleWithWebIdentityError>>()
+            .and_then(StsSdkError::as_service_error)
+            .is_some_and(|e| {
+                e.is_expired_token_exception()
+                    || e.is_invalid_identity_token_exception()
+                    || e.is_idp_rejected_claim_exception()
+            })
+    })
+}
+
+/// Error message for a failed data load, with tailored guidance when web identity
+/// or expired credentials are the cause
+pub fn describe_load_error(error: &anyhow::Error) -> String {
+    let token_file = env::var("AWS_WEB_IDENTITY_TOKEN_FILE").ok();
+    let role_arn = env::var("AWS_ROLE_ARN").ok();
+    credential_hint(error, token_file.as_deref(), role_arn.as_deref())
+        .unwrap_or_else(|| error.to_string())
+}
+
+/// Guidance for a load that failed on credentials, given the web identity settings.
+/// `None` for any other failure, whose own message says more than a login hint.
+fn credential_hint(
+    error: &anyhow::Error,
+    token_file: Option<&str>,
+    role_arn: Option<&str>,
+) -> Option<String> {
+    if !is_credential_error(error) {
+        return None;
+    }
+
+    if let Some(problem) = web_identity_problem(token_file, role_arn) {
+        return Some(problem);
+    }
+
+    if let Some(token_file) = token_file
+        && is_web_identity_rejected(error)
+    {
+        return Some(format!(
+            "Web identity token in {} was rejected as expired or invalid. \
+             In EKS the kubelet refreshes it; check the service account's role annotation.",
+            token_file
+        ));
+    }
+
+    Some("Credentials expired or invalid - press r to retry after `aws sso login`".to_string())
+}
+
+pub fn create_ecr_client(config: &SdkConfig) -> ECRClient {
+    ECRClient::new(config)
+}
+
+pub fn create_sfn_client(config: &SdkConfig) -> SFNClient {
+    SFNClient::new(config)
+}
+
+pub fn create_s3_client(config: &SdkConfig) -> S3Client {
+    // Custom endpoints rarely resolve bucket subdomains, so address buckets by path
+    let s3_config = aws_sdk_s3::config::Builder::from(config)
+        .force_path_style(config.endpoint_url().is_some())
+        .build();
+    S3Client::from_conf(s3_config)
+}
+
+pub fn create_ec2_client(config: &SdkConfig) -> EC2Client {
+    EC2Client::new(config)
+}
+
+pub fn create_lambda_client(config: &SdkConfig) -> LambdaClient {
+    LambdaClient::new(config)
+}
+
+pub fn create_logs_client(config: &SdkConfig) -> LogsClient {
+    LogsClient::new(config)
+}
+
+pub fn create_secrets_client(config: &SdkConfig) -> SecretsClient {
+    SecretsClient::new(config)
+}
+
+pub fn create_iam_client(config: &SdkConfig) -> IAMClient {
+    IAMClient::new(config)
+}
+
+pub fn create_rds_client(config: &SdkConfig) -> RDSClient {
+    RDSClient::new(config)
+}
+
+pub fn create_route53_client(config: &SdkConfig) -> Route53Client {
+    Route53Client::new(config)
+}
+
+#[derive(Debug, Clone)]
+pub struct CallerIdentity {
+    pub account: String,
+    pub arn: String,
+    pub user_id: String,
+}
+
+pub async fn get_caller_identity(config: &SdkConfig) -> Result<CallerIdentity> {
+    let resp = STSClient::new(config).get_caller_identity().send().await?;
+
+    Ok(CallerIdentity {
+        account: resp.account().unwrap_or("").to_string(),
+        arn: resp.arn().unwrap_or("").to_string(),
+        user_id: resp.user_id().unwrap_or("").to_string(),
+    })
+}
+
+/// Resolve credentials and report which provider in the chain supplied them
+/// (e.g. "Environment", "ProfileFile", "WebIdentityToken")
+pub async fn get_credential_source(config: &SdkConfig) -> Result<String> {
+    let provider = config
+        .credentials_provider()
+        .ok_or_else(|| anyhow::anyhow!("No credentials provider configured"))?;
+    let credentials = provider.provide_credentials().await?;
+
+    // The provider name is only exposed through the Debug representation
+    let debug = format!("{:?}", credentials);
+    let source = debug
+        .split("provider_name: \"")
+        .nth(1)
+        .and_then(|rest| rest.split('"').next())
+        .unwrap_or("Unknown")
+        .to_string();
+
+    Ok(source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_ecr::{
+        error::SdkError, operation::describe_repositories::DescribeRepositoriesError,
+    };
+
+    /// Write `content` to a uniquely named file in the temp dir
+    fn token_file(name: &str, content: &str) -> String {
+        let path = env::temp_dir().join(format!("ats-test-{}-{}", std::process::id(), name));
+        fs::write(&path, content).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn web_identity_not_configured_is_not_a_problem() {
+        assert_eq!(web_identity_problem(None, None), None);
+        assert_eq!(web_identity_problem(Some(""), None), None);
+    }
+
+    #[test]
+    fn web_identity_requires_a_role_arn() {
+        let path = token_file("no-role", "token");
+        let problem = web_identity_problem(Some(&path), None).unwrap();
+        assert!(problem.contains("AWS_ROLE_ARN"));
+    }
+
+    #[test]
+    fn web_identity_reports_unreadable_and_empty_token_files() {
+        let role_arn = Some("arn:aws:iam::123456789012:role/ats");
+
+        let missing = web_identity_problem(Some("/nonexistent/ats/token"), role_arn).unwrap();
+        assert!(missing.starts_with("Cannot read web identity token file"));
+
+        let empty = token_file("empty", "\n");
+        let problem = web_identity_problem(Some(&empty), role_arn).unwrap();
+        assert!(problem.ends_with("is empty"));
+    }
+
+    #[test]
+    fn web_identity_with_a_readable_token_is_usable() {
+        let path = token_file("valid", "eyJhbGciOiJSUzI1NiJ9.e30.sig");
+        let role_arn = Some("arn:aws:iam::123456789012:role/ats");
+        assert_eq!(web_identity_problem(Some(&path), role_arn), None);
+    }
+
+    #[test]
+    fn known_regions_catch_typos() {
+        assert!(is_known_region("ap-northeast-2"));
+        assert!(!is_known_region("ap-northeast2"));
+        assert!(!is_known_region("US-EAST-1"));
+    }
+
+    #[test]
+    fn profile_names_follow_each_files_section_format() {
+        let credentials = "[default]\naws_access_key_id = x\n\n[ci]\n";
+        assert_eq!(
+            parse_profile_names(credentials, false),
+            vec!["default", "ci"]
+        );
+
+        let config = "[default]\n[profile dev]\nregion = eu-west-1\n[sso-session corp]\n";
+        assert_eq!(parse_profile_names(config, true), vec!["default", "dev"]);
+    }
+
+    fn dispatch_failure(connector_error: ConnectorError) -> anyhow::Error {
+        SdkError::<DescribeRepositoriesError>::dispatch_failure(connector_error).into()
+    }
+
+    #[test]
+    fn only_connection_failures_are_connection_errors() {
+        assert!(is_connection_error(&dispatch_failure(ConnectorError::io(
+            "connection reset".into()
+        ))));
+        assert!(is_connection_error(&dispatch_failure(
+            ConnectorError::timeout("connect timed out".into())
+        )));
+        assert!(!is_connection_error(&dispatch_failure(
+            ConnectorError::other(Box::new(CredentialsError::not_loaded("no profile")), None)
+        )));
+        assert!(!is_connection_error(&anyhow::anyhow!(
+            "AccessDeniedException: not authorized"
+        )));
+    }
+
+    #[test]
+    fn credential_errors_are_told_apart_from_request_errors() {
+        assert!(is_credential_error(&dispatch_failure(
+            ConnectorError::other(Box::new(CredentialsError::not_loaded("no profile")), None)
+        )));
+
+        let expired = ErrorMetadata::builder()
+            .code("ExpiredTokenException")
+            .message("The security token included in the request is expired")
+            .build();
+        let service_error = |meta: ErrorMetadata| -> anyhow::Error {
+            SdkError::<DescribeRepositoriesError, ()>::service_error(
+                DescribeRepositoriesError::generic(meta),
+                (),
+            )
+            .into()
+        };
+        assert!(is_credential_error(&service_error(expired)));
+
+        let denied = ErrorMetadata::builder()
+            .code("AccessDeniedException")
+            .build();
+        assert!(!is_credential_error(&service_error(denied)));
+    }
+
+    #[test]
+    fn only_credential_errors_get_the_web_identity_hint() {
+        let role_arn = Some("arn:aws:iam::123456789012:role/ats");
+        let unreadable = Some("/nonexistent/ats/token");
+
+        let denied = anyhow::anyhow!("AccessDeniedException: not authorized");
+        assert_eq!(credential_hint(&denied, unreadable, role_arn), None);
+
+        let not_loaded = dispatch_failure(ConnectorError::other(
+            Box::new(CredentialsError::not_loaded("no profile")),
+            None,
+        ));
+        let hint = credential_hint(&not_loaded, unreadable, role_arn).unwrap();
+        assert!(hint.starts_with("Cannot read web identity token file"));
+        let hint = credential_hint(&not_loaded, None, None).unwrap();
+        assert!(hint.contains("aws sso login"));
+    }
 }
@@ -0,0 +1,86 @@
+/// Display order of `columns` as indices. Columns named in `order` (matched
+/// case-insensitively) are rearranged among their own positions; unlisted columns
+/// keep their default place.
+pub fn column_order(columns: &[&str], order: &[String]) -> Vec<usize> {
+    let mut listed: Vec<usize> = Vec::new();
+    for name in order {
+        if let Some(index) = columns
+            .iter()
+            .position(|column| column.eq_ignore_ascii_case(name))
+            && !listed.contains(&index)
+        {
+            listed.push(index);
+        }
+    }
+
+    let mut slots = listed.clone();
+    slots.sort_unstable();
+
+    let mut result: Vec<usize> = (0..columns.len()).collect();
+    for (slot, index) in slots.into_iter().zip(listed) {
+        result[slot] = index;
+    }
+    result
+}
+
+/// `items` rearranged into the index order returned by `column_order`
+pub fn reorder<T: Clone>(items: &[T], order: &[usize]) -> Vec<T> {
+    order.iter().map(|&index| items[index].clone()).collect()
+}
+
+/// Column headers with an arrow on the one the table is sorted by, e.g. `SIZE ▼`
+pub fn sort_headers(columns: &[&str], sort_key: Option<usize>, ascending: bool) -> Vec<String> {
+    columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| match sort_key {
+            Some(key) if key == index => {
+                format!("{} {}", column, if ascending { "▲" } else { "▼" })
+            }
+            _ => column.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLUMNS: [&str; 4] = ["NAME", "CREATED", "SIZE", "ENCRYPTION"];
+
+    fn order(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn no_override_keeps_default_order() {
+        assert_eq!(column_order(&COLUMNS, &[]), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn listed_columns_swap_within_their_positions() {
+        let result = column_order(&COLUMNS, &order(&["encryption", "CREATED"]));
+        assert_eq!(
+            reorder(&COLUMNS, &result),
+            ["NAME", "ENCRYPTION", "SIZE", "CREATED"]
+        );
+    }
+
+    #[test]
+    fn unknown_and_duplicate_names_are_ignored() {
+        let result = column_order(&COLUMNS, &order(&["SIZE", "BOGUS", "NAME", "SIZE"]));
+        assert_eq!(
+            reorder(&COLUMNS, &result),
+            ["SIZE", "CREATED", "NAME", "ENCRYPTION"]
+        );
+    }
+
+    #[test]
+    fn sort_arrow_marks_only_the_sorted_column() {
+        assert_eq!(
+            sort_headers(&COLUMNS, Some(2), false),
+            ["NAME", "CREATED", "SIZE ▼", "ENCRYPTION"]
+        );
+        assert_eq!(sort_headers(&COLUMNS, None, true), COLUMNS);
+    }
+}
@@ -0,0 +1,107 @@
+use anyhow::{Result, anyhow};
+
+/// Render a table as tab-separated values, replacing tabs and newlines inside
+/// cells so every row stays on one line
+pub fn to_tsv(columns: &[String], rows: &[Vec<String>]) -> String {
+    std::iter::once(columns)
+        .chain(rows.iter().map(Vec::as_slice))
+        .map(|row| {
+            row.iter()
+                .map(|cell| cell.replace(['\t', '\n', '\r'], " "))
+                .collect::<Vec<_>>()
+                .join("\t")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Render a table as comma-separated values, quoting cells that contain commas,
+/// quotes or line breaks (RFC 4180)
+pub fn to_csv(columns: &[String], rows: &[Vec<String>]) -> String {
+    let mut csv: String = std::iter::once(columns)
+        .chain(rows.iter().map(Vec::as_slice))
+        .map(|row| {
+            row.iter()
+                .map(|cell| {
+                    if cell.contains([',', '"', '\n', '\r']) {
+                        format!("\"{}\"", cell.replace('"', "\"\""))
+                    } else {
+                        cell.clone()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    csv.push('\n');
+    csv
+}
+
+/// Narrow a table to the `wanted` columns, matched case-insensitively and kept in
+/// the order given
+pub fn select_columns(
+    columns: &[String],
+    rows: &[Vec<String>],
+    wanted: &[String],
+) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+    let indices = wanted
+        .iter()
+        .map(|name| {
+            columns
+                .iter()
+                .position(|column| column.eq_ignore_ascii_case(name.trim()))
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Unknown column '{}' (columns: {})",
+                        name.trim(),
+                        columns.join(", ")
+                    )
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let pick = |row: &[String]| indices.iter().map(|&i| row[i].clone()).collect();
+    Ok((pick(columns), rows.iter().map(|row| pick(row)).collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn select_columns_keeps_requested_order() {
+        let columns = strings(&["NAME", "URI", "CREATED"]);
+        let rows = vec![strings(&["api", "123/api", "2024-01-01"])];
+
+        let (columns, rows) =
+            select_columns(&columns, &rows, &strings(&["created", " name"])).unwrap();
+        assert_eq!(columns, strings(&["CREATED", "NAME"]));
+        assert_eq!(rows, vec![strings(&["2024-01-01", "api"])]);
+    }
+
+    #[test]
+    fn select_columns_rejects_unknown_columns() {
+        let columns = strings(&["NAME"]);
+        let error = select_columns(&columns, &[], &strings(&["size"])).unwrap_err();
+        assert_eq!(error.to_string(), "Unknown column 'size' (columns: NAME)");
+    }
+
+    #[test]
+    fn to_csv_quotes_cells_that_need_it() {
+        let columns = strings(&["NAME", "TAGS"]);
+        let rows = vec![
+            strings(&["api", "latest, v1"]),
+            strings(&["say \"hi\"", "none"]),
+        ];
+
+        assert_eq!(
+            to_csv(&columns, &rows),
+            "NAME,TAGS\napi,\"latest, v1\"\n\"say \"\"hi\"\"\",none\n"
+        );
+    }
+}
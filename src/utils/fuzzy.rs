@@ -0,0 +1,68 @@
+/// Score `text` against `pattern` as a case-insensitive subsequence, e.g. "prd-api"
+/// matches "production-api-server". `None` when some pattern character is missing;
+/// otherwise higher is better: consecutive runs, matches at word starts, and an early
+/// first match all add to the score, and longer texts lose a little.
+pub fn fuzzy_score(text: &str, pattern: &str) -> Option<i64> {
+    let text: Vec<char> = text.chars().flat_map(char::to_lowercase).collect();
+    let pattern: Vec<char> = pattern.chars().flat_map(char::to_lowercase).collect();
+    let Some(&first) = pattern.first() else {
+        return Some(-(text.len() as i64 / 4));
+    };
+
+    // Matching greedily from the first occurrence can miss a tighter match later on
+    // ("api" in "backend-api"), so try every place the pattern could start
+    text.iter()
+        .enumerate()
+        .filter(|&(_, &c)| c == first)
+        .filter_map(|(start, _)| score_from(&text, &pattern, start))
+        .max()
+}
+
+/// Score of the greedy match of `pattern` whose first character is at `start`
+fn score_from(text: &[char], pattern: &[char], start: usize) -> Option<i64> {
+    let mut score = 0;
+    let mut previous_match: Option<usize> = None;
+    let mut position = start;
+
+    for &p in pattern {
+        let index = position + text[position..].iter().position(|&c| c == p)?;
+
+        score += 1;
+        if previous_match.is_some_and(|previous| previous + 1 == index) {
+            score += 5;
+        }
+        if index == 0 || !text[index - 1].is_alphanumeric() {
+            score += 3;
+        }
+
+        previous_match = Some(index);
+        position = index + 1;
+    }
+
+    Some(score * 10 - start as i64 - text.len() as i64 / 4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_characters_in_order() {
+        assert!(fuzzy_score("production-api-server", "prd-api").is_some());
+        assert!(fuzzy_score("Production-API-Server", "PRDAPI").is_some());
+        assert!(fuzzy_score("production-api-server", "api-prd").is_none());
+        assert!(fuzzy_score("api", "apis").is_none());
+        assert_eq!(fuzzy_score("anything", ""), Some(-2));
+    }
+
+    #[test]
+    fn ranks_contiguous_and_word_start_matches_higher() {
+        let exact = fuzzy_score("api-server", "api").unwrap();
+        let word_start = fuzzy_score("backend-api-server", "api").unwrap();
+        let scattered = fuzzy_score("a-p-i-server", "api").unwrap();
+        let buried = fuzzy_score("rapid-install", "api").unwrap();
+        assert!(exact > word_start);
+        assert!(word_start > scattered);
+        assert!(word_start > buried);
+    }
+}
@@ -1 +1,6 @@
 pub mod aws;
+pub mod columns;
+pub mod export;
+pub mod fuzzy;
+pub mod rate_limit;
+pub mod text;
@@ -0,0 +1,61 @@
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// Token bucket shared by all services to cap AWS requests per second
+#[derive(Debug)]
+pub struct RateLimiter {
+    requests_per_second: f64,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// A limiter allowing `requests_per_second` with bursts of the same size;
+    /// a rate of zero or less disables limiting
+    pub fn new(requests_per_second: f64) -> Self {
+        Self {
+            requests_per_second,
+            bucket: Mutex::new(Bucket {
+                tokens: Self::capacity(requests_per_second),
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    pub fn unlimited() -> Self {
+        Self::new(0.0)
+    }
+
+    fn capacity(requests_per_second: f64) -> f64 {
+        requests_per_second.max(1.0)
+    }
+
+    /// Wait until a request may be issued
+    pub async fn acquire(&self) {
+        if self.requests_per_second <= 0.0 {
+            return;
+        }
+
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().await;
+                let elapsed = bucket.last_refill.elapsed().as_secs_f64();
+                bucket.tokens = (bucket.tokens + elapsed * self.requests_per_second)
+                    .min(Self::capacity(self.requests_per_second));
+                bucket.last_refill = Instant::now();
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - bucket.tokens) / self.requests_per_second)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
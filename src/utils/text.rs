@@ -0,0 +1,106 @@
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+use std::ops::Range;
+
+/// Byte range of the first case-insensitive occurrence of `pattern` in `text`
+pub fn find_match(text: &str, pattern: &str) -> Option<Range<usize>> {
+    if pattern.is_empty() {
+        return None;
+    }
+
+    for (start, _) in text.char_indices() {
+        let mut text_chars = text[start..].char_indices();
+        let mut matched = true;
+        let mut end = start;
+
+        for p in pattern.chars() {
+            match text_chars.next() {
+                Some((offset, c)) if c.to_lowercase().eq(p.to_lowercase()) => {
+                    end = start + offset + c.len_utf8();
+                }
+                _ => {
+                    matched = false;
+                    break;
+                }
+            }
+        }
+
+        if matched {
+            return Some(start..end);
+        }
+    }
+
+    None
+}
+
+/// `template` with each `{name}` placeholder replaced by its value in `fields`.
+/// Placeholders without a value are left as typed, so a mistake shows in the result.
+pub fn fill_template(template: &str, fields: &[(&str, String)]) -> String {
+    fields
+        .iter()
+        .fold(template.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{}}}", name), value)
+        })
+}
+
+/// Render `text` as a line with the part matching `pattern` emphasized
+pub fn highlight_match(text: &str, pattern: &str) -> Line<'static> {
+    match find_match(text, pattern) {
+        Some(range) => Line::from(vec![
+            Span::raw(text[..range.start].to_string()),
+            Span::styled(
+                text[range.clone()].to_string(),
+                Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            ),
+            Span::raw(text[range.end..].to_string()),
+        ]),
+        None => Line::from(text.to_string()),
+    }
+}
+
+/// Shorten `text` to at most `width` characters, marking the cut with `…`
+pub fn truncate_with_ellipsis(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+
+    let mut truncated: String = text.chars().take(width - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+/// Split `text` into chunks of at most `width` characters, one per display line
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    if width == 0 || text.is_empty() {
+        return vec![text.to_string()];
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    chars
+        .chunks(width)
+        .map(|chunk| chunk.iter().collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn templates_fill_known_placeholders_only() {
+        let fields = [
+            ("uri", "123.dkr.ecr.eu-west-1.amazonaws.com/api".to_string()),
+            ("tag", "v1.2".to_string()),
+        ];
+        assert_eq!(
+            fill_template("docker pull {uri}:{tag}", &fields),
+            "docker pull 123.dkr.ecr.eu-west-1.amazonaws.com/api:v1.2"
+        );
+        assert_eq!(fill_template("{name}@{tag}", &fields), "{name}@v1.2");
+    }
+}